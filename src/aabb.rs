@@ -0,0 +1,241 @@
+use crate::{Point, Ray, Sphere, Vector3};
+
+/// An axis-aligned bounding box in 3D space, spanning from `min` to `max`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Aabb {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Aabb {
+    /// Construct a box from its minimum and maximum corners
+    pub const fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// Construct a box from its center and half its size along each axis
+    pub fn from_center_half_extents(center: Point, half_extents: Vector3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// The smallest box containing all of the given points.
+    /// Returns `None` if there are no points.
+    pub fn from_points(points: &[Point]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(
+            rest.iter()
+                .fold(Self::new(*first, *first), |b, p| b.including(*p)),
+        )
+    }
+
+    /// The smallest box containing the positions in interleaved vertex data, without copying
+    /// them out first. Returns `None` if there are no positions.
+    ///
+    /// Each vertex is `stride` floats long, and begins with its x, y and z coordinates. To
+    /// read positions stored at an offset within each vertex, pass `&data[offset..]`.
+    pub fn from_positions(data: &[f32], stride: usize) -> Option<Self> {
+        let mut positions = positions(data, stride);
+        let first = positions.next()?;
+        Some(positions.fold(Self::new(first, first), |b, p| b.including(p)))
+    }
+
+    /// This box, grown to include the given point
+    pub fn including(&self, point: Point) -> Self {
+        Self::new(self.min.min(point), self.max.max(point))
+    }
+
+    /// The center of this box
+    pub fn center(&self) -> Point {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    /// Half the size of this box along each axis
+    pub fn half_extents(&self) -> Vector3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Whether a point lies within this box, including its surface
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Whether this box overlaps another. Boxes that only share a face intersect.
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+
+    /// The smallest box containing both this box and another
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// The distance along a ray at which it first touches this box, in multiples of the ray's
+    /// direction. Returns zero if the ray starts inside the box, and `None` if it misses.
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        let mut near = 0.0f32;
+        let mut far = f32::INFINITY;
+
+        for axis in 0..3 {
+            let (origin, direction) = (ray.origin[axis], ray.direction[axis]);
+            if direction == 0.0 {
+                if origin < self.min[axis] || origin > self.max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            let inverse = 1.0 / direction;
+            let t0 = (self.min[axis] - origin) * inverse;
+            let t1 = (self.max[axis] - origin) * inverse;
+            near = near.max(t0.min(t1));
+            far = far.min(t0.max(t1));
+        }
+
+        if near <= far {
+            Some(near)
+        } else {
+            None
+        }
+    }
+
+    /// The closest point within this box to the given point
+    pub fn clamp(&self, point: Point) -> Point {
+        point.max(self.min).min(self.max)
+    }
+}
+
+/// The bounding box and a bounding sphere of the positions in interleaved vertex data, computed
+/// in a single pass. Returns `None` if there are no positions. See [`Aabb::from_positions`]
+/// for the layout of the data.
+///
+/// The sphere is grown to enclose each point in turn, so it is not the smallest possible, but
+/// is usually much tighter than the sphere around the bounding box.
+pub fn mesh_bounds(data: &[f32], stride: usize) -> Option<(Aabb, Sphere)> {
+    let mut positions = positions(data, stride);
+    let first = positions.next()?;
+
+    let mut aabb = Aabb::new(first, first);
+    let mut sphere = Sphere::new(first, 0.0);
+    for p in positions {
+        aabb = aabb.including(p);
+
+        let offset = p - sphere.center;
+        let distance = offset.magnitude();
+        if distance > sphere.radius {
+            // Move the center just far enough to keep the far side of the sphere in place
+            let radius = (sphere.radius + distance) * 0.5;
+            sphere.center += offset * ((radius - sphere.radius) / distance);
+            sphere.radius = radius;
+        }
+    }
+
+    Some((aabb, sphere))
+}
+
+/// The positions at the start of each vertex in interleaved vertex data
+fn positions(data: &[f32], stride: usize) -> impl Iterator<Item = Point> + '_ {
+    assert!(stride >= 3, "each vertex must have room for a position");
+    data.chunks(stride)
+        .filter(|v| v.len() >= 3)
+        .map(|v| Point::new(v[0], v[1], v[2]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn overlap() {
+        let a = Aabb::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 2.0, 2.0));
+        let b = Aabb::from_center_half_extents(Point::new(2.5, 1.0, 1.0), Vector3::one());
+
+        assert_eq!(a.center(), Point::new(1.0, 1.0, 1.0));
+        assert!(a.contains(Point::new(2.0, 0.5, 1.0)));
+        assert!(!a.contains(Point::new(2.5, 0.5, 1.0)));
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&Aabb::new(
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(4.0, 1.0, 1.0)
+        )));
+        assert_eq!(
+            a.union(&b),
+            Aabb::new(Point::ORIGIN, Point::new(3.5, 2.0, 2.0))
+        );
+        assert_eq!(
+            a.clamp(Point::new(5.0, -1.0, 1.0)),
+            Point::new(2.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn ray() {
+        let aabb = Aabb::new(Point::new(2.0, -1.0, -1.0), Point::new(4.0, 1.0, 1.0));
+
+        assert_eq!(
+            aabb.intersect_ray(&Ray::new(Point::ORIGIN, Vector3::UNIT_X)),
+            Some(2.0)
+        );
+        assert_eq!(
+            aabb.intersect_ray(&Ray::new(Point::ORIGIN, -Vector3::UNIT_X)),
+            None
+        );
+        assert_eq!(
+            aabb.intersect_ray(&Ray::new(Point::ORIGIN, Vector3::UNIT_Y)),
+            None
+        );
+        assert_eq!(
+            aabb.intersect_ray(&Ray::new(Point::new(3.0, 0.0, 0.0), Vector3::UNIT_Y)),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn mesh_bounds() {
+        // Position, then a two-component texture coordinate
+        #[rustfmt::skip]
+        let vertices = [
+            -1.0, 0.0, 0.0, 0.0, 0.0,
+            3.0, 0.0, 0.0, 1.0, 0.0,
+            1.0, 2.0, -1.0, 0.5, 1.0,
+        ];
+
+        let aabb = Aabb::from_positions(&vertices, 5).unwrap();
+        assert_eq!(
+            aabb,
+            Aabb::new(Point::new(-1.0, 0.0, -1.0), Point::new(3.0, 2.0, 0.0))
+        );
+        assert_eq!(
+            Aabb::from_points(&[Point::new(-1.0, 0.0, 0.0), Point::new(3.0, 2.0, -1.0)]),
+            Some(Aabb::new(
+                Point::new(-1.0, 0.0, -1.0),
+                Point::new(3.0, 2.0, 0.0)
+            ))
+        );
+
+        let (combined, sphere) = crate::mesh_bounds(&vertices, 5).unwrap();
+        assert_eq!(combined, aabb);
+        for v in vertices.chunks(5) {
+            let p = Point::new(v[0], v[1], v[2]);
+            assert!((p - sphere.center).magnitude() <= sphere.radius * 1.0001);
+        }
+
+        // The final vertex is too short to hold a position once offset
+        let uvs = Aabb::from_positions(&vertices[3..], 5).unwrap();
+        assert_eq!(
+            uvs,
+            Aabb::new(Point::new(0.0, 0.0, 1.0), Point::new(1.0, 0.0, 3.0))
+        );
+        assert_eq!(Aabb::from_positions(&[], 3), None);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Aabb, Frustum, Point, Ray};
+
+/// The most items stored in a single leaf of a [`Bvh`].
+const MAX_LEAF_SIZE: usize = 4;
+
+/// A bounding volume hierarchy over a set of boxes, for accelerating ray picking and
+/// frustum culling.
+///
+/// Items are identified by their index in the slice the hierarchy was built from. The tree is
+/// built by splitting each node at the median of the item centers, along its longest axis.
+#[derive(Clone, Debug, Default)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+    items: Vec<usize>,
+    bounds: Vec<Aabb>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Node {
+    bounds: Aabb,
+    /// For leaves, the index of the first item. Otherwise the index of the first child, with
+    /// the second child immediately following it.
+    first: usize,
+    /// The number of items in a leaf, or zero for interior nodes.
+    count: usize,
+}
+
+impl Bvh {
+    /// Build a hierarchy over the given boxes
+    pub fn build(bounds: &[Aabb]) -> Self {
+        let mut bvh = Self {
+            nodes: Vec::with_capacity(bounds.len() * 2),
+            items: (0..bounds.len()).collect(),
+            bounds: vec![],
+        };
+        if bounds.is_empty() {
+            return bvh;
+        }
+
+        let centers: Vec<Point> = bounds.iter().map(Aabb::center).collect();
+        bvh.nodes.push(Node {
+            bounds: bounds[0],
+            first: 0,
+            count: bounds.len(),
+        });
+
+        let mut pending = vec![0];
+        while let Some(index) = pending.pop() {
+            let Node { first, count, .. } = bvh.nodes[index];
+            let items = &mut bvh.items[first..first + count];
+
+            let node_bounds = items
+                .iter()
+                .fold(bounds[items[0]], |b, &i| b.union(&bounds[i]));
+            bvh.nodes[index].bounds = node_bounds;
+
+            if count <= MAX_LEAF_SIZE {
+                continue;
+            }
+
+            // Split along the axis where the item centers are most spread out
+            let (lo, hi) = items
+                .iter()
+                .fold((centers[items[0]], centers[items[0]]), |(lo, hi), &i| {
+                    (lo.min(centers[i]), hi.max(centers[i]))
+                });
+            let spread = hi - lo;
+            let axis = if spread.x >= spread.y && spread.x >= spread.z {
+                0
+            } else if spread.y >= spread.z {
+                1
+            } else {
+                2
+            };
+
+            let middle = count / 2;
+            items.select_nth_unstable_by(middle, |&a, &b| {
+                centers[a][axis].total_cmp(&centers[b][axis])
+            });
+
+            let child = bvh.nodes.len();
+            bvh.nodes.push(Node {
+                bounds: node_bounds,
+                first,
+                count: middle,
+            });
+            bvh.nodes.push(Node {
+                bounds: node_bounds,
+                first: first + middle,
+                count: count - middle,
+            });
+            bvh.nodes[index].first = child;
+            bvh.nodes[index].count = 0;
+
+            pending.push(child);
+            pending.push(child + 1);
+        }
+
+        // Store the item bounds in tree order, so leaves can test them without indirection
+        bvh.bounds = bvh.items.iter().map(|&i| bounds[i]).collect();
+        bvh
+    }
+
+    /// The number of items in the hierarchy
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the hierarchy contains no items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Call `visit` with the index of every item whose box the ray passes through, along with
+    /// the distance at which it enters that box. Items are not visited in any particular order.
+    pub fn traverse_ray(&self, ray: &Ray, mut visit: impl FnMut(usize, f32)) {
+        self.traverse(
+            |bounds| bounds.intersect_ray(ray).is_some(),
+            |item, bounds| {
+                if let Some(t) = bounds.intersect_ray(ray) {
+                    visit(item, t);
+                }
+            },
+        );
+    }
+
+    /// Call `visit` with the index of every item whose box might be visible within the frustum.
+    /// See [`Frustum::intersects_aabb`].
+    pub fn traverse_frustum(&self, frustum: &Frustum, mut visit: impl FnMut(usize)) {
+        self.traverse(
+            |bounds| frustum.intersects_aabb(bounds),
+            |item, bounds| {
+                if frustum.intersects_aabb(bounds) {
+                    visit(item);
+                }
+            },
+        );
+    }
+
+    /// Walk the nodes whose bounds pass `test`, passing each item in their leaves to `visit`
+    /// along with its bounds.
+    fn traverse(&self, test: impl Fn(&Aabb) -> bool, mut visit: impl FnMut(usize, &Aabb)) {
+        if self.nodes.is_empty() {
+            return;
+        }
+
+        let mut pending = vec![0];
+        while let Some(index) = pending.pop() {
+            let node = &self.nodes[index];
+            if !test(&node.bounds) {
+                continue;
+            }
+
+            if node.count > 0 {
+                let range = node.first..node.first + node.count;
+                for (&item, bounds) in self.items[range.clone()].iter().zip(&self.bounds[range]) {
+                    visit(item, bounds);
+                }
+            } else {
+                pending.push(node.first);
+                pending.push(node.first + 1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn grid() -> Vec<Aabb> {
+        (0..100)
+            .map(|i| {
+                let center = Point::new((i % 10) as f32 * 3.0, (i / 10) as f32 * 3.0, 0.0);
+                Aabb::from_center_half_extents(center, Vector3::one())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ray() {
+        let boxes = grid();
+        let bvh = Bvh::build(&boxes);
+        assert_eq!(bvh.len(), 100);
+
+        let ray = Ray::new(Point::new(6.0, 9.0, 10.0), Vector3::FORWARD);
+        let mut hits = vec![];
+        bvh.traverse_ray(&ray, |i, t| hits.push((i, t)));
+        assert_eq!(hits, vec![(32, 9.0)]);
+
+        let mut count = 0;
+        Bvh::build(&[]).traverse_ray(&ray, |_, _| count += 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn frustum() {
+        let boxes = grid();
+        let bvh = Bvh::build(&boxes);
+
+        let view = Matrix4::look_at(Point::new(0.0, 0.0, 5.0), Point::ORIGIN, Vector3::UP);
+        let projection = Matrix4::perspective(1.0, 2.0 * 0.7f32.atan(), 1.0, 10.0);
+        let frustum = Frustum::from_matrix(&(projection * view));
+
+        let mut visible = vec![];
+        bvh.traverse_frustum(&frustum, |i| visible.push(i));
+        visible.sort();
+
+        assert_eq!(visible, vec![0, 1, 10, 11]);
+    }
+}
@@ -0,0 +1,132 @@
+use std::cell::OnceCell;
+
+use crate::{DepthRange, Frustum, Matrix4};
+
+/// A camera, combining a view matrix and a projection matrix.
+///
+/// The combined view-projection matrix, its inverse, and the view frustum are computed on
+/// first use, and cached until the view or projection changes.
+#[derive(Clone, Debug)]
+pub struct Camera {
+    view: Matrix4,
+    projection: Matrix4,
+    depth_range: DepthRange,
+    cache: OnceCell<Derived>,
+}
+
+#[derive(Clone, Debug)]
+struct Derived {
+    view_projection: Matrix4,
+    inverse_view_projection: Matrix4,
+    frustum: Frustum,
+}
+
+impl Camera {
+    /// Construct a camera from view and projection matrices, where the projection produces
+    /// OpenGL's -1 to 1 depth range
+    pub fn new(view: Matrix4, projection: Matrix4) -> Self {
+        Self::with_depth_range(view, projection, DepthRange::NegativeOneToOne)
+    }
+
+    /// Construct a camera from view and projection matrices, where the projection produces the
+    /// given depth range. The range is needed to extract the near and far planes of the frustum.
+    pub fn with_depth_range(view: Matrix4, projection: Matrix4, depth_range: DepthRange) -> Self {
+        Self {
+            view,
+            projection,
+            depth_range,
+            cache: OnceCell::new(),
+        }
+    }
+
+    /// The view (world-to-camera) matrix
+    pub fn view(&self) -> &Matrix4 {
+        &self.view
+    }
+
+    /// The projection (camera-to-clip) matrix
+    pub fn projection(&self) -> &Matrix4 {
+        &self.projection
+    }
+
+    /// Replace the view matrix
+    pub fn set_view(&mut self, view: Matrix4) {
+        self.view = view;
+        self.cache.take();
+    }
+
+    /// Replace the projection matrix
+    pub fn set_projection(&mut self, projection: Matrix4) {
+        self.projection = projection;
+        self.cache.take();
+    }
+
+    /// The combined view-projection (world-to-clip) matrix
+    pub fn view_projection(&self) -> &Matrix4 {
+        &self.derived().view_projection
+    }
+
+    /// The inverse of the view-projection matrix, which maps clip coordinates back to world space
+    pub fn inverse_view_projection(&self) -> &Matrix4 {
+        &self.derived().inverse_view_projection
+    }
+
+    /// The view frustum in world space
+    pub fn frustum(&self) -> &Frustum {
+        &self.derived().frustum
+    }
+
+    fn derived(&self) -> &Derived {
+        self.cache.get_or_init(|| {
+            let view_projection = self.projection * self.view;
+            Derived {
+                view_projection,
+                inverse_view_projection: view_projection.invert(),
+                frustum: Frustum::from_matrix_with_depth_range(&view_projection, self.depth_range),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn cached_products() {
+        let projection = Matrix4::perspective(1.5, 1.0, 0.1, 100.0);
+        let mut camera = Camera::new(
+            Matrix4::look_at(Point::new(0.0, 0.0, 5.0), Point::ORIGIN, Vector3::UP),
+            projection,
+        );
+
+        assert_eq!(*camera.view_projection(), projection * *camera.view());
+        assert_nearly_eq!(
+            &(*camera.inverse_view_projection() * *camera.view_projection()),
+            &Matrix4::identity(),
+            epsilon = 1e-4
+        );
+        assert!(camera.frustum().contains_point(Point::ORIGIN));
+
+        // Moving the camera invalidates the cached products
+        camera.set_view(Matrix4::look_at(
+            Point::new(0.0, 0.0, 5.0),
+            Point::new(0.0, 0.0, 10.0),
+            Vector3::UP,
+        ));
+        assert_eq!(*camera.view_projection(), projection * *camera.view());
+        assert!(!camera.frustum().contains_point(Point::ORIGIN));
+
+        let reversed = Camera::with_depth_range(
+            *camera.view(),
+            Matrix4::perspective_reversed_z(1.5, 1.0, 0.1, 100.0),
+            DepthRange::OneToZero,
+        );
+        assert!(reversed
+            .frustum()
+            .contains_point(Point::new(0.0, 0.0, 10.0)));
+        assert!(!reversed
+            .frustum()
+            .contains_point(Point::new(0.0, 0.0, 200.0)));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{first_root, Point, Ray, Sphere};
+
+/// A capsule, consisting of all points within `radius` of the line segment from `a` to `b`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Capsule {
+    pub a: Point,
+    pub b: Point,
+    pub radius: f32,
+}
+
+impl Capsule {
+    /// Construct a capsule from the end points of its central segment, and its radius
+    pub const fn new(a: Point, b: Point, radius: f32) -> Self {
+        Self { a, b, radius }
+    }
+
+    /// The closest point on the central segment of this capsule to the given point
+    pub fn closest_point_on_segment(&self, point: Point) -> Point {
+        closest_point_on_segment(point, self.a, self.b)
+    }
+
+    /// The closest point within this capsule to the given point, which is the point itself
+    /// if it lies inside
+    pub fn closest_point(&self, point: Point) -> Point {
+        let on_segment = self.closest_point_on_segment(point);
+        let offset = point - on_segment;
+        if offset.magnitude_squared() <= self.radius * self.radius {
+            point
+        } else {
+            on_segment + offset.normalized() * self.radius
+        }
+    }
+
+    /// Whether a point lies within this capsule
+    pub fn contains(&self, point: Point) -> bool {
+        (point - self.closest_point_on_segment(point)).magnitude_squared()
+            <= self.radius * self.radius
+    }
+
+    /// Whether this capsule overlaps a sphere
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let r = self.radius + sphere.radius;
+        (sphere.center - self.closest_point_on_segment(sphere.center)).magnitude_squared() <= r * r
+    }
+
+    /// Whether this capsule overlaps another
+    pub fn intersects_capsule(&self, other: &Capsule) -> bool {
+        let (p, q) = closest_points_between_segments(self.a, self.b, other.a, other.b);
+        let r = self.radius + other.radius;
+        (q - p).magnitude_squared() <= r * r
+    }
+
+    /// The distance along a ray at which it first touches this capsule, in multiples of the
+    /// ray's direction. Returns zero if the ray starts inside the capsule, and `None` if it misses.
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        if self.contains(ray.origin) {
+            return Some(0.0);
+        }
+
+        let axis = self.b - self.a;
+        let length_squared = axis.magnitude_squared();
+
+        // Test the side of the capsule as an infinite cylinder, keeping hits between the caps
+        let mut nearest = None;
+        if length_squared > 0.0 {
+            let m = ray.origin - self.a;
+            let d = ray.direction;
+            let project = |v: crate::Vector3| v - axis * (v.dot(axis) / length_squared);
+            let (dp, mp) = (project(d), project(m));
+
+            if let Some(t) = first_root(
+                dp.magnitude_squared(),
+                mp.dot(dp),
+                mp.magnitude_squared() - self.radius * self.radius,
+            ) {
+                let s = (m + d * t).dot(axis);
+                if (0.0..=length_squared).contains(&s) {
+                    nearest = Some(t);
+                }
+            }
+        }
+
+        // Then the hemispherical caps
+        [self.a, self.b]
+            .into_iter()
+            .filter_map(|center| Sphere::new(center, self.radius).intersect_ray(ray))
+            .chain(nearest)
+            .min_by(f32::total_cmp)
+    }
+}
+
+/// The closest point to `p` on the line segment between `a` and `b`
+pub(crate) fn closest_point_on_segment(p: Point, a: Point, b: Point) -> Point {
+    let ab = b - a;
+    let length_squared = ab.magnitude_squared();
+    if length_squared == 0.0 {
+        return a;
+    }
+
+    let t = ((p - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+/// The closest pair of points between the segments `p1`–`q1` and `p2`–`q2`
+pub(crate) fn closest_points_between_segments(
+    p1: Point,
+    q1: Point,
+    p2: Point,
+    q2: Point,
+) -> (Point, Point) {
+    let (d1, d2) = (q1 - p1, q2 - p2);
+    let r = p1 - p2;
+    let (a, e, f) = (d1.magnitude_squared(), d2.magnitude_squared(), d2.dot(r));
+
+    let (s, t) = if a == 0.0 && e == 0.0 {
+        (0.0, 0.0)
+    } else if a == 0.0 {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = d1.dot(r);
+        if e == 0.0 {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(d2);
+            let denominator = a * e - b * b;
+
+            // Parallel segments have no unique closest pair, so pick any s
+            let s = if denominator != 0.0 {
+                ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    (p1 + d1 * s, p2 + d2 * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn overlap() {
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 4.0, 0.0), 1.0);
+
+        assert!(capsule.contains(Point::new(0.5, 4.5, 0.0)));
+        assert!(!capsule.contains(Point::new(1.5, 2.0, 0.0)));
+        assert_eq!(
+            capsule.closest_point(Point::new(3.0, 2.0, 0.0)),
+            Point::new(1.0, 2.0, 0.0)
+        );
+        assert_eq!(
+            capsule.closest_point(Point::new(0.0, 7.0, 0.0)),
+            Point::new(0.0, 5.0, 0.0)
+        );
+
+        assert!(capsule.intersects_sphere(&Sphere::new(Point::new(0.0, 6.0, 0.0), 1.0)));
+        assert!(!capsule.intersects_sphere(&Sphere::new(Point::new(2.5, 2.0, 0.0), 1.0)));
+
+        let crossing = Capsule::new(Point::new(-5.0, 2.0, 1.5), Point::new(5.0, 2.0, 1.5), 0.6);
+        assert!(capsule.intersects_capsule(&crossing));
+        let parallel = Capsule::new(Point::new(2.5, -1.0, 0.0), Point::new(2.5, 8.0, 0.0), 0.4);
+        assert!(!capsule.intersects_capsule(&parallel));
+    }
+
+    #[test]
+    fn ray() {
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 4.0, 0.0), 1.0);
+
+        let side = Ray::new(Point::new(-5.0, 2.0, 0.0), Vector3::UNIT_X);
+        assert_nearly_eq!(capsule.intersect_ray(&side).unwrap(), 4.0, epsilon = 1e-5);
+
+        let cap = Ray::new(Point::new(0.0, 10.0, 0.0), Vector3::DOWN);
+        assert_nearly_eq!(capsule.intersect_ray(&cap).unwrap(), 5.0, epsilon = 1e-5);
+
+        let miss = Ray::new(Point::new(-5.0, 6.0, 0.0), Vector3::UNIT_X);
+        assert_eq!(capsule.intersect_ray(&miss), None);
+
+        let inside = Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::UNIT_X);
+        assert_eq!(capsule.intersect_ray(&inside), Some(0.0));
+
+        // A ray along the axis enters through a cap, not the side
+        let along_axis = Ray::new(Point::new(0.0, -3.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+        assert_nearly_eq!(
+            capsule.intersect_ray(&along_axis).unwrap(),
+            1.0,
+            epsilon = 1e-5
+        );
+
+        // A capsule with coincident end points is a sphere
+        let sphere = Capsule::new(Point::ORIGIN, Point::ORIGIN, 1.0);
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::UNIT_X);
+        assert_nearly_eq!(sphere.intersect_ray(&ray).unwrap(), 4.0, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{Rect, Vector2};
+
+/// A circle in 2D space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Circle {
+    pub center: Vector2,
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Construct a circle from its center and radius
+    pub const fn new(center: Vector2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// The smallest circle enclosing all of the given points.
+    /// Returns `None` if there are no points.
+    ///
+    /// This runs in expected linear time, and allocates a shuffled copy of the points.
+    pub fn from_points(points: &[Vector2]) -> Option<Self> {
+        let first = *points.first()?;
+
+        // Welzl's algorithm is only linear for points in random order, and cubic for adversarial
+        // orders such as points sorted by angle around a circle. Shuffle with a fixed seed, so
+        // that the result is still deterministic.
+        let mut points = points.to_vec();
+        let mut state = 0x9e37_79b9u32;
+        for i in (1..points.len()).rev() {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            points.swap(i, ((state as u64 * (i as u64 + 1)) >> 32) as usize);
+        }
+
+        // Unrolled into loops, each nested loop fixes another point on the boundary of the circle
+        let mut circle = Circle::new(first, 0.0);
+        for (i, &p) in points.iter().enumerate() {
+            if circle.contains_within(p) {
+                continue;
+            }
+            circle = Circle::new(p, 0.0);
+            for (j, &q) in points[..i].iter().enumerate() {
+                if circle.contains_within(q) {
+                    continue;
+                }
+                circle = Circle::from_diameter(p, q);
+                for &r in &points[..j] {
+                    if !circle.contains_within(r) {
+                        circle = Circle::circumscribe(p, q, r).unwrap_or(circle);
+                    }
+                }
+            }
+        }
+
+        Some(circle)
+    }
+
+    /// The circle whose diameter is the segment between two points
+    fn from_diameter(a: Vector2, b: Vector2) -> Self {
+        let center = (a + b) * 0.5;
+        Self::new(center, (a - center).magnitude())
+    }
+
+    /// The circle passing through three points, or `None` if they are collinear
+    fn circumscribe(a: Vector2, b: Vector2, c: Vector2) -> Option<Self> {
+        let (ab, ac) = (b - a, c - a);
+        let d = 2.0 * ab.cross(ac);
+        // Relative to the lengths of the sides, so that small triangles aren't rejected
+        if d.abs() <= f32::EPSILON * ab.magnitude() * ac.magnitude() {
+            return None;
+        }
+
+        let (ab2, ac2) = (ab.magnitude_squared(), ac.magnitude_squared());
+        let offset = Vector2::new(ac.y * ab2 - ab.y * ac2, ab.x * ac2 - ac.x * ab2) / d;
+        Some(Self::new(a + offset, offset.magnitude()))
+    }
+
+    /// Containment with a little slack, so that rounding error doesn't reject boundary points
+    fn contains_within(&self, point: Vector2) -> bool {
+        (point - self.center).magnitude() <= self.radius * (1.0 + 1e-5) + 1e-6
+    }
+
+    /// Whether a point lies within this circle, including its boundary
+    pub fn contains(&self, point: Vector2) -> bool {
+        (point - self.center).magnitude_squared() <= self.radius * self.radius
+    }
+
+    /// Whether this circle overlaps another
+    pub fn intersects(&self, other: &Circle) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).magnitude_squared() <= r * r
+    }
+
+    /// Whether this circle overlaps a rectangle
+    pub fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.contains(rect.clamp(self.center))
+    }
+
+    /// Whether this circle overlaps the line segment between two points
+    pub fn intersects_segment(&self, a: Vector2, b: Vector2) -> bool {
+        self.contains(closest_point_on_segment_2d(self.center, a, b))
+    }
+
+    /// The smallest rectangle enclosing this circle
+    pub fn bounding_rect(&self) -> Rect {
+        Rect::from_center_half_size(self.center, Vector2::from_scalar(self.radius))
+    }
+}
+
+/// The closest point to `p` on the line segment between `a` and `b`
+pub(crate) fn closest_point_on_segment_2d(p: Vector2, a: Vector2, b: Vector2) -> Vector2 {
+    let ab = b - a;
+    let length_squared = ab.magnitude_squared();
+    if length_squared == 0.0 {
+        return a;
+    }
+
+    let t = ((p - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn overlap() {
+        let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0);
+
+        assert!(circle.contains(Vector2::new(0.0, 2.0)));
+        assert!(!circle.contains(Vector2::new(1.5, 1.5)));
+        assert!(circle.intersects(&Circle::new(Vector2::new(3.0, 0.0), 1.0)));
+        assert!(!circle.intersects(&Circle::new(Vector2::new(3.0, 0.5), 1.0)));
+
+        let rect = Rect::new(Vector2::new(1.5, 1.5), Vector2::new(3.0, 3.0));
+        assert!(!circle.intersects_rect(&rect));
+        assert!(circle.intersects_rect(&rect.expanded(0.2)));
+
+        assert!(circle.intersects_segment(Vector2::new(-5.0, 1.0), Vector2::new(5.0, 1.0)));
+        assert!(!circle.intersects_segment(Vector2::new(-5.0, 3.0), Vector2::new(5.0, 3.0)));
+        assert!(!circle.intersects_segment(Vector2::new(3.0, 0.0), Vector2::new(5.0, 0.0)));
+    }
+
+    #[test]
+    fn from_points() {
+        let points = [
+            Vector2::new(1.0, 0.0),
+            Vector2::new(0.0, 0.5),
+            Vector2::new(-1.0, 0.0),
+            Vector2::new(0.2, -0.3),
+            Vector2::new(0.0, 1.0),
+        ];
+        let circle = Circle::from_points(&points).unwrap();
+
+        assert_nearly_eq!(&circle.center, &Vector2::zero(), epsilon = 1e-6);
+        assert_nearly_eq!(circle.radius, 1.0, epsilon = 1e-6);
+
+        let pair = Circle::from_points(&[Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0)]).unwrap();
+        assert_eq!(pair, Circle::new(Vector2::new(2.0, 0.0), 2.0));
+        assert_eq!(Circle::from_points(&[]), None);
+
+        let small: Vec<_> = points.iter().map(|&p| p * 1e-4).collect();
+        let circle = Circle::from_points(&small).unwrap();
+        assert_nearly_eq!(&circle.center, &Vector2::zero(), epsilon = 1e-10);
+        assert_nearly_eq!(circle.radius, 1e-4, epsilon = 1e-10);
+
+        let sorted: Vec<_> = (0..1000)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / 1000.0;
+                Vector2::new(1.0 + 2.0 * angle.cos(), 1.0 + 2.0 * angle.sin())
+            })
+            .collect();
+        let circle = Circle::from_points(&sorted).unwrap();
+        assert_nearly_eq!(&circle.center, &Vector2::one(), epsilon = 1e-5);
+        assert_nearly_eq!(circle.radius, 2.0, epsilon = 1e-5);
+    }
+}
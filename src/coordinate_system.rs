@@ -0,0 +1,86 @@
+use crate::{Matrix4, Vector4};
+
+/// A convention for which way the axes of 3D space point, as used by different tools and
+/// file formats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CoordinateSystem {
+    /// +y up, +x right, +z towards the viewer, as used by this crate, OpenGL, glTF and Maya
+    YUpRightHanded,
+    /// +z up, +x right, +y away from the viewer, as used by Blender, 3ds Max and some USD
+    /// files
+    ZUpRightHanded,
+    /// +y up, +x right, +z away from the viewer, as used by Direct3D and Unity
+    YUpLeftHanded,
+}
+
+impl CoordinateSystem {
+    /// The matrix converting coordinates in this system to [`Self::YUpRightHanded`]
+    fn to_y_up_right_handed(self) -> Matrix4 {
+        let (x, y, z) = match self {
+            Self::YUpRightHanded => return Matrix4::identity(),
+            Self::ZUpRightHanded => (Vector4::UNIT_X, -Vector4::UNIT_Z, Vector4::UNIT_Y),
+            Self::YUpLeftHanded => (Vector4::UNIT_X, Vector4::UNIT_Y, -Vector4::UNIT_Z),
+        };
+        Matrix4::from_cols(x, y, z, Vector4::UNIT_W)
+    }
+}
+
+impl Matrix4 {
+    /// The matrix converting coordinates from one coordinate system to another.
+    ///
+    /// Between systems of opposite handedness this is a reflection, which reverses the winding
+    /// order of triangles.
+    pub fn change_of_basis(from: CoordinateSystem, to: CoordinateSystem) -> Self {
+        // Each conversion is a signed permutation of the axes, so the inverse is the transpose
+        to.to_y_up_right_handed().transpose() * from.to_y_up_right_handed()
+    }
+
+    /// This transform, expressed in another coordinate system. The result acts on coordinates
+    /// in `to` as this transform acts on coordinates in `from`.
+    pub fn rebase(&self, from: CoordinateSystem, to: CoordinateSystem) -> Self {
+        Self::change_of_basis(from, to) * *self * Self::change_of_basis(to, from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn change_of_basis() {
+        use CoordinateSystem::*;
+
+        let blender = Matrix4::change_of_basis(ZUpRightHanded, YUpRightHanded);
+        assert_eq!(blender * Vector3::UNIT_Z, Vector3::UNIT_Y);
+        assert_eq!(blender * Vector3::UNIT_Y, -Vector3::UNIT_Z);
+        assert_eq!(
+            Matrix4::change_of_basis(ZUpRightHanded, YUpLeftHanded) * Vector3::UNIT_Y,
+            Vector3::UNIT_Z
+        );
+
+        let systems = [YUpRightHanded, ZUpRightHanded, YUpLeftHanded];
+        for from in systems {
+            for to in systems {
+                let m = Matrix4::change_of_basis(from, to);
+                assert_eq!(m * Matrix4::change_of_basis(to, from), Matrix4::identity());
+                let mirrored = (from == YUpLeftHanded) != (to == YUpLeftHanded);
+                let [x, y, z] = [0, 1, 2].map(|i| Vector3::from(m.column(i)));
+                let handedness = x.cross(y).dot(z);
+                assert_eq!(handedness, if mirrored { -1.0 } else { 1.0 });
+            }
+        }
+
+        // Lifting an object one unit in Blender lifts it one unit in Y-up space
+        let lift = Matrix4::translation(Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(
+            lift.rebase(ZUpRightHanded, YUpRightHanded),
+            Matrix4::translation(Vector3::new(0.0, 1.0, 0.0))
+        );
+        let turn = Matrix4::rotation_z(0.5);
+        assert_nearly_eq!(
+            &turn.rebase(ZUpRightHanded, YUpRightHanded),
+            &Matrix4::rotation_y(0.5),
+            epsilon = 1e-6
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{Point, Ray, Sphere, Vector3};
+
+/// A solid cylinder, consisting of all points within `radius` of the line segment from `a` to
+/// `b`, capped by flat ends at `a` and `b`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Cylinder {
+    pub a: Point,
+    pub b: Point,
+    pub radius: f32,
+}
+
+impl Cylinder {
+    /// Construct a cylinder from the centers of its ends, and its radius
+    pub const fn new(a: Point, b: Point, radius: f32) -> Self {
+        Self { a, b, radius }
+    }
+
+    /// Whether a point lies within this cylinder
+    pub fn contains(&self, point: Point) -> bool {
+        let axis = self.b - self.a;
+        let length_squared = axis.magnitude_squared();
+        let offset = point - self.a;
+        let s = offset.dot(axis);
+
+        (0.0..=length_squared).contains(&s)
+            && offset.magnitude_squared() - s * s / length_squared <= self.radius * self.radius
+    }
+
+    /// The distance along a ray at which it first touches this cylinder, in multiples of the
+    /// ray's direction. Returns zero if the ray starts inside the cylinder, and `None` if it
+    /// misses, or if the cylinder has zero length.
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        let axis = self.b - self.a;
+        let length_squared = axis.magnitude_squared();
+        if length_squared == 0.0 {
+            return None;
+        }
+
+        if self.contains(ray.origin) {
+            return Some(0.0);
+        }
+
+        let m = ray.origin - self.a;
+        let d = ray.direction;
+        let project = |v: Vector3| v - axis * (v.dot(axis) / length_squared);
+        let (dp, mp) = (project(d), project(m));
+
+        let side = roots(
+            dp.magnitude_squared(),
+            mp.dot(dp),
+            mp.magnitude_squared() - self.radius * self.radius,
+        )
+        .into_iter()
+        .flatten()
+        .filter(|&t| t >= 0.0 && (0.0..=length_squared).contains(&(m + d * t).dot(axis)));
+
+        let normal = axis / length_squared.sqrt();
+        let caps = [
+            intersect_disk(ray, self.a, normal, self.radius),
+            intersect_disk(ray, self.b, normal, self.radius),
+        ];
+
+        side.chain(caps.into_iter().flatten())
+            .min_by(f32::total_cmp)
+    }
+}
+
+/// A solid cone, such as the volume lit by a spotlight.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Cone {
+    /// The tip of the cone.
+    pub apex: Point,
+    /// The unit-length direction from the apex towards the base.
+    pub direction: Vector3,
+    /// The distance from the apex to the base, along `direction`.
+    pub height: f32,
+    /// The angle between the axis and the sloping surface, in radians.
+    pub half_angle: f32,
+}
+
+impl Cone {
+    /// Construct a cone from its apex, unit-length axis direction, height, and half-angle
+    pub const fn new(apex: Point, direction: Vector3, height: f32, half_angle: f32) -> Self {
+        Self {
+            apex,
+            direction,
+            height,
+            half_angle,
+        }
+    }
+
+    /// The radius of the base of the cone
+    pub fn base_radius(&self) -> f32 {
+        self.height * self.half_angle.tan()
+    }
+
+    /// Whether a point lies within this cone
+    pub fn contains(&self, point: Point) -> bool {
+        let offset = point - self.apex;
+        let h = offset.dot(self.direction);
+        let cos = self.half_angle.cos();
+
+        (0.0..=self.height).contains(&h) && h * h >= cos * cos * offset.magnitude_squared()
+    }
+
+    /// Whether this cone overlaps a sphere, as for culling lights against bounding volumes.
+    ///
+    /// Note that this is conservative near the rim of the base, where spheres just beyond the
+    /// base may be reported as intersecting.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let offset = sphere.center - self.apex;
+        let along = offset.dot(self.direction);
+        let across = (offset.magnitude_squared() - along * along).max(0.0).sqrt();
+        let (sin, cos) = self.half_angle.sin_cos();
+
+        // The distance from the center to the sloping surface, and to the planes at either end
+        let outside_slope = cos * across - sin * along > sphere.radius;
+        let beyond_base = along > self.height + sphere.radius;
+        let behind_apex = along < -sphere.radius;
+
+        !(outside_slope || beyond_base || behind_apex)
+    }
+
+    /// The distance along a ray at which it first touches this cone, in multiples of the
+    /// ray's direction. Returns zero if the ray starts inside the cone, and `None` if it misses.
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        if self.contains(ray.origin) {
+            return Some(0.0);
+        }
+
+        let (a, d, m) = (self.direction, ray.direction, ray.origin - self.apex);
+        let cos2 = self.half_angle.cos().powi(2);
+        let (da, ma) = (d.dot(a), m.dot(a));
+
+        let side = roots(
+            da * da - cos2 * d.magnitude_squared(),
+            da * ma - cos2 * d.dot(m),
+            ma * ma - cos2 * m.magnitude_squared(),
+        )
+        .into_iter()
+        .flatten()
+        .filter(|&t| t >= 0.0 && (0.0..=self.height).contains(&(m + d * t).dot(a)));
+
+        let base = intersect_disk(ray, self.apex + a * self.height, a, self.base_radius());
+
+        side.chain(base).min_by(f32::total_cmp)
+    }
+}
+
+/// Both roots of `a·t² + 2·b·t + c = 0`, if they are real
+fn roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2] {
+    let discriminant = b * b - a * c;
+    if a == 0.0 || discriminant < 0.0 {
+        return [None, None];
+    }
+
+    let root = discriminant.sqrt();
+    [Some((-b - root) / a), Some((-b + root) / a)]
+}
+
+/// The distance along a ray at which it crosses a flat disk, if it does so ahead of the origin
+fn intersect_disk(ray: &Ray, center: Point, normal: Vector3, radius: f32) -> Option<f32> {
+    let denominator = ray.direction.dot(normal);
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let t = (center - ray.origin).dot(normal) / denominator;
+    if t >= 0.0 && (ray.at(t) - center).magnitude_squared() <= radius * radius {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn cylinder() {
+        let cylinder = Cylinder::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 4.0, 0.0), 1.0);
+
+        assert!(cylinder.contains(Point::new(0.5, 3.5, 0.5)));
+        assert!(!cylinder.contains(Point::new(0.0, 4.5, 0.0)));
+        assert!(!cylinder.contains(Point::new(1.0, 2.0, 1.0)));
+
+        let side = Ray::new(Point::new(-5.0, 2.0, 0.0), Vector3::UNIT_X);
+        assert_nearly_eq!(cylinder.intersect_ray(&side).unwrap(), 4.0, epsilon = 1e-5);
+
+        let cap = Ray::new(Point::new(0.5, 10.0, 0.0), Vector3::DOWN);
+        assert_nearly_eq!(cylinder.intersect_ray(&cap).unwrap(), 6.0, epsilon = 1e-5);
+
+        let miss = Ray::new(Point::new(-5.0, 4.5, 0.0), Vector3::UNIT_X);
+        assert_eq!(cylinder.intersect_ray(&miss), None);
+
+        // Rays parallel to the axis can only enter through the caps
+        let outside_parallel = Ray::new(Point::new(1.5, -5.0, 0.0), Vector3::UP);
+        assert_eq!(cylinder.intersect_ray(&outside_parallel), None);
+
+        // A slanted cylinder, hit through its side by an unnormalized ray
+        let slanted = Cylinder::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 4.0, 0.0), 1.0);
+        let ray = Ray::new(Point::new(4.0, 0.0, 0.0), Vector3::new(-2.0, 2.0, 0.0));
+        let t = slanted.intersect_ray(&ray).unwrap();
+        assert_nearly_eq!(
+            (ray.at(t) - Point::new(2.0, 2.0, 0.0)).magnitude(),
+            1.0,
+            epsilon = 1e-5
+        );
+
+        let flat = Cylinder::new(Point::ORIGIN, Point::ORIGIN, 1.0);
+        assert_eq!(flat.intersect_ray(&side), None);
+    }
+
+    #[test]
+    fn cone() {
+        let spotlight = Cone::new(
+            Point::new(0.0, 10.0, 0.0),
+            Vector3::DOWN,
+            10.0,
+            std::f32::consts::FRAC_PI_4,
+        );
+
+        assert_nearly_eq!(spotlight.base_radius(), 10.0, epsilon = 1e-5);
+        assert!(spotlight.contains(Point::new(2.0, 5.0, 2.0)));
+        assert!(!spotlight.contains(Point::new(4.0, 5.0, 4.0)));
+        assert!(!spotlight.contains(Point::new(0.0, 11.0, 0.0)));
+
+        assert!(spotlight.intersects_sphere(&Sphere::new(Point::new(6.0, 5.0, 0.0), 1.0)));
+        assert!(!spotlight.intersects_sphere(&Sphere::new(Point::new(8.0, 5.0, 0.0), 1.0)));
+        assert!(!spotlight.intersects_sphere(&Sphere::new(Point::new(0.0, 12.0, 0.0), 1.0)));
+        assert!(!spotlight.intersects_sphere(&Sphere::new(Point::new(0.0, -2.0, 0.0), 1.0)));
+
+        let side = Ray::new(Point::new(-10.0, 5.0, 0.0), Vector3::UNIT_X);
+        assert_nearly_eq!(spotlight.intersect_ray(&side).unwrap(), 5.0, epsilon = 1e-5);
+
+        let base = Ray::new(Point::new(1.0, -5.0, 0.0), Vector3::UP);
+        assert_nearly_eq!(spotlight.intersect_ray(&base).unwrap(), 5.0, epsilon = 1e-5);
+
+        // The ray crosses the mirror-image nappe above the apex, which is not part of the cone
+        let above = Ray::new(Point::new(-10.0, 15.0, 0.0), Vector3::UNIT_X);
+        assert_eq!(spotlight.intersect_ray(&above), None);
+    }
+}
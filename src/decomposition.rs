@@ -0,0 +1,336 @@
+use crate::{Matrix4, Quaternion, Vector3, Vector4};
+
+impl Matrix4 {
+    /// Decompose this matrix into an orthogonal matrix `Q` and an upper-triangular matrix `R`,
+    /// such that `self == Q * R`, using Householder reflections.
+    ///
+    /// The diagonal of `R` is non-negative, so for a matrix composed of a rotation and a
+    /// positive scale, `Q` is the rotation. Note that `Q` contains a reflection if this matrix does.
+    pub fn qr(&self) -> (Matrix4, Matrix4) {
+        let mut q = Matrix4::identity();
+        let mut r = *self;
+
+        for k in 0..3 {
+            let norm = (k..4).map(|i| r.0[k][i] * r.0[k][i]).sum::<f32>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+
+            let alpha = if r.0[k][k] > 0.0 { -norm } else { norm };
+            let mut v = [0.0; 4];
+            for (i, v) in v.iter_mut().enumerate().skip(k) {
+                *v = r.0[k][i];
+            }
+            v[k] -= alpha;
+
+            let v_norm_squared: f32 = v.iter().map(|v| v * v).sum();
+            if v_norm_squared == 0.0 {
+                continue;
+            }
+
+            // Apply the reflection H = I - 2vvᵀ/(vᵀv) as R = HR and Q = QH
+            for c in 0..4 {
+                let d = 2.0 * (k..4).map(|i| v[i] * r.0[c][i]).sum::<f32>() / v_norm_squared;
+                for (i, v) in v.iter().enumerate().skip(k) {
+                    r.0[c][i] -= d * v;
+                }
+            }
+            for row in 0..4 {
+                let d = 2.0 * (k..4).map(|i| q.0[i][row] * v[i]).sum::<f32>() / v_norm_squared;
+                for (i, v) in v.iter().enumerate().skip(k) {
+                    q.0[i][row] -= d * v;
+                }
+            }
+        }
+
+        // Flip signs so that the diagonal of R is non-negative
+        for k in 0..4 {
+            if r.0[k][k] < 0.0 {
+                for c in 0..4 {
+                    r.0[c][k] = -r.0[c][k];
+                }
+                q.0[k] = -q.0[k];
+            }
+        }
+
+        // Clean up the round-off below the diagonal
+        for c in 0..4 {
+            for i in c + 1..4 {
+                r.0[c][i] = 0.0;
+            }
+        }
+
+        (q, r)
+    }
+
+    /// Decompose the upper 3x3 (rotation/scale/shear) part of this matrix into a rotation `R`
+    /// and a symmetric stretch `S`, such that the upper 3x3 is `R * S`.
+    /// The translation and projective parts of this matrix are ignored.
+    ///
+    /// Unlike simply normalizing the basis vectors, this extracts the rotation that is
+    /// closest to the original matrix even in the presence of shear or non-uniform scale.
+    /// If the matrix contains a reflection, `R` remains a proper rotation and the
+    /// reflection is carried by `S` (which then has a negative determinant).
+    pub fn polar_decomposition(&self) -> (Matrix4, Matrix4) {
+        let m = self.linear_part();
+        let sign = if m.linear_determinant() < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        // Newton iteration for the orthogonal polar factor, R = (R + R⁻ᵀ) / 2
+        let mut r = m.scaled_linear(sign);
+        for _ in 0..32 {
+            let next = r
+                .scaled_linear(0.5)
+                .added_linear(&r.invert().transpose().scaled_linear(0.5));
+            let converged = (0..3).all(|i| {
+                (Vector3::from(next.0[i]) - Vector3::from(r.0[i])).magnitude_squared() < 1e-12
+            });
+            r = next;
+            if converged {
+                break;
+            }
+        }
+
+        let s = r.transpose() * m;
+        // S is symmetric by construction; average out any round-off
+        let s = s
+            .scaled_linear(0.5)
+            .added_linear(&s.transpose().scaled_linear(0.5));
+
+        (r, s)
+    }
+
+    /// Decompose an affine transform without shear into its translation, rotation and scale,
+    /// such that `self == Matrix4::from_translation_rotation_scale(translation, rotation, scale)`.
+    ///
+    /// If this matrix contains a reflection (its determinant is negative), the x scale is
+    /// negated so that the rotation remains a proper rotation. Any shear is discarded; see
+    /// [`Self::polar_decomposition`] for matrices that contain it. The scale must be nonzero
+    /// along every axis.
+    pub fn decompose(&self) -> (Vector3, Quaternion, Vector3) {
+        let translation = Vector3::from(self.0[3]);
+
+        let [x, y, z] = [0, 1, 2].map(|i| Vector3::from(self.0[i]));
+        let mut scale = Vector3::new(x.magnitude(), y.magnitude(), z.magnitude());
+        if self.linear_determinant() < 0.0 {
+            scale.x = -scale.x;
+        }
+
+        let rotation = Matrix4::from_cols(
+            Vector4::from(x / scale.x),
+            Vector4::from(y / scale.y),
+            Vector4::from(z / scale.z),
+            Vector4::UNIT_W,
+        );
+
+        (
+            translation,
+            Quaternion::from_matrix(&rotation).normalized(),
+            scale,
+        )
+    }
+
+    /// The transform that scales along each axis, then rotates, then translates. This is the
+    /// inverse of [`Self::decompose`].
+    pub fn from_translation_rotation_scale(
+        translation: Vector3,
+        rotation: Quaternion,
+        scale: Vector3,
+    ) -> Self {
+        let mut m = rotation.to_matrix();
+        for i in 0..3 {
+            m.0[i] *= scale[i];
+        }
+        m.0[3] = Vector4::new(translation.x, translation.y, translation.z, 1.0);
+        m
+    }
+
+    /// Interpolate between this transform and another, by decomposing both into translation,
+    /// rotation, and stretch (scale and shear), interpolating those separately, and recomposing.
+    ///
+    /// Translation and stretch are interpolated linearly, and rotation along the shortest arc
+    /// at constant angular velocity, which avoids the shrinking and skewing that results from
+    /// interpolating matrix elements directly. The factor is clamped to `[0, 1]`.
+    pub fn interpolate(&self, other: &Matrix4, factor: f32) -> Matrix4 {
+        let t = factor.clamp(0.0, 1.0);
+
+        let translation = Vector3::from(self.0[3]).lerp(Vector3::from(other.0[3]), t);
+
+        let (r0, s0) = self.polar_decomposition();
+        let (r1, s1) = other.polar_decomposition();
+
+        let rotation = r0 * Matrix4::exp_rotation((r0.transpose() * r1).log_rotation() * t);
+        let stretch = s0.scaled_linear(1.0 - t).added_linear(&s1.scaled_linear(t));
+
+        Matrix4::translation(translation) * rotation * stretch
+    }
+
+    /// The upper 3x3 of this matrix, with the translation and projective parts cleared.
+    pub(crate) fn linear_part(&self) -> Matrix4 {
+        let mut m = *self;
+        for i in 0..3 {
+            m.0[i].w = 0.0;
+        }
+        m.0[3] = Vector4::new(0.0, 0.0, 0.0, 1.0);
+        m
+    }
+
+    /// The determinant of the upper 3x3 of this matrix.
+    pub(crate) fn linear_determinant(&self) -> f32 {
+        Vector3::from(self.0[0]).dot(Vector3::from(self.0[1]).cross(Vector3::from(self.0[2])))
+    }
+
+    fn scaled_linear(&self, s: f32) -> Matrix4 {
+        let mut m = *self;
+        for i in 0..3 {
+            m.0[i] = Vector4::from(Vector3::from(m.0[i]) * s);
+        }
+        m
+    }
+
+    fn added_linear(&self, rhs: &Matrix4) -> Matrix4 {
+        let mut m = *self;
+        for i in 0..3 {
+            m.0[i] = Vector4::from(Vector3::from(m.0[i]) + Vector3::from(rhs.0[i]));
+        }
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn qr() {
+        let m = Matrix4::from_2d_array([
+            [3.0, 2.0, 1.0, 1.0],
+            [2.0, 3.0, 2.0, 2.0],
+            [1.0, 2.0, 3.0, 3.0],
+            [0.0, 1.0, 1.0, 0.0],
+        ]);
+        let (q, r) = m.qr();
+
+        assert_nearly_eq!(&(q * r), &m, epsilon = 1e-5);
+        assert_nearly_eq!(&(q.transpose() * q), &Matrix4::identity(), epsilon = 1e-5);
+        for c in 0..4 {
+            assert!(r.0[c][c] >= 0.0);
+            for i in c + 1..4 {
+                assert_eq!(r.0[c][i], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn polar() {
+        let rotation = Matrix4::rotation_axis_angle(Vector3::new(0.6, 0.0, 0.8), -0.7);
+        let shear = Matrix4::from_2d_array([
+            [2.0, 0.0, 0.0, 0.0],
+            [0.5, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.5, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * rotation * shear;
+        let (r, s) = m.polar_decomposition();
+
+        assert_nearly_eq!(&(r * s), &m.linear_part(), epsilon = 1e-5);
+        assert_nearly_eq!(&(r.transpose() * r), &Matrix4::identity(), epsilon = 1e-5);
+        assert_nearly_eq!(&s, &s.transpose(), epsilon = 1e-5);
+        assert!(r.linear_determinant() > 0.0);
+
+        let scaled = rotation * Matrix4::uniform_scale(2.0);
+        let (r, s) = scaled.polar_decomposition();
+        assert_nearly_eq!(&r, &rotation, epsilon = 1e-5);
+        assert_nearly_eq!(&s, &Matrix4::uniform_scale(2.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn polar_mirrored() {
+        let rotation = Matrix4::rotation_y(0.3);
+        let mirror = Matrix4::from_2d_array([
+            [-1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let m = rotation * mirror;
+        let (r, s) = m.polar_decomposition();
+
+        assert_nearly_eq!(&(r * s), &m, epsilon = 1e-5);
+        assert!(r.linear_determinant() > 0.0);
+        assert!(s.linear_determinant() < 0.0);
+    }
+
+    #[test]
+    fn interpolate() {
+        let a = Matrix4::identity();
+        let b = Matrix4::translation(Vector3::new(2.0, 4.0, 0.0))
+            * Matrix4::rotation_z(std::f32::consts::FRAC_PI_2)
+            * Matrix4::uniform_scale(3.0);
+
+        let mid = a.interpolate(&b, 0.5);
+        let expected = Matrix4::translation(Vector3::new(1.0, 2.0, 0.0))
+            * Matrix4::rotation_z(std::f32::consts::FRAC_PI_4)
+            * Matrix4::uniform_scale(2.0);
+
+        assert_nearly_eq!(&mid, &expected, epsilon = 1e-5);
+        assert_nearly_eq!(&a.interpolate(&b, 0.0), &a, epsilon = 1e-5);
+        assert_nearly_eq!(&a.interpolate(&b, 1.0), &b, epsilon = 1e-5);
+        assert_nearly_eq!(&a.interpolate(&b, 2.0), &b, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn qr_extracts_rotation() {
+        let rotation = Matrix4::rotation_axis_angle(Vector3::new(0.0, 0.6, 0.8), 1.2);
+        let m = rotation * Matrix4::uniform_scale(3.0);
+        let (q, r) = m.qr();
+
+        assert_nearly_eq!(&q, &rotation, epsilon = 1e-5);
+        assert_nearly_eq!(&r, &Matrix4::uniform_scale(3.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn decompose() {
+        let translation = Vector3::new(1.0, -2.0, 3.0);
+        let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), 1.2);
+        let scale = Vector3::new(2.0, 0.5, 3.0);
+        let m = Matrix4::from_translation_rotation_scale(translation, rotation, scale);
+
+        assert_nearly_eq!(
+            &m,
+            &(Matrix4::translation(translation)
+                * rotation.to_matrix()
+                * Matrix4::from(Matrix3::from_2d_array([
+                    [2.0, 0.0, 0.0],
+                    [0.0, 0.5, 0.0],
+                    [0.0, 0.0, 3.0]
+                ]))),
+            epsilon = 1e-6
+        );
+
+        let (t, r, s) = m.decompose();
+        assert_eq!(t, translation);
+        assert_nearly_eq!(r.dot(rotation).abs(), 1.0, epsilon = 1e-6);
+        assert_nearly_eq!(&s, &scale, epsilon = 1e-5);
+
+        // A mirrored matrix keeps a proper rotation, carrying the reflection in the scale
+        let mirrored = m * Matrix4::from(Matrix3::from_2d_array([
+            [1.0, 0.0, 0.0],
+            [0.0, -1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ]));
+        let (t, r, s) = mirrored.decompose();
+        assert_eq!(t, translation);
+        assert!(s.x < 0.0);
+        assert!(r.to_matrix().is_orthonormal(1e-5));
+        assert!(r.to_matrix().linear_determinant() > 0.0);
+        assert_nearly_eq!(
+            &Matrix4::from_translation_rotation_scale(t, r, s),
+            &mirrored,
+            epsilon = 1e-5
+        );
+    }
+}
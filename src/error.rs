@@ -0,0 +1,70 @@
+/// The ways in which fallible operations in this crate can fail
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MathError {
+    /// The matrix has no inverse, as its determinant is zero
+    SingularMatrix,
+    /// The vector has no direction, as its length is zero
+    ZeroVector,
+    /// The slice does not hold the number of elements required
+    InvalidSliceLength { expected: usize, actual: usize },
+}
+
+impl std::fmt::Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SingularMatrix => f.write_str("matrix is singular"),
+            Self::ZeroVector => f.write_str("vector has zero length"),
+            Self::InvalidSliceLength { expected, actual } => {
+                write!(
+                    f,
+                    "expected {} elements, but the slice has {}",
+                    expected, actual
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn fallible() {
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(m.try_invert(), Ok(m.invert()));
+        assert_eq!(
+            Matrix4::uniform_scale(0.0).try_invert(),
+            Err(MathError::SingularMatrix)
+        );
+
+        assert_eq!(
+            Vector3::new(0.0, 3.0, 4.0).try_normalize(),
+            Ok(Vector3::new(0.0, 0.6, 0.8))
+        );
+        assert_eq!(Vector2::zero().try_normalize(), Err(MathError::ZeroVector));
+
+        assert_eq!(
+            Vector3::from_slice(&[1.0, 2.0, 3.0]),
+            Ok(Vector3::new(1.0, 2.0, 3.0))
+        );
+        assert_eq!(
+            Vector4::from_slice(&[1.0, 2.0]),
+            Err(MathError::InvalidSliceLength {
+                expected: 4,
+                actual: 2
+            })
+        );
+        assert_eq!(Matrix4::from_slice(m.as_slice()), Ok(m));
+        assert_eq!(
+            MathError::InvalidSliceLength {
+                expected: 16,
+                actual: 3
+            }
+            .to_string(),
+            "expected 16 elements, but the slice has 3"
+        );
+    }
+}
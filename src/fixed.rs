@@ -0,0 +1,366 @@
+use crate::{Matrix4, Point, Vector2, Vector3, Vector4};
+
+/// A signed Q16.16 fixed-point number.
+///
+/// Arithmetic wraps on overflow, so results are bit-for-bit identical across
+/// platforms and build profiles, which makes this suitable for deterministic simulation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Fixed(pub i32);
+
+impl Fixed {
+    /// The number of fractional bits.
+    pub const FRACTIONAL_BITS: u32 = 16;
+
+    /// The additive identity.
+    pub const ZERO: Fixed = Fixed(0);
+    /// The multiplicative identity.
+    pub const ONE: Fixed = Fixed(1 << Self::FRACTIONAL_BITS);
+
+    /// Construct from the raw Q16.16 bit pattern
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// Construct from an integer. Note that only the range of an `i16` is representable.
+    pub const fn from_int(i: i32) -> Self {
+        Self(i.wrapping_shl(Self::FRACTIONAL_BITS))
+    }
+
+    /// Construct from a floating-point value, rounding to the nearest representable value
+    pub fn from_f32(f: f32) -> Self {
+        Self((f * Self::ONE.0 as f32).round() as i32)
+    }
+
+    /// The raw Q16.16 bit pattern
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Convert to a floating-point value
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    /// The absolute value
+    pub const fn abs(self) -> Self {
+        Self(self.0.wrapping_abs())
+    }
+}
+
+impl From<f32> for Fixed {
+    fn from(f: f32) -> Self {
+        Self::from_f32(f)
+    }
+}
+
+impl From<Fixed> for f32 {
+    fn from(f: Fixed) -> Self {
+        f.to_f32()
+    }
+}
+
+impl std::ops::Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Fixed {
+        Fixed(self.0.wrapping_neg())
+    }
+}
+
+impl std::ops::Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl std::ops::Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl std::ops::Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, rhs: Fixed) -> Fixed {
+        Fixed(((self.0 as i64 * rhs.0 as i64) >> Fixed::FRACTIONAL_BITS) as i32)
+    }
+}
+
+impl std::ops::Div for Fixed {
+    type Output = Fixed;
+    /// Divide, panicking if `rhs` is zero.
+    fn div(self, rhs: Fixed) -> Fixed {
+        Fixed((((self.0 as i64) << Fixed::FRACTIONAL_BITS) / rhs.0 as i64) as i32)
+    }
+}
+
+impl std::ops::AddAssign for Fixed {
+    fn add_assign(&mut self, rhs: Fixed) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::SubAssign for Fixed {
+    fn sub_assign(&mut self, rhs: Fixed) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::MulAssign for Fixed {
+    fn mul_assign(&mut self, rhs: Fixed) {
+        *self = *self * rhs;
+    }
+}
+
+impl std::ops::DivAssign for Fixed {
+    fn div_assign(&mut self, rhs: Fixed) {
+        *self = *self / rhs;
+    }
+}
+
+/// A vector in 2D space, with fixed-point coordinates.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FixedVector2 {
+    pub x: Fixed,
+    pub y: Fixed,
+}
+
+/// A vector in 3D space, with fixed-point coordinates.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FixedVector3 {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+}
+
+/// A point in 3D space, with fixed-point coordinates.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FixedPoint {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+}
+
+macro_rules! implement_fixed_vector {
+    ($VectorT:ident, $FloatT:ident { $($field:ident),+ }) => {
+        impl $VectorT {
+            /// Construct new a vector from individual coordinates
+            pub const fn new($($field: Fixed),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// The additive identity
+            pub const fn zero() -> Self {
+                Self { $($field: Fixed::ZERO),+ }
+            }
+
+            /// Compute the dot product between this vector and another
+            pub fn dot(&self, rhs: Self) -> Fixed {
+                let mut sum = Fixed::ZERO;
+                $(sum += self.$field * rhs.$field;)+
+                sum
+            }
+        }
+
+        impl From<$FloatT> for $VectorT {
+            /// Convert from floating-point, rounding each coordinate to the nearest representable value
+            fn from(v: $FloatT) -> Self {
+                Self::new($(Fixed::from_f32(v.$field)),+)
+            }
+        }
+
+        impl From<$VectorT> for $FloatT {
+            /// Convert to floating-point
+            fn from(v: $VectorT) -> Self {
+                Self::new($(v.$field.to_f32()),+)
+            }
+        }
+
+        impl std::ops::Neg for $VectorT {
+            type Output = $VectorT;
+            fn neg(self) -> $VectorT { $VectorT::new($(-self.$field),+) }
+        }
+
+        impl std::ops::Mul<Fixed> for $VectorT {
+            type Output = $VectorT;
+            fn mul(self, t: Fixed) -> $VectorT { $VectorT::new($(self.$field * t),+) }
+        }
+
+        impl std::ops::Div<Fixed> for $VectorT {
+            type Output = $VectorT;
+            fn div(self, t: Fixed) -> $VectorT { $VectorT::new($(self.$field / t),+) }
+        }
+    };
+}
+
+implement_fixed_vector!(FixedVector2, Vector2 { x, y });
+implement_fixed_vector!(FixedVector3, Vector3 { x, y, z });
+implement_fixed_vector!(FixedPoint, Point { x, y, z });
+
+macro_rules! fixed_op {
+    (impl $trait:ident<$other_type:ty> for $type:ty {
+        fn $op_fn:ident -> $result_type:ty, $op:tt { $($field:ident),+ }
+    }) => {
+        impl std::ops::$trait<$other_type> for $type {
+            type Output = $result_type;
+
+            fn $op_fn(self, rhs: $other_type) -> $result_type {
+                <$result_type>::new($(self.$field $op rhs.$field),+)
+            }
+        }
+    };
+}
+
+fixed_op!(impl Add<FixedVector2> for FixedVector2 { fn add -> FixedVector2, + {x, y} });
+fixed_op!(impl Sub<FixedVector2> for FixedVector2 { fn sub -> FixedVector2, - {x, y} });
+fixed_op!(impl Add<FixedVector3> for FixedVector3 { fn add -> FixedVector3, + {x, y, z} });
+fixed_op!(impl Sub<FixedVector3> for FixedVector3 { fn sub -> FixedVector3, - {x, y, z} });
+fixed_op!(impl Add<FixedVector3> for FixedPoint { fn add -> FixedPoint, + {x, y, z} });
+fixed_op!(impl Sub<FixedVector3> for FixedPoint { fn sub -> FixedPoint, - {x, y, z} });
+fixed_op!(impl Sub<FixedPoint> for FixedPoint { fn sub -> FixedVector3, - {x, y, z} });
+
+impl FixedVector3 {
+    /// Compute the cross product between this vector and another.
+    pub fn cross(&self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
+/// A 4x4 affine transformation matrix, with fixed-point elements.
+///
+/// Elements are stored in the same column-major layout as [`Matrix4`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct FixedMatrix4(pub [[Fixed; 4]; 4]);
+
+impl FixedMatrix4 {
+    /// The identity matrix.
+    pub const fn identity() -> Self {
+        let (o, i) = (Fixed::ZERO, Fixed::ONE);
+        Self([[i, o, o, o], [o, i, o, o], [o, o, i, o], [o, o, o, i]])
+    }
+
+    fn transform(&self, v: [Fixed; 3], w: Fixed) -> [Fixed; 3] {
+        let m = &self.0;
+        let mut r = [Fixed::ZERO; 3];
+        for (j, r) in r.iter_mut().enumerate() {
+            *r = m[0][j] * v[0] + m[1][j] * v[1] + m[2][j] * v[2] + m[3][j] * w;
+        }
+        r
+    }
+}
+
+impl From<Matrix4> for FixedMatrix4 {
+    /// Convert from floating-point, rounding each element to the nearest representable value
+    fn from(m: Matrix4) -> Self {
+        let mut r = Self::default();
+        for i in 0..4 {
+            for j in 0..4 {
+                r.0[i][j] = Fixed::from_f32(m.0[i][j]);
+            }
+        }
+        r
+    }
+}
+
+impl From<FixedMatrix4> for Matrix4 {
+    /// Convert to floating-point
+    fn from(m: FixedMatrix4) -> Self {
+        let c = |i: usize| {
+            Vector4::new(
+                m.0[i][0].to_f32(),
+                m.0[i][1].to_f32(),
+                m.0[i][2].to_f32(),
+                m.0[i][3].to_f32(),
+            )
+        };
+        Matrix4([c(0), c(1), c(2), c(3)])
+    }
+}
+
+impl std::ops::Mul for FixedMatrix4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut result = Self::default();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                result.0[i][j] = rhs.0[i][0] * self.0[0][j]
+                    + rhs.0[i][1] * self.0[1][j]
+                    + rhs.0[i][2] * self.0[2][j]
+                    + rhs.0[i][3] * self.0[3][j];
+            }
+        }
+
+        result
+    }
+}
+
+impl std::ops::Mul<FixedPoint> for FixedMatrix4 {
+    type Output = FixedPoint;
+
+    fn mul(self, rhs: FixedPoint) -> FixedPoint {
+        let [x, y, z] = self.transform([rhs.x, rhs.y, rhs.z], Fixed::ONE);
+        FixedPoint::new(x, y, z)
+    }
+}
+
+impl std::ops::Mul<FixedVector3> for FixedMatrix4 {
+    type Output = FixedVector3;
+
+    fn mul(self, rhs: FixedVector3) -> FixedVector3 {
+        let [x, y, z] = self.transform([rhs.x, rhs.y, rhs.z], Fixed::ZERO);
+        FixedVector3::new(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Fixed::from_f32(1.5);
+        let b = Fixed::from_int(-2);
+
+        assert_eq!(a + b, Fixed::from_f32(-0.5));
+        assert_eq!(a * b, Fixed::from_int(-3));
+        assert_eq!(b / a, Fixed::from_f32(-4.0 / 3.0));
+        assert_eq!(Fixed::from_bits(1).to_f32(), 1.0 / 65536.0);
+    }
+
+    #[test]
+    fn vectors() {
+        let a = FixedVector3::from(Vector3::new(3.0, -5.0, 4.0));
+        let b = FixedVector3::from(Vector3::new(2.0, 6.0, 5.0));
+
+        assert_eq!(a.dot(b), Fixed::from_int(-4));
+        assert_eq!(Vector3::from(a.cross(b)), Vector3::new(-49.0, -7.0, 28.0));
+
+        let p = FixedPoint::from(Point::new(0.25, 0.5, 0.75));
+        assert_eq!(Point::from(p + a - a), Point::new(0.25, 0.5, 0.75));
+    }
+
+    #[test]
+    fn matrix() {
+        let m = FixedMatrix4::from(Matrix4::translation(Vector3::new(10.0, 1.0, 0.0)));
+        let p = FixedPoint::from(Point::new(1.0, 2.0, 3.0));
+
+        assert_eq!(Point::from(m * p), Point::new(11.0, 3.0, 3.0));
+        assert_eq!(
+            m * FixedVector3::from(Vector3::one()),
+            FixedVector3::from(Vector3::one())
+        );
+        assert_eq!(m * FixedMatrix4::identity(), m);
+    }
+}
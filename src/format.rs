@@ -0,0 +1,110 @@
+/// A fixed-size text buffer implementing [`std::fmt::Write`], for formatting values without
+/// allocating, as when drawing them in a HUD every frame.
+///
+/// Writes that do not fit are truncated at a character boundary, and report an error.
+///
+/// Vectors and matrices format into it (or any other [`std::fmt::Write`] sink) with `write_to`,
+/// which produces the same text as their [`Display`](std::fmt::Display) implementations, but
+/// without the `String` that `to_string` allocates.
+///
+/// ```
+/// # use mini_math::*;
+/// use std::fmt::Write;
+///
+/// let mut buffer = FixedBuffer::<64>::new();
+/// write!(buffer, "{:.1}", Vector3::new(1.0, 2.5, -3.25)).unwrap();
+/// assert_eq!(buffer.as_str(), "(1.0, 2.5, -3.2)");
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedBuffer<N> {
+    /// Construct an empty buffer
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// The text written so far
+    pub fn as_str(&self) -> &str {
+        // Only whole characters are ever copied in, so this is always valid UTF-8
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// The length of the text written so far, in bytes
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether nothing has been written
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discard the text written so far, so that the buffer can be reused
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for FixedBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> std::fmt::Write for FixedBuffer<N> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let available = N - self.len;
+        let mut count = s.len().min(available);
+        while !s.is_char_boundary(count) {
+            count -= 1;
+        }
+
+        self.bytes[self.len..self.len + count].copy_from_slice(&s.as_bytes()[..count]);
+        self.len += count;
+
+        if count < s.len() {
+            Err(std::fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn format() {
+        let mut buffer = FixedBuffer::<128>::new();
+        Vector2::new(1.5, -2.0).write_to(&mut buffer).unwrap();
+        assert_eq!(buffer.as_str(), "(1.5, -2)");
+
+        buffer.clear();
+        Matrix4::translation(Vector3::new(1.0, 2.0, 3.0))
+            .write_to(&mut buffer)
+            .unwrap();
+        assert_eq!(
+            buffer.as_str(),
+            "(1, 0, 0, 1)\n(0, 1, 0, 2)\n(0, 0, 1, 3)\n(0, 0, 0, 1)"
+        );
+        assert_eq!(
+            format!("{:.2}", Matrix3::identity().row(0)),
+            "(1.00, 0.00, 0.00)"
+        );
+
+        // Overflowing text is truncated at a character boundary
+        let mut small = FixedBuffer::<4>::new();
+        assert!(std::fmt::Write::write_str(&mut small, "ab°c").is_err());
+        assert_eq!(small.as_str(), "ab°");
+        assert!(Point::ORIGIN.write_to(&mut small).is_err());
+        assert_eq!(small.len(), 4);
+    }
+}
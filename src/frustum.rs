@@ -0,0 +1,233 @@
+use crate::{Aabb, DepthRange, Matrix4, Obb, Plane, Point, Side, Vector3, Vector4};
+
+/// A view frustum, bounded by six planes whose normals face inwards.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Frustum {
+    /// The left, right, bottom, top, near and far planes, in that order.
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Extract the frustum from a combined view-projection matrix, which must produce clip
+    /// coordinates in the OpenGL convention of `[-w, w]` along each axis (as
+    /// [`Matrix4::perspective`] and [`Matrix4::orthographic`] do).
+    pub fn from_matrix(m: &Matrix4) -> Self {
+        Self::from_matrix_with_depth_range(m, DepthRange::NegativeOneToOne)
+    }
+
+    /// Extract the frustum from a combined view-projection matrix producing depths in the given
+    /// range, such as one built with [`Matrix4::perspective_with_depth_range`] or
+    /// [`Matrix4::perspective_reversed_z`].
+    ///
+    /// For projections without a far plane, the far plane has a zero normal, which every point
+    /// is in front of, and the far corners are not finite.
+    pub fn from_matrix_with_depth_range(m: &Matrix4, depth_range: DepthRange) -> Self {
+        let plane = |v: Vector4| Plane::new(Vector3::from(v), v.w).normalized();
+        let (x, y, z, w) = (m.row(0), m.row(1), m.row(2), m.row(3));
+        let (near, far) = match depth_range {
+            DepthRange::NegativeOneToOne => (w + z, w - z),
+            DepthRange::ZeroToOne => (z, w - z),
+            DepthRange::OneToZero => (w - z, z),
+        };
+
+        Self {
+            planes: [
+                plane(w + x),
+                plane(w - x),
+                plane(w + y),
+                plane(w - y),
+                plane(near),
+                plane(far),
+            ],
+        }
+    }
+
+    /// Whether a point lies within the frustum
+    pub fn contains_point(&self, point: Point) -> bool {
+        self.planes.iter().all(|p| p.signed_distance(point) >= 0.0)
+    }
+
+    /// Whether a sphere might overlap the frustum.
+    ///
+    /// Note that this is conservative: spheres near the corners of the frustum may be reported
+    /// as intersecting when they lie just outside.
+    pub fn intersects_sphere(&self, center: Point, radius: f32) -> bool {
+        self.planes
+            .iter()
+            .all(|p| p.signed_distance(center) >= -radius)
+    }
+
+    /// Whether a box might overlap the frustum.
+    ///
+    /// Note that this is conservative in the same way as [`Self::intersects_sphere`].
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes
+            .iter()
+            .all(|p| p.classify_aabb(aabb) != Side::Back)
+    }
+
+    /// The eight corners of the frustum, found by intersecting its planes.
+    ///
+    /// Corners are ordered with the x-axis varying fastest, from left to right, then bottom to
+    /// top, then near to far.
+    pub fn corners(&self) -> [Point; 8] {
+        std::array::from_fn(|i| {
+            let [x, y, z] = [i & 1, (i >> 1) & 1, (i >> 2) & 1];
+            intersect_planes(&self.planes[x], &self.planes[2 + y], &self.planes[4 + z])
+        })
+    }
+
+    /// Whether an oriented box overlaps the frustum, tested exactly using the separating axis
+    /// theorem.
+    ///
+    /// Unlike the plane tests, this has no false positives near the corners of the frustum,
+    /// at the cost of projecting onto up to 29 axes.
+    pub fn intersects_obb(&self, obb: &Obb) -> bool {
+        let corners = self.corners();
+        let edges = [
+            corners[1] - corners[0],
+            corners[2] - corners[0],
+            corners[4] - corners[0],
+            corners[5] - corners[1],
+            corners[6] - corners[2],
+            corners[7] - corners[3],
+        ];
+
+        let separated = |axis: Vector3| {
+            let (lo, hi) = obb.project(axis);
+            let (mut min, mut max) = (f32::INFINITY, f32::NEG_INFINITY);
+            for c in &corners {
+                let d = Vector3::from(*c).dot(axis);
+                min = min.min(d);
+                max = max.max(d);
+            }
+            hi < min || lo > max
+        };
+
+        let face_normals = self.planes.iter().map(|p| p.normal);
+        let box_axes = obb.axes.iter().copied();
+        let cross_axes = obb
+            .axes
+            .iter()
+            .flat_map(|a| edges.iter().map(move |e| a.cross(*e)))
+            .filter(|axis| axis.magnitude_squared() > 1e-12);
+
+        !face_normals
+            .chain(box_axes)
+            .chain(cross_axes)
+            .any(separated)
+    }
+}
+
+/// The point where three planes meet, which must not be parallel
+fn intersect_planes(a: &Plane, b: &Plane, c: &Plane) -> Point {
+    let bc = b.normal.cross(c.normal);
+    let ca = c.normal.cross(a.normal);
+    let ab = a.normal.cross(b.normal);
+    let denominator = a.normal.dot(bc);
+
+    Point::from((bc * a.d + ca * b.d + ab * c.d) / -denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn perspective() {
+        let view = Matrix4::look_at(Point::new(0.0, 0.0, 5.0), Point::ORIGIN, Vector3::UP);
+        let projection = Matrix4::perspective(1.0, std::f32::consts::FRAC_PI_2, 1.0, 100.0);
+        let frustum = Frustum::from_matrix(&(projection * view));
+
+        assert!(frustum.contains_point(Point::ORIGIN));
+        assert!(frustum.contains_point(Point::new(3.0, 3.0, 0.0)));
+        assert!(!frustum.contains_point(Point::new(6.0, 0.0, 0.0)));
+        assert!(!frustum.contains_point(Point::new(0.0, 0.0, 4.5)));
+        assert!(!frustum.contains_point(Point::new(0.0, 0.0, -100.0)));
+
+        assert!(frustum.intersects_sphere(Point::new(6.0, 0.0, 0.0), 1.0));
+        assert!(!frustum.intersects_sphere(Point::new(8.0, 0.0, 0.0), 1.0));
+        assert!(frustum.intersects_aabb(&Aabb::new(
+            Point::new(5.0, -1.0, -1.0),
+            Point::new(7.0, 1.0, 1.0)
+        )));
+        assert!(!frustum.intersects_aabb(&Aabb::new(
+            Point::new(7.0, -1.0, -1.0),
+            Point::new(9.0, 1.0, 1.0)
+        )));
+        assert_nearly_eq!(
+            frustum.planes[4].signed_distance(Point::ORIGIN),
+            4.0,
+            epsilon = 1e-4
+        );
+
+        let corners = frustum.corners();
+        assert_nearly_eq!(&corners[0], &Point::new(-1.0, -1.0, 4.0), epsilon = 1e-4);
+        assert_nearly_eq!(
+            &corners[7],
+            &Point::new(100.0, 100.0, -95.0),
+            epsilon = 1e-2
+        );
+    }
+
+    #[test]
+    fn depth_ranges() {
+        let view = Matrix4::look_at(Point::new(0.0, 0.0, 5.0), Point::ORIGIN, Vector3::UP);
+        let fov = std::f32::consts::FRAC_PI_2;
+        let expected = Frustum::from_matrix(&(Matrix4::perspective(1.0, fov, 1.0, 100.0) * view));
+
+        for depth_range in [DepthRange::ZeroToOne, DepthRange::OneToZero] {
+            let projection =
+                Matrix4::perspective_with_depth_range(1.0, fov, 1.0, 100.0, depth_range);
+            let frustum = Frustum::from_matrix_with_depth_range(&(projection * view), depth_range);
+
+            assert_nearly_eq!(
+                &frustum.corners()[..],
+                &expected.corners()[..],
+                epsilon = 1e-2
+            );
+            assert!(!frustum.contains_point(Point::new(0.0, 0.0, 4.5)));
+            assert!(frustum.contains_point(Point::new(0.0, 0.0, -90.0)));
+            assert!(!frustum.contains_point(Point::new(0.0, 0.0, -100.0)));
+        }
+
+        let infinite = Matrix4::perspective_infinite_reversed_z(1.0, fov, 1.0) * view;
+        let frustum = Frustum::from_matrix_with_depth_range(&infinite, DepthRange::OneToZero);
+        assert!(!frustum.contains_point(Point::new(0.0, 0.0, 4.5)));
+        assert!(frustum.contains_point(Point::new(0.0, 0.0, -1e6)));
+    }
+
+    #[test]
+    fn oriented_box() {
+        let view = Matrix4::look_at(Point::new(0.0, 0.0, 5.0), Point::ORIGIN, Vector3::UP);
+        let projection = Matrix4::perspective(1.0, std::f32::consts::FRAC_PI_2, 1.0, 10.0);
+        let frustum = Frustum::from_matrix(&(projection * view));
+
+        // A long, thin box diagonally beyond the top right corner of the frustum, which the
+        // per-plane test cannot reject
+        let diagonal = Vector3::new(1.0, -1.0, 0.0).normalized();
+        let obb = Obb::new(
+            Point::new(5.0, 5.0, 0.0) + Vector3::new(1.0, 1.0, 0.0).normalized() * 1.2,
+            [
+                diagonal,
+                Vector3::new(1.0, 1.0, 0.0).normalized(),
+                Vector3::UNIT_Z,
+            ],
+            Vector3::new(8.0, 0.2, 0.2),
+        );
+        let plane_test = frustum
+            .planes
+            .iter()
+            .all(|p| obb.project(p.normal).1 + p.d >= 0.0);
+        assert!(plane_test);
+        assert!(!frustum.intersects_obb(&obb));
+
+        let inside = Obb::from(Aabb::new(
+            Point::new(-1.0, -1.0, -1.0),
+            Point::new(1.0, 1.0, 1.0),
+        ));
+        assert!(frustum.intersects_obb(&inside));
+        let behind = Obb::new(Point::new(0.0, 0.0, 8.0), inside.axes, inside.half_extents);
+        assert!(!frustum.intersects_obb(&behind));
+    }
+}
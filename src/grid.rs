@@ -0,0 +1,71 @@
+use crate::{Aabb, Point, Vector3};
+
+/// A regular lattice of box-shaped cells, for mapping between points and integer cell
+/// coordinates in voxel grids, heightmaps and spatial hashes.
+///
+/// Cell `[0, 0, 0]` spans from `origin` to `origin + cell_size`. The lattice is unbounded, and
+/// cells before the origin have negative coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Grid {
+    pub origin: Point,
+    pub cell_size: Vector3,
+}
+
+impl Grid {
+    /// Construct a lattice from its origin and the size of each cell
+    pub const fn new(origin: Point, cell_size: Vector3) -> Self {
+        Self { origin, cell_size }
+    }
+
+    /// Construct a lattice of cubic cells of the given size, with its origin at the world origin
+    pub const fn uniform(cell_size: f32) -> Self {
+        Self::new(Point::ORIGIN, Vector3::from_scalar(cell_size))
+    }
+
+    /// The coordinates of the cell containing a point. Points on a boundary between cells
+    /// belong to the cell after it.
+    pub fn cell(&self, point: Point) -> [i32; 3] {
+        let p = (point - self.origin) / self.cell_size;
+        [p.x, p.y, p.z].map(|c| c.floor() as i32)
+    }
+
+    /// The corner of a cell nearest the origin
+    pub fn cell_min(&self, cell: [i32; 3]) -> Point {
+        let [x, y, z] = cell.map(|c| c as f32);
+        self.origin + Vector3::new(x, y, z) * self.cell_size
+    }
+
+    /// The center of a cell
+    pub fn cell_center(&self, cell: [i32; 3]) -> Point {
+        self.cell_min(cell) + self.cell_size * 0.5
+    }
+
+    /// The box covered by a cell
+    pub fn cell_aabb(&self, cell: [i32; 3]) -> Aabb {
+        let min = self.cell_min(cell);
+        Aabb::new(min, min + self.cell_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn cells() {
+        let grid = Grid::new(Point::new(10.0, 0.0, 0.0), Vector3::new(2.0, 1.0, 0.5));
+
+        assert_eq!(grid.cell(Point::new(10.0, 0.0, 0.0)), [0, 0, 0]);
+        assert_eq!(grid.cell(Point::new(13.5, -0.5, 1.0)), [1, -1, 2]);
+        assert_eq!(grid.cell_min([1, -1, 2]), Point::new(12.0, -1.0, 1.0));
+        assert_eq!(grid.cell_center([1, -1, 2]), Point::new(13.0, -0.5, 1.25));
+        assert_eq!(
+            grid.cell_aabb([0, 0, 0]),
+            Aabb::new(Point::new(10.0, 0.0, 0.0), Point::new(12.0, 1.0, 0.5))
+        );
+
+        let p = Point::new(-3.7, 8.2, 0.1);
+        let uniform = Grid::uniform(0.25);
+        assert!(uniform.cell_aabb(uniform.cell(p)).contains(p));
+    }
+}
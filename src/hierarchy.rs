@@ -0,0 +1,63 @@
+use crate::Matrix4;
+
+/// Compute the world transform of every node in a hierarchy from its local transform, without
+/// allocating.
+///
+/// Each node's parent is given by its index, or `None` for a root. Nodes must be in
+/// topological order, with every parent before its children, so that a single pass suffices.
+///
+/// # Panics
+///
+/// Panics if the slices differ in length, or if a node's parent does not come before it.
+pub fn propagate_transforms(parents: &[Option<u32>], locals: &[Matrix4], worlds: &mut [Matrix4]) {
+    assert_eq!(parents.len(), locals.len());
+    assert_eq!(parents.len(), worlds.len());
+
+    for (i, (parent, local)) in parents.iter().zip(locals).enumerate() {
+        worlds[i] = match *parent {
+            Some(parent) => {
+                let parent = parent as usize;
+                assert!(parent < i, "node {i} comes before its parent {parent}");
+                worlds[parent] * *local
+            }
+            None => *local,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn propagate() {
+        let parents = [None, Some(0), Some(1), None, Some(0)];
+        let locals = [
+            Matrix4::translation(Vector3::new(1.0, 0.0, 0.0)),
+            Matrix4::rotation_z(std::f32::consts::FRAC_PI_2),
+            Matrix4::translation(Vector3::new(0.0, 2.0, 0.0)),
+            Matrix4::uniform_scale(3.0),
+            Matrix4::uniform_scale(2.0),
+        ];
+        let mut worlds = [Matrix4::zero(); 5];
+        propagate_transforms(&parents, &locals, &mut worlds);
+
+        assert_eq!(worlds[0], locals[0]);
+        assert_eq!(worlds[2], locals[0] * locals[1] * locals[2]);
+        assert_eq!(worlds[3], locals[3]);
+        assert_eq!(worlds[4], locals[0] * locals[4]);
+        // The grandchild's offset is rotated clockwise by its parent, then translated
+        assert_nearly_eq!(
+            &(worlds[2] * Point::ORIGIN),
+            &Point::new(3.0, 0.0, 0.0),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "comes before its parent")]
+    fn out_of_order() {
+        let mut worlds = [Matrix4::zero(); 2];
+        propagate_transforms(&[Some(1), None], &[Matrix4::identity(); 2], &mut worlds);
+    }
+}
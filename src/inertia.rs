@@ -0,0 +1,107 @@
+//! Inertia tensors of solid primitives with uniform density, for setting up rigid bodies.
+//!
+//! Each tensor is about the shape's center of mass, in its local frame. Shapes with an axis of
+//! symmetry (cylinders and capsules) are aligned with the y axis.
+
+use crate::{Matrix3, Vector3};
+
+/// A diagonal matrix
+fn diagonal(d: Vector3) -> Matrix3 {
+    Matrix3([
+        Vector3::new(d.x, 0.0, 0.0),
+        Vector3::new(0.0, d.y, 0.0),
+        Vector3::new(0.0, 0.0, d.z),
+    ])
+}
+
+/// The inertia tensor of a solid box, given half its size along each axis
+pub fn box_inertia(mass: f32, half_extents: Vector3) -> Matrix3 {
+    let s = half_extents * half_extents;
+    diagonal(Vector3::new(s.y + s.z, s.x + s.z, s.x + s.y) * (mass / 3.0))
+}
+
+/// The inertia tensor of a solid sphere
+pub fn sphere_inertia(mass: f32, radius: f32) -> Matrix3 {
+    diagonal(Vector3::from_scalar(0.4 * mass * radius * radius))
+}
+
+/// The inertia tensor of a solid cylinder, of the given total height
+pub fn cylinder_inertia(mass: f32, radius: f32, height: f32) -> Matrix3 {
+    let (r2, h2) = (radius * radius, height * height);
+    let across = mass * (3.0 * r2 + h2) / 12.0;
+    diagonal(Vector3::new(across, 0.5 * mass * r2, across))
+}
+
+/// The inertia tensor of a solid capsule, where `height` is the length of the cylindrical
+/// section between the centers of the two hemispherical caps
+pub fn capsule_inertia(mass: f32, radius: f32, height: f32) -> Matrix3 {
+    let (r2, h2) = (radius * radius, height * height);
+
+    // Divide the mass between the cylinder and the caps by volume
+    let cylinder_volume = height;
+    let caps_volume = 4.0 / 3.0 * radius;
+    let cylinder_mass = mass * cylinder_volume / (cylinder_volume + caps_volume);
+    let caps_mass = mass - cylinder_mass;
+
+    let along = 0.5 * cylinder_mass * r2 + 0.4 * caps_mass * r2;
+    // Each cap's center of mass lies 3/8 of the radius beyond the end of the cylinder
+    let across = cylinder_mass * (h2 / 12.0 + r2 / 4.0)
+        + caps_mass * (0.4 * r2 + h2 / 4.0 + 0.375 * height * radius);
+    diagonal(Vector3::new(across, along, across))
+}
+
+/// Shift an inertia tensor about the center of mass to one about a point at `offset` from it,
+/// using the parallel axis theorem
+pub fn parallel_axis(inertia: &Matrix3, mass: f32, offset: Vector3) -> Matrix3 {
+    let d = offset;
+    let mut result = *inertia;
+    for (i, column) in result.0.iter_mut().enumerate() {
+        for j in 0..3 {
+            let identity = if i == j { d.magnitude_squared() } else { 0.0 };
+            column[j] += mass * (identity - d[i] * d[j]);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn inertia() {
+        // A cube with sides of 2 has moments of m * s^2 / 6
+        assert_eq!(
+            box_inertia(3.0, Vector3::one()).0[0],
+            Vector3::new(2.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            box_inertia(12.0, Vector3::new(1.0, 2.0, 3.0)).0[1],
+            Vector3::new(0.0, 40.0, 0.0)
+        );
+
+        // A capsule without a cylinder is a sphere
+        assert_nearly_eq!(
+            &capsule_inertia(2.0, 0.5, 0.0),
+            &sphere_inertia(2.0, 0.5),
+            epsilon = 1e-6
+        );
+        let cylinder = cylinder_inertia(6.0, 1.0, 2.0);
+        assert_eq!(cylinder.0[1].y, 3.0);
+        assert_eq!(cylinder.0[0].x, 3.5);
+        let capsule = capsule_inertia(6.0, 1.0, 2.0);
+        assert!(capsule.0[1].y < cylinder.0[1].y);
+        assert!(capsule.0[0].x > capsule.0[1].y);
+
+        let shifted = parallel_axis(&sphere_inertia(1.0, 1.0), 2.0, Vector3::new(1.0, 1.0, 0.0));
+        assert_nearly_eq!(
+            &shifted,
+            &Matrix3([
+                Vector3::new(2.4, -2.0, 0.0),
+                Vector3::new(-2.0, 2.4, 0.0),
+                Vector3::new(0.0, 0.0, 4.4),
+            ]),
+            epsilon = 1e-6
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Point, Vector3};
+
+/// Advance a body by one step of semi-implicit (symplectic) Euler integration, updating the
+/// velocity before using it to move the position.
+///
+/// This costs one evaluation of the acceleration per step, and unlike explicit Euler, does not
+/// gain energy over time, so orbits and springs stay stable.
+pub fn semi_implicit_euler(
+    position: &mut Point,
+    velocity: &mut Vector3,
+    acceleration: Vector3,
+    dt: f32,
+) {
+    *velocity += acceleration * dt;
+    *position += *velocity * dt;
+}
+
+/// Advance a body by one step of velocity Verlet integration, which is second-order accurate,
+/// and exact for constant acceleration.
+///
+/// `acceleration` is the acceleration at the current position, and `acceleration_at` computes
+/// the acceleration at the new position. The latter is returned, to pass in to the next step.
+pub fn velocity_verlet(
+    position: &mut Point,
+    velocity: &mut Vector3,
+    acceleration: Vector3,
+    dt: f32,
+    acceleration_at: impl FnOnce(Point) -> Vector3,
+) -> Vector3 {
+    *position += *velocity * dt + acceleration * (0.5 * dt * dt);
+    let next = acceleration_at(*position);
+    *velocity += (acceleration + next) * (0.5 * dt);
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn integrate() {
+        let gravity = Vector3::new(0.0, -10.0, 0.0);
+
+        // Verlet is exact under constant acceleration
+        let (mut p, mut v) = (Point::ORIGIN, Vector3::new(1.0, 5.0, 0.0));
+        let mut a = gravity;
+        for _ in 0..10 {
+            a = velocity_verlet(&mut p, &mut v, a, 0.1, |_| gravity);
+        }
+        assert_nearly_eq!(&p, &Point::new(1.0, 0.0, 0.0), epsilon = 1e-5);
+        assert_nearly_eq!(&v, &Vector3::new(1.0, -5.0, 0.0), epsilon = 1e-5);
+
+        // Euler lands in roughly the same place
+        let (mut p, mut v) = (Point::ORIGIN, Vector3::new(1.0, 5.0, 0.0));
+        for _ in 0..10 {
+            semi_implicit_euler(&mut p, &mut v, gravity, 0.1);
+        }
+        assert_nearly_eq!(&v, &Vector3::new(1.0, -5.0, 0.0), epsilon = 1e-5);
+        assert_nearly_eq!(&p, &Point::new(1.0, -0.5, 0.0), epsilon = 1e-5);
+
+        // Neither gains energy on a spring
+        let spring = |p: Point| -Vector3::from(p);
+        let (mut p, mut v) = (Point::new(1.0, 0.0, 0.0), Vector3::zero());
+        let (mut q, mut u) = (p, v);
+        let mut a = spring(p);
+        for _ in 0..1000 {
+            let acceleration = spring(p);
+            semi_implicit_euler(&mut p, &mut v, acceleration, 0.05);
+            a = velocity_verlet(&mut q, &mut u, a, 0.05, spring);
+        }
+        let energy =
+            |p: Point, v: Vector3| Vector3::from(p).magnitude_squared() + v.magnitude_squared();
+        assert!(energy(p, v) < 1.1);
+        assert_nearly_eq!(energy(q, u), 1.0, epsilon = 1e-2);
+    }
+}
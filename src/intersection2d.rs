@@ -0,0 +1,164 @@
+//! Intersection tests between 2D primitives, for line-of-sight checks and drag-selection.
+
+use crate::{Circle, Rect, Vector2};
+
+/// The point where the segments `a0`–`a1` and `b0`–`b1` cross, if any.
+///
+/// Returns `None` for parallel segments, including overlapping collinear ones.
+pub fn intersect_segments_2d(
+    a0: Vector2,
+    a1: Vector2,
+    b0: Vector2,
+    b1: Vector2,
+) -> Option<Vector2> {
+    let (da, db) = (a1 - a0, b1 - b0);
+    let denominator = da.cross(db);
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let offset = b0 - a0;
+    let t = offset.cross(db) / denominator;
+    let u = offset.cross(da) / denominator;
+
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some(a0 + da * t)
+    } else {
+        None
+    }
+}
+
+/// The distance along a ray at which it first touches a rectangle, in multiples of `direction`.
+///
+/// Returns zero if the ray starts inside the rectangle, and `None` if it misses entirely.
+pub fn intersect_ray_rect(origin: Vector2, direction: Vector2, rect: &Rect) -> Option<f32> {
+    let mut near = 0.0f32;
+    let mut far = f32::INFINITY;
+
+    for axis in 0..2 {
+        if direction[axis] == 0.0 {
+            if origin[axis] < rect.min[axis] || origin[axis] > rect.max[axis] {
+                return None;
+            }
+            continue;
+        }
+
+        let inverse = 1.0 / direction[axis];
+        let t0 = (rect.min[axis] - origin[axis]) * inverse;
+        let t1 = (rect.max[axis] - origin[axis]) * inverse;
+        near = near.max(t0.min(t1));
+        far = far.min(t0.max(t1));
+    }
+
+    if near <= far {
+        Some(near)
+    } else {
+        None
+    }
+}
+
+/// The fraction of the way along the segment `a`–`b` at which it first touches a circle.
+///
+/// Returns zero if the segment starts inside the circle, and `None` if it misses entirely.
+pub fn intersect_segment_circle(a: Vector2, b: Vector2, circle: &Circle) -> Option<f32> {
+    let d = b - a;
+    let m = a - circle.center;
+    let c = m.magnitude_squared() - circle.radius * circle.radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+
+    let qa = d.magnitude_squared();
+    let qb = m.dot(d);
+    let discriminant = qb * qb - qa * c;
+    if qa == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+
+    let t = (-qb - discriminant.sqrt()) / qa;
+    if (0.0..=1.0).contains(&t) {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn segments() {
+        let hit = intersect_segments_2d(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(4.0, 4.0),
+            Vector2::new(0.0, 4.0),
+            Vector2::new(4.0, 0.0),
+        );
+        assert_eq!(hit, Some(Vector2::new(2.0, 2.0)));
+
+        let short = intersect_segments_2d(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(0.0, 4.0),
+            Vector2::new(4.0, 0.0),
+        );
+        assert_eq!(short, None);
+
+        let parallel = intersect_segments_2d(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 0.0),
+            Vector2::new(0.0, 1.0),
+            Vector2::new(1.0, 1.0),
+        );
+        assert_eq!(parallel, None);
+    }
+
+    #[test]
+    fn ray_rect() {
+        let rect = Rect::new(Vector2::new(2.0, -1.0), Vector2::new(4.0, 1.0));
+
+        assert_eq!(
+            intersect_ray_rect(Vector2::zero(), Vector2::UNIT_X, &rect),
+            Some(2.0)
+        );
+        assert_eq!(
+            intersect_ray_rect(Vector2::zero(), -Vector2::UNIT_X, &rect),
+            None
+        );
+        assert_eq!(
+            intersect_ray_rect(Vector2::zero(), Vector2::UNIT_Y, &rect),
+            None
+        );
+        assert_eq!(
+            intersect_ray_rect(Vector2::new(3.0, 0.0), Vector2::UNIT_Y, &rect),
+            Some(0.0)
+        );
+        assert_eq!(
+            intersect_ray_rect(Vector2::new(0.0, -2.0), Vector2::new(1.0, 1.0), &rect),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn segment_circle() {
+        let circle = Circle::new(Vector2::new(5.0, 0.0), 1.0);
+
+        assert_eq!(
+            intersect_segment_circle(Vector2::zero(), Vector2::new(10.0, 0.0), &circle),
+            Some(0.4)
+        );
+        assert_eq!(
+            intersect_segment_circle(Vector2::zero(), Vector2::new(3.0, 0.0), &circle),
+            None
+        );
+        assert_eq!(
+            intersect_segment_circle(Vector2::new(0.0, 2.0), Vector2::new(10.0, 2.0), &circle),
+            None
+        );
+        assert_eq!(
+            intersect_segment_circle(Vector2::new(5.0, 0.5), Vector2::new(10.0, 2.0), &circle),
+            Some(0.0)
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{Aabb, Point, Vector3};
+
+/// The axes along which a [`Kdop`] is bounded: the coordinate axes, then the four diagonals
+/// through the corners of a cube, then the six diagonals through its edges.
+///
+/// The diagonals are deliberately not normalized, so that projections stay cheap.
+const AXES: [Vector3; 13] = [
+    Vector3::new(1.0, 0.0, 0.0),
+    Vector3::new(0.0, 1.0, 0.0),
+    Vector3::new(0.0, 0.0, 1.0),
+    Vector3::new(1.0, 1.0, 1.0),
+    Vector3::new(1.0, 1.0, -1.0),
+    Vector3::new(1.0, -1.0, 1.0),
+    Vector3::new(-1.0, 1.0, 1.0),
+    Vector3::new(1.0, 1.0, 0.0),
+    Vector3::new(1.0, -1.0, 0.0),
+    Vector3::new(1.0, 0.0, 1.0),
+    Vector3::new(1.0, 0.0, -1.0),
+    Vector3::new(0.0, 1.0, 1.0),
+    Vector3::new(0.0, 1.0, -1.0),
+];
+
+/// A discrete oriented polytope, bounded by pairs of planes along `N` fixed axes (so `2N` planes
+/// in total). These fit elongated and diagonal shapes more tightly than an [`Aabb`], while
+/// remaining nearly as cheap to build and test.
+///
+/// `N` may be at most 13. See [`Dop14`] and [`Dop26`] for the common choices.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Kdop<const N: usize> {
+    /// The minimum extent along each axis.
+    pub min: [f32; N],
+    /// The maximum extent along each axis.
+    pub max: [f32; N],
+}
+
+/// A 14-DOP, bounded along the coordinate axes and the diagonals through the corners of a cube.
+pub type Dop14 = Kdop<7>;
+
+/// A 26-DOP, bounded along the coordinate axes and every diagonal of a cube.
+pub type Dop26 = Kdop<13>;
+
+impl<const N: usize> Kdop<N> {
+    const VALID: () = assert!(N >= 3 && N <= 13, "a k-DOP must have between 3 and 13 axes");
+
+    /// An empty k-DOP, which contains nothing and is the identity for [`Self::merge`].
+    pub fn empty() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID;
+        Self {
+            min: [f32::INFINITY; N],
+            max: [f32::NEG_INFINITY; N],
+        }
+    }
+
+    /// The smallest k-DOP containing all of the given points.
+    /// Returns `None` if there are no points.
+    pub fn from_points(points: &[Point]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        Some(points.iter().fold(Self::empty(), |dop, p| {
+            let d = Self::project(*p);
+            dop.merge(&Self { min: d, max: d })
+        }))
+    }
+
+    /// The projection of a point onto each axis
+    fn project(point: Point) -> [f32; N] {
+        let v = Vector3::from(point);
+        std::array::from_fn(|i| AXES[i].dot(v))
+    }
+
+    /// The smallest k-DOP containing both this one and another
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            min: std::array::from_fn(|i| self.min[i].min(other.min[i])),
+            max: std::array::from_fn(|i| self.max[i].max(other.max[i])),
+        }
+    }
+
+    /// Whether a point lies within this k-DOP
+    pub fn contains(&self, point: Point) -> bool {
+        let d = Self::project(point);
+        (0..N).all(|i| d[i] >= self.min[i] && d[i] <= self.max[i])
+    }
+
+    /// Whether this k-DOP overlaps another. As both share the same axes, they overlap unless
+    /// they are separated along one of those axes.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// The bounding box of this k-DOP, given by its extents along the coordinate axes
+    pub fn aabb(&self) -> Aabb {
+        Aabb::new(
+            Point::new(self.min[0], self.min[1], self.min[2]),
+            Point::new(self.max[0], self.max[1], self.max[2]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn diagonal_rods() {
+        // Two thin rods along the diagonal, whose bounding boxes overlap but which are apart
+        let a = [Point::new(0.0, 0.0, 0.0), Point::new(4.0, 4.0, 0.0)];
+        let b = [Point::new(1.0, 3.0, 0.0), Point::new(1.5, 3.5, 0.0)];
+
+        let (dop_a, dop_b) = (
+            Dop14::from_points(&a).unwrap(),
+            Dop14::from_points(&b).unwrap(),
+        );
+        assert!(dop_a.aabb().intersects(&dop_b.aabb()));
+        assert!(!dop_a.intersects(&dop_b));
+
+        let dop = Dop26::from_points(&a).unwrap();
+        assert!(dop.contains(Point::new(2.0, 2.0, 0.0)));
+        assert!(!dop.contains(Point::new(1.0, 3.0, 0.0)));
+
+        let merged = dop_a.merge(&dop_b);
+        assert!(merged.contains(Point::new(1.0, 3.0, 0.0)));
+        assert!(merged.intersects(&dop_b));
+        assert_eq!(Dop14::from_points(&[]), None);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Matrix4, Point, Vector3};
+
+/// A point in 3D space with double-precision coordinates.
+///
+/// Single-precision coordinates lose sub-millimetre precision beyond roughly 10km from the
+/// origin, which shows up as vertex jitter. Store absolute positions as `WorldPoint`s, and use
+/// the camera-relative helpers to produce single-precision values near the origin for rendering.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct WorldPoint {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl WorldPoint {
+    /// Construct a new point from individual coordinates
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The origin
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    /// The offset of this point from the given origin, computed in double precision
+    /// before being rounded to single precision
+    pub fn relative_to(&self, origin: WorldPoint) -> Point {
+        Point::new(
+            (self.x - origin.x) as f32,
+            (self.y - origin.y) as f32,
+            (self.z - origin.z) as f32,
+        )
+    }
+}
+
+impl From<Point> for WorldPoint {
+    fn from(p: Point) -> Self {
+        Self::new(p.x as f64, p.y as f64, p.z as f64)
+    }
+}
+
+impl std::ops::Add<Vector3> for WorldPoint {
+    type Output = WorldPoint;
+
+    fn add(self, rhs: Vector3) -> WorldPoint {
+        WorldPoint::new(
+            self.x + rhs.x as f64,
+            self.y + rhs.y as f64,
+            self.z + rhs.z as f64,
+        )
+    }
+}
+
+impl std::ops::Sub<Vector3> for WorldPoint {
+    type Output = WorldPoint;
+
+    fn sub(self, rhs: Vector3) -> WorldPoint {
+        WorldPoint::new(
+            self.x - rhs.x as f64,
+            self.y - rhs.y as f64,
+            self.z - rhs.z as f64,
+        )
+    }
+}
+
+impl Matrix4 {
+    /// A look-at matrix for a camera at `eye`, in camera-relative space (i.e. with the
+    /// camera at the origin). Pair this with [`Matrix4::translation_relative`] and
+    /// [`Matrix4::transform_relative`] using the same `eye` as the origin.
+    pub fn look_at_relative(eye: WorldPoint, target: WorldPoint, up: Vector3) -> Self {
+        Self::look_at(Point::zero(), target.relative_to(eye), up)
+    }
+
+    /// A matrix that translates to `position`, expressed relative to `origin`.
+    pub fn translation_relative(position: WorldPoint, origin: WorldPoint) -> Self {
+        Self::translation(position.relative_to(origin).into())
+    }
+
+    /// Transform a point in double precision, and express the result relative to `origin`.
+    pub fn transform_relative(&self, point: WorldPoint, origin: WorldPoint) -> Point {
+        let m = |i: usize, j: usize| self.0[i][j] as f64;
+        let transformed = WorldPoint::new(
+            m(0, 0) * point.x + m(1, 0) * point.y + m(2, 0) * point.z + m(3, 0),
+            m(0, 1) * point.x + m(1, 1) * point.y + m(2, 1) * point.z + m(3, 1),
+            m(0, 2) * point.x + m(1, 2) * point.y + m(2, 2) * point.z + m(3, 2),
+        );
+        transformed.relative_to(origin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn relative() {
+        let origin = WorldPoint::new(20_000_000.0, 0.0, -35_000_000.0);
+        let p = origin + Vector3::new(0.001, 2.0, -0.003);
+
+        assert_nearly_eq!(
+            &p.relative_to(origin),
+            &Point::new(0.001, 2.0, -0.003),
+            epsilon = 1e-6
+        );
+
+        let m = Matrix4::translation_relative(p, origin);
+        assert_nearly_eq!(
+            &(m * Point::zero()),
+            &Point::new(0.001, 2.0, -0.003),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn camera() {
+        let eye = WorldPoint::new(20_000_000.0, 0.0, 0.0);
+        let target = eye + Vector3::new(0.0, 0.0, -1.0);
+        let view = Matrix4::look_at_relative(eye, target, Vector3::new(0.0, 1.0, 0.0));
+
+        let p = eye + Vector3::new(0.002, 0.0, -5.0);
+        let q = view * Matrix4::identity().transform_relative(p, eye);
+        assert_nearly_eq!(&q, &Point::new(0.002, 0.0, -5.0), epsilon = 1e-6);
+    }
+}
@@ -1,11 +1,114 @@
 //! Lightweight math routines for 3D graphics.
 
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
+
+mod aabb;
+mod bvh;
+mod camera;
+mod capsule;
+mod circle;
+mod coordinate_system;
+mod cylinder;
+mod decomposition;
+mod error;
+mod fixed;
+mod format;
+mod frustum;
+mod grid;
+mod hierarchy;
+mod inertia;
+mod integrate;
+mod intersection2d;
+mod kdop;
+mod large_world;
+mod line2;
+mod macros;
 mod matrix;
+mod matrix2;
+mod matrix3;
+mod matrix_stack;
+mod mesh;
+mod nan_policy;
 mod nearly_equal;
+mod noise;
+mod obb;
 mod operators;
+mod packing;
+mod pca;
+mod plane;
+mod projection;
+mod quaternion;
+mod ray;
+mod rect;
+mod sampling;
+mod screen;
+mod simd;
+mod skinning;
+mod soa;
+mod spatial_hash;
+mod sphere;
+mod spring;
+mod summation;
+mod sweep;
+mod track;
+mod traits;
+mod transform;
+mod twist;
+mod validate;
 mod vector;
 
+#[cfg(feature = "num-traits")]
+mod numeric;
+#[cfg(feature = "encase")]
+mod shader_type;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+pub use aabb::*;
+pub use bvh::*;
+pub use camera::*;
+pub use capsule::*;
+pub use circle::*;
+pub use coordinate_system::*;
+pub use cylinder::*;
+pub use error::*;
+pub use fixed::*;
+pub use format::*;
+pub use frustum::*;
+pub use grid::*;
+pub use hierarchy::*;
+pub use inertia::*;
+pub use integrate::*;
+pub use intersection2d::*;
+pub use kdop::*;
+pub use large_world::*;
+pub use line2::*;
 pub use matrix::*;
+pub use matrix2::*;
+pub use matrix3::*;
+pub use matrix_stack::*;
+pub use mesh::*;
 pub use nearly_equal::*;
-pub use operators::*;
+pub use noise::*;
+pub use obb::*;
+pub use packing::*;
+pub use pca::*;
+pub use plane::*;
+pub use projection::*;
+pub use quaternion::*;
+pub use ray::*;
+pub use rect::*;
+pub use sampling::*;
+pub use screen::*;
+pub use skinning::*;
+pub use soa::*;
+pub use spatial_hash::*;
+pub use sphere::*;
+pub use spring::*;
+pub use summation::*;
+pub use sweep::*;
+pub use track::*;
+pub use traits::*;
+pub use transform::*;
+pub use twist::*;
 pub use vector::*;
@@ -0,0 +1,179 @@
+use crate::{intersect_ray_rect, Rect, Vector2};
+
+/// Which side of a line or plane a point lies on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The side the normal faces.
+    Front,
+    /// The side opposite the normal.
+    Back,
+    /// Straddling the line or plane, or for points, within the tolerance of it.
+    Intersecting,
+}
+
+impl Side {
+    /// Classify a signed distance, treating distances within `epsilon` of zero as `Intersecting`.
+    pub fn from_signed_distance(distance: f32, epsilon: f32) -> Self {
+        if distance > epsilon {
+            Side::Front
+        } else if distance < -epsilon {
+            Side::Back
+        } else {
+            Side::Intersecting
+        }
+    }
+}
+
+/// An infinite line in 2D space, consisting of all points `p` where `normal.dot(p) + d == 0`.
+///
+/// This is the 2D counterpart of [`crate::Plane`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Line2 {
+    pub normal: Vector2,
+    pub d: f32,
+}
+
+impl Line2 {
+    /// Construct a line from the coefficients of its equation
+    pub const fn new(normal: Vector2, d: f32) -> Self {
+        Self { normal, d }
+    }
+
+    /// Construct a line passing through the given point, with the given normal
+    pub fn from_point_normal(point: Vector2, normal: Vector2) -> Self {
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Construct the line passing through two points, with a unit normal facing left of the
+    /// direction from `a` to `b` (the side from which they appear in counter-clockwise order)
+    pub fn from_points(a: Vector2, b: Vector2) -> Self {
+        let direction = b - a;
+        let normal = Vector2::new(-direction.y, direction.x).normalized();
+        Self::from_point_normal(a, normal)
+    }
+
+    /// The signed distance from the line to a point, positive on the side the normal faces.
+    /// Note that this is scaled by the length of the normal, if it is not unit length.
+    pub fn signed_distance(&self, point: Vector2) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+
+    /// Which side of the line a point lies on, treating points within `epsilon` as on the line
+    pub fn classify(&self, point: Vector2, epsilon: f32) -> Side {
+        Side::from_signed_distance(self.signed_distance(point), epsilon)
+    }
+
+    /// The closest point on the line to the given point. The normal must be unit length.
+    pub fn closest_point(&self, point: Vector2) -> Vector2 {
+        point - self.normal * self.signed_distance(point)
+    }
+}
+
+/// A ray in 2D space, starting at `origin` and extending along `direction`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Ray2 {
+    pub origin: Vector2,
+    pub direction: Vector2,
+}
+
+impl Ray2 {
+    /// Construct a ray from its origin and direction
+    pub const fn new(origin: Vector2, direction: Vector2) -> Self {
+        Self { origin, direction }
+    }
+
+    /// The point at the given distance along the ray, in multiples of `direction`
+    pub fn at(&self, t: f32) -> Vector2 {
+        self.origin + self.direction * t
+    }
+
+    /// The closest point on the ray to the given point
+    pub fn closest_point(&self, point: Vector2) -> Vector2 {
+        let length_squared = self.direction.magnitude_squared();
+        if length_squared == 0.0 {
+            return self.origin;
+        }
+
+        let t = (point - self.origin).dot(self.direction) / length_squared;
+        self.at(t.max(0.0))
+    }
+
+    /// The distance from the ray to a point
+    pub fn distance(&self, point: Vector2) -> f32 {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// Which side of the ray a point lies on, where `Front` is to the left of the direction
+    /// of travel (consistent with [`Line2::from_points`])
+    pub fn classify(&self, point: Vector2, epsilon: f32) -> Side {
+        Line2::from_points(self.origin, self.origin + self.direction).classify(point, epsilon)
+    }
+
+    /// The distance along the ray at which it crosses a line, in multiples of `direction`.
+    /// Returns `None` if the ray is parallel to the line or points away from it.
+    pub fn intersect_line(&self, line: &Line2) -> Option<f32> {
+        let denominator = line.normal.dot(self.direction);
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let t = -line.signed_distance(self.origin) / denominator;
+        if t >= 0.0 {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    /// The distance along the ray at which it first touches a rectangle, in multiples of
+    /// `direction`. See [`intersect_ray_rect`].
+    pub fn intersect_rect(&self, rect: &Rect) -> Option<f32> {
+        intersect_ray_rect(self.origin, self.direction, rect)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn line() {
+        let line = Line2::from_points(Vector2::new(0.0, 1.0), Vector2::new(2.0, 1.0));
+
+        assert_eq!(line.normal, Vector2::new(0.0, 1.0));
+        assert_eq!(line.signed_distance(Vector2::new(5.0, 3.0)), 2.0);
+        assert_eq!(line.classify(Vector2::new(5.0, 3.0), 1e-5), Side::Front);
+        assert_eq!(line.classify(Vector2::new(5.0, -3.0), 1e-5), Side::Back);
+        assert_eq!(
+            line.classify(Vector2::new(-5.0, 1.0), 1e-5),
+            Side::Intersecting
+        );
+        assert_eq!(
+            line.closest_point(Vector2::new(5.0, 3.0)),
+            Vector2::new(5.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn ray() {
+        let ray = Ray2::new(Vector2::new(1.0, 1.0), Vector2::new(1.0, 0.0));
+
+        assert_eq!(ray.at(2.0), Vector2::new(3.0, 1.0));
+        assert_eq!(ray.distance(Vector2::new(4.0, 3.0)), 2.0);
+        assert_eq!(ray.distance(Vector2::new(-2.0, 5.0)), 5.0);
+        assert_eq!(ray.classify(Vector2::new(4.0, 3.0), 1e-5), Side::Front);
+        assert_eq!(ray.classify(Vector2::new(4.0, -3.0), 1e-5), Side::Back);
+
+        let wall = Line2::from_point_normal(Vector2::new(5.0, 0.0), Vector2::new(-1.0, 0.0));
+        assert_eq!(ray.intersect_line(&wall), Some(4.0));
+        assert_eq!(
+            Ray2::new(ray.origin, -ray.direction).intersect_line(&wall),
+            None
+        );
+
+        let rect = Rect::new(Vector2::new(3.0, 0.0), Vector2::new(4.0, 2.0));
+        assert_eq!(ray.intersect_rect(&rect), Some(2.0));
+    }
+}
@@ -0,0 +1,106 @@
+//! Terse construction of vectors and matrices. Each macro expands to a `const fn` call, so it
+//! can be used to initialize constants.
+
+/// Construct a [`Vector2`](crate::Vector2) from its coordinates, or from a single value for
+/// every coordinate.
+///
+/// ```
+/// # use mini_math::*;
+/// assert_eq!(vec2!(1.0, 2.0), Vector2::new(1.0, 2.0));
+/// assert_eq!(vec2!(0.5), Vector2::new(0.5, 0.5));
+/// ```
+#[macro_export]
+macro_rules! vec2 {
+    ($s:expr) => {
+        $crate::Vector2::from_scalar($s)
+    };
+    ($x:expr, $y:expr $(,)?) => {
+        $crate::Vector2::new($x, $y)
+    };
+}
+
+/// Construct a [`Vector3`](crate::Vector3) from its coordinates, or from a single value for
+/// every coordinate.
+///
+/// ```
+/// # use mini_math::*;
+/// const UP: Vector3 = vec3!(0.0, 1.0, 0.0);
+/// assert_eq!(UP, Vector3::UNIT_Y);
+/// assert_eq!(vec3!(2.0), Vector3::new(2.0, 2.0, 2.0));
+/// ```
+#[macro_export]
+macro_rules! vec3 {
+    ($s:expr) => {
+        $crate::Vector3::from_scalar($s)
+    };
+    ($x:expr, $y:expr, $z:expr $(,)?) => {
+        $crate::Vector3::new($x, $y, $z)
+    };
+}
+
+/// Construct a [`Vector4`](crate::Vector4) from its coordinates, or from a single value for
+/// every coordinate.
+///
+/// ```
+/// # use mini_math::*;
+/// assert_eq!(vec4!(1.0, 2.0, 3.0, 1.0), Vector4::new(1.0, 2.0, 3.0, 1.0));
+/// assert_eq!(vec4!(0.0), Vector4::zero());
+/// ```
+#[macro_export]
+macro_rules! vec4 {
+    ($s:expr) => {
+        $crate::Vector4::from_scalar($s)
+    };
+    ($x:expr, $y:expr, $z:expr, $w:expr $(,)?) => {
+        $crate::Vector4::new($x, $y, $z, $w)
+    };
+}
+
+/// Construct a [`Point`](crate::Point) from its coordinates, or from a single value for
+/// every coordinate.
+///
+/// ```
+/// # use mini_math::*;
+/// assert_eq!(point!(1.0, 2.0, 3.0), Point::new(1.0, 2.0, 3.0));
+/// assert_eq!(point!(0.0), Point::ORIGIN);
+/// ```
+#[macro_export]
+macro_rules! point {
+    ($s:expr) => {
+        $crate::Point::from_scalar($s)
+    };
+    ($x:expr, $y:expr, $z:expr $(,)?) => {
+        $crate::Point::new($x, $y, $z)
+    };
+}
+
+/// Construct a [`Matrix4`](crate::Matrix4) from its elements, written row by row with rows
+/// separated by semicolons, so that the source reads like the matrix. A single value fills the
+/// diagonal, leaving the other elements zero.
+///
+/// ```
+/// # use mini_math::*;
+/// const SHIFT: Matrix4 = matrix![
+///     1.0, 0.0, 0.0, 5.0;
+///     0.0, 1.0, 0.0, 0.0;
+///     0.0, 0.0, 1.0, 0.0;
+///     0.0, 0.0, 0.0, 1.0;
+/// ];
+/// assert_eq!(SHIFT, Matrix4::translation(Vector3::new(5.0, 0.0, 0.0)));
+/// assert_eq!(matrix![1.0], Matrix4::identity());
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($s:expr) => {{
+        let s: f32 = $s;
+        $crate::Matrix4::from_rows(
+            $crate::Vector4::new(s, 0.0, 0.0, 0.0),
+            $crate::Vector4::new(0.0, s, 0.0, 0.0),
+            $crate::Vector4::new(0.0, 0.0, s, 0.0),
+            $crate::Vector4::new(0.0, 0.0, 0.0, s),
+        )
+    }};
+    ($($a:expr, $b:expr, $c:expr, $d:expr);+ $(;)?) => {
+        $crate::Matrix4::from_rows($($crate::Vector4::new($a, $b, $c, $d)),+)
+    };
+}
@@ -1,22 +1,56 @@
-use crate::{NearlyEqual, Point, Vector3, Vector4};
+use crate::validate::validate;
+use crate::{nested_mismatch, MathError, Matrix3, NearlyEqual, Point, Tolerance, Vector3, Vector4};
 
 /// A 4x4 matrix, suitable for 3D transformations.
+///
+/// Note that this is aligned to 16 bytes, matching the layout of a `mat4` in std140 and
+/// std430 buffers.
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[repr(C)]
+#[repr(C, align(16))]
 pub struct Matrix4(pub [Vector4; 4]);
 
+/// The order in which rotations about each axis are applied by [`Matrix4::from_euler`].
+///
+/// For example, `Xyz` rotates about the x-axis first, then the y-axis, and finally the z-axis
+/// (all about the fixed world axes), which is equivalent to `rotation_z * rotation_y * rotation_x`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+}
+
+/// The range of depths in normalized device coordinates (after the perspective divide) that a
+/// projection produces, from the near plane to the far plane.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    /// -1 at the near plane to 1 at the far plane, as in OpenGL. This is the range produced by
+    /// [`Matrix4::perspective`] and [`Matrix4::orthographic`].
+    #[default]
+    NegativeOneToOne,
+    /// 0 at the near plane to 1 at the far plane, as in Vulkan, Direct3D, Metal and wgpu
+    ZeroToOne,
+    /// 1 at the near plane to 0 at the far plane, for reversed depth in APIs that keep 0 to 1.
+    /// This is the range produced by [`Matrix4::perspective_reversed_z`] and
+    /// [`Matrix4::perspective_infinite_reversed_z`].
+    OneToZero,
+}
+
 impl Matrix4 {
-    /// A new matrix from a 1D array.
+    /// A new matrix from a 1D array, in column-major order.
     pub const fn from_1d_array(a: [f32; 16]) -> Self {
         Self([
             Vector4::new(a[0], a[1], a[2], a[3]),
-            Vector4::new(a[4], a[5], a[6], a[4]),
+            Vector4::new(a[4], a[5], a[6], a[7]),
             Vector4::new(a[8], a[9], a[10], a[11]),
             Vector4::new(a[12], a[13], a[14], a[15]),
         ])
     }
 
-    /// A new matrix from a 2D array.
+    /// A new matrix from a 2D array, where each inner array is a column.
     pub const fn from_2d_array(a: [[f32; 4]; 4]) -> Self {
         Self([
             Vector4::new(a[0][0], a[0][1], a[0][2], a[0][3]),
@@ -26,6 +60,36 @@ impl Matrix4 {
         ])
     }
 
+    /// A new matrix from a slice of 16 elements, in column-major order.
+    pub fn from_slice(s: &[f32]) -> Result<Self, MathError> {
+        let a: [f32; 16] = s.try_into().map_err(|_| MathError::InvalidSliceLength {
+            expected: 16,
+            actual: s.len(),
+        })?;
+        Ok(Self::from_1d_array(a))
+    }
+
+    /// A new matrix from its column vectors.
+    pub const fn from_cols(c0: Vector4, c1: Vector4, c2: Vector4, c3: Vector4) -> Self {
+        Self([c0, c1, c2, c3])
+    }
+
+    /// A new matrix from its row vectors.
+    pub const fn from_rows(r0: Vector4, r1: Vector4, r2: Vector4, r3: Vector4) -> Self {
+        let [r0, r1, r2, r3] = [
+            r0.coordinates(),
+            r1.coordinates(),
+            r2.coordinates(),
+            r3.coordinates(),
+        ];
+        Self([
+            Vector4::new(r0[0], r1[0], r2[0], r3[0]),
+            Vector4::new(r0[1], r1[1], r2[1], r3[1]),
+            Vector4::new(r0[2], r1[2], r2[2], r3[2]),
+            Vector4::new(r0[3], r1[3], r2[3], r3[3]),
+        ])
+    }
+
     /// The identity matrix.
     pub const fn identity() -> Self {
         Self([
@@ -48,13 +112,23 @@ impl Matrix4 {
 
     /// A look-at matrix suitable for positioning a camera.
     pub fn look_at(eye: Point, target: Point, up: Vector3) -> Self {
+        Self::look_at_pair(eye, target, up).0
+    }
+
+    /// A look-at matrix suitable for positioning a camera, along with its inverse (the
+    /// camera-to-world transform).
+    ///
+    /// Both are built from the same basis vectors, so the inverse is exact and much cheaper than
+    /// calling [`Self::invert`]. Its columns are the camera's right, up and backward axes, and
+    /// its position in world space.
+    pub fn look_at_pair(eye: Point, target: Point, up: Vector3) -> (Self, Self) {
         let z_axis = (target - eye).normalized();
         let x_axis = z_axis.cross(up).normalized();
         let y_axis = x_axis.cross(z_axis);
 
         let eye_vec = eye.into();
 
-        Self([
+        let view = Self([
             Vector4::new(x_axis.x, y_axis.x, -z_axis.x, 0.0),
             Vector4::new(x_axis.y, y_axis.y, -z_axis.y, 0.0),
             Vector4::new(x_axis.z, y_axis.z, -z_axis.z, 0.0),
@@ -64,7 +138,24 @@ impl Matrix4 {
                 z_axis.dot(eye_vec),
                 1.0,
             ),
-        ])
+        ]);
+        let inverse = Self([
+            Vector4::from(x_axis),
+            Vector4::from(y_axis),
+            Vector4::from(-z_axis),
+            Vector4::from(eye),
+        ]);
+
+        (view, inverse)
+    }
+
+    /// A rotation that turns an object to face along `forward`, keeping its top towards `up`.
+    ///
+    /// The object's forward axis is -z, as for a camera. Unlike [`Self::look_at`], this is
+    /// the object-to-world orientation, without translation, so it suits orienting objects and
+    /// decals rather than building a view matrix.
+    pub fn aim(forward: Vector3, up: Vector3) -> Self {
+        Self::look_at_pair(Point::ORIGIN, Point::from(forward), up).1
     }
 
     /// A perspective matrix suitable for use as a camera projection.
@@ -79,12 +170,79 @@ impl Matrix4 {
         ])
     }
 
-    /// An orthographic matrix suitable for rendering user interfaces.
+    /// A perspective matrix producing depths in the given range. See [`Self::perspective`].
+    pub fn perspective_with_depth_range(
+        aspect_ratio: f32,
+        fov_radians: f32,
+        znear: f32,
+        zfar: f32,
+        depth_range: DepthRange,
+    ) -> Self {
+        Self::perspective(aspect_ratio, fov_radians, znear, zfar).remap_depth(depth_range)
+    }
+
+    /// A perspective matrix with reversed depth, mapping the near plane to a depth of 1 and the
+    /// far plane to 0.
+    ///
+    /// Floating-point depth buffers are most precise near 0, so reversing the depth spreads
+    /// that precision more evenly across the view. This requires a 0 to 1 clip-space depth
+    /// range (as in Vulkan, Direct3D, Metal and wgpu), a depth buffer cleared to 0, and a
+    /// greater-than depth test. The result is the same as [`Self::perspective_with_depth_range`]
+    /// with [`DepthRange::OneToZero`].
+    pub fn perspective_reversed_z(
+        aspect_ratio: f32,
+        fov_radians: f32,
+        znear: f32,
+        zfar: f32,
+    ) -> Self {
+        let f = 1.0 / (fov_radians / 2.0).tan();
+
+        Self([
+            Vector4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, f, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, znear / (zfar - znear), -1.0),
+            Vector4::new(0.0, 0.0, znear * zfar / (zfar - znear), 0.0),
+        ])
+    }
+
+    /// A perspective matrix without a far plane, as the limit of [`Self::perspective`] when
+    /// `zfar` goes to infinity. Distant points approach a depth of 1 without reaching it.
+    pub fn perspective_infinite(aspect_ratio: f32, fov_radians: f32, znear: f32) -> Self {
+        let f = 1.0 / (fov_radians / 2.0).tan();
+
+        Self([
+            Vector4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, f, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, -1.0, -1.0),
+            Vector4::new(0.0, 0.0, -2.0 * znear, 0.0),
+        ])
+    }
+
+    /// A perspective matrix with reversed depth and no far plane, as the limit of
+    /// [`Self::perspective_reversed_z`] when `zfar` goes to infinity. This is the usual choice
+    /// for open worlds, and distant points approach a depth of 0 without reaching it.
+    pub fn perspective_infinite_reversed_z(
+        aspect_ratio: f32,
+        fov_radians: f32,
+        znear: f32,
+    ) -> Self {
+        let f = 1.0 / (fov_radians / 2.0).tan();
+
+        Self([
+            Vector4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, f, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, -1.0),
+            Vector4::new(0.0, 0.0, znear, 0.0),
+        ])
+    }
+
+    /// An orthographic matrix suitable for rendering user interfaces or shadow maps, projecting
+    /// the given box (which need not be centered on the view axis) to clip space.
     pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
         Self([
             Vector4::new(2.0 / (right - left), 0.0, 0.0, 0.0),
             Vector4::new(0.0, 2.0 / (top - bottom), 0.0, 0.0),
-            Vector4::new(0.0, 0.0, -2.0 / (far - near), -1.0),
+            Vector4::new(0.0, 0.0, -2.0 / (far - near), 0.0),
             Vector4::new(
                 -(right + left) / (right - left),
                 -(top + bottom) / (top - bottom),
@@ -94,6 +252,40 @@ impl Matrix4 {
         ])
     }
 
+    /// An orthographic matrix producing depths in the given range. See [`Self::orthographic`].
+    pub fn orthographic_with_depth_range(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+        depth_range: DepthRange,
+    ) -> Self {
+        Self::orthographic(left, right, bottom, top, near, far).remap_depth(depth_range)
+    }
+
+    /// Remap the depth produced by a projection matrix from -1 to 1 into the given range
+    fn remap_depth(mut self, depth_range: DepthRange) -> Self {
+        for column in &mut self.0 {
+            column.z = match depth_range {
+                DepthRange::NegativeOneToOne => column.z,
+                // z' = (z + w) / 2
+                DepthRange::ZeroToOne => 0.5 * (column.z + column.w),
+                // z' = (w - z) / 2
+                DepthRange::OneToZero => 0.5 * (column.w - column.z),
+            };
+        }
+        self
+    }
+
+    /// An orthographic matrix projecting a box of the given width and height, centered on the
+    /// view axis. See [`Self::orthographic`].
+    pub fn orthographic_symmetric(width: f32, height: f32, near: f32, far: f32) -> Self {
+        let (x, y) = (width * 0.5, height * 0.5);
+        Self::orthographic(-x, x, -y, y, near, far)
+    }
+
     /// A matrix that translates by the given vector.
     pub fn translation(v: Vector3) -> Self {
         Self([
@@ -134,8 +326,29 @@ impl Matrix4 {
         ])
     }
 
-    /// A matrix that rotates around an arbitrary axis.
+    /// A matrix that rotates around each of the x, y, and z axes in the given order.
+    pub fn from_euler(order: EulerOrder, x_radians: f32, y_radians: f32, z_radians: f32) -> Self {
+        let x = Self::rotation_x(x_radians);
+        let y = Self::rotation_y(y_radians);
+        let z = Self::rotation_z(z_radians);
+
+        match order {
+            EulerOrder::Xyz => z * y * x,
+            EulerOrder::Xzy => y * z * x,
+            EulerOrder::Yxz => z * x * y,
+            EulerOrder::Yzx => x * z * y,
+            EulerOrder::Zxy => y * x * z,
+            EulerOrder::Zyx => x * y * z,
+        }
+    }
+
+    /// A matrix that rotates around an arbitrary axis, using Rodrigues' rotation formula.
+    /// The axis need not be unit length.
+    ///
+    /// As with [`Matrix4::rotation_z`], positive angles rotate clockwise when looking along
+    /// the axis towards the origin.
     pub fn rotation_axis_angle(axis: Vector3, angle_radians: f32) -> Self {
+        let axis = axis.normalized();
         let sin = angle_radians.sin();
         let cos = angle_radians.cos();
         let k = 1.0 - cos;
@@ -193,6 +406,28 @@ impl Matrix4 {
         ])
     }
 
+    /// A matrix that rotates unit vector `from` onto unit vector `to` along the shortest arc.
+    ///
+    /// Unlike [`Matrix4::rotation_from_vector_to_vector`], this remains accurate when the
+    /// vectors point in (nearly) opposite directions, where the axis of rotation is ambiguous.
+    pub fn rotation_align(from: Vector3, to: Vector3) -> Self {
+        let cos = from.dot(to);
+        if cos > -0.5 {
+            return Self::rotation_from_vector_to_vector(from, to);
+        }
+
+        // Split into two well-conditioned rotations, via a vector perpendicular to `from`
+        let perpendicular = to - from * cos;
+        let mid = if perpendicular.magnitude_squared() > 1e-12 {
+            perpendicular.normalized()
+        } else {
+            from.any_perpendicular()
+        };
+
+        Self::rotation_from_vector_to_vector(mid, to)
+            * Self::rotation_from_vector_to_vector(from, mid)
+    }
+
     /// A matrix that scales uniformly in all dimensions.
     pub fn uniform_scale(scale: f32) -> Self {
         Self([
@@ -212,6 +447,56 @@ impl Matrix4 {
         self.0[i]
     }
 
+    /// The row vectors of this matrix, from top to bottom
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Vector4> + '_ {
+        (0..4).map(|i| self.row(i))
+    }
+
+    /// The column vectors of this matrix, from left to right
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = Vector4> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// The elements of this matrix in column-major order, matching
+    /// [`to_cols_array`](Self::to_cols_array)
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = f32> + '_ {
+        self.as_array().iter().copied()
+    }
+
+    /// Apply a function to each element of this matrix
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        Matrix4(self.0.map(|c| Vector4::new(f(c.x), f(c.y), f(c.z), f(c.w))))
+    }
+
+    /// Combine the corresponding elements of two matrices with a function
+    pub fn zip_with(&self, other: &Matrix4, f: impl Fn(f32, f32) -> f32) -> Self {
+        let mut result = *self;
+        for (r, o) in result.as_array_mut().iter_mut().zip(other.iter()) {
+            *r = f(*r, o);
+        }
+        result
+    }
+
+    /// The elements of this matrix in column-major order, one column after another.
+    ///
+    /// This is the layout expected by OpenGL, Vulkan and WGSL, and matches the in-memory
+    /// layout of this type.
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        let mut a = [0.0; 16];
+        for (i, a) in a.iter_mut().enumerate() {
+            *a = self.0[i / 4][i % 4];
+        }
+        a
+    }
+
+    /// The elements of this matrix in row-major order, one row after another.
+    ///
+    /// This is the layout expected by HLSL constant buffers declared `row_major`, and by
+    /// APIs that use the row-vector convention.
+    pub fn to_rows_array(&self) -> [f32; 16] {
+        self.transpose().to_cols_array()
+    }
+
     /// The transpose of this matrix (i.e. this matrix flipped along the diagonal)
     pub fn transpose(&self) -> Self {
         let mut r = Self::zero();
@@ -225,8 +510,175 @@ impl Matrix4 {
         r
     }
 
+    /// Transpose this matrix in place
+    pub fn transpose_in_place(&mut self) {
+        for i in 0..4 {
+            for j in i + 1..4 {
+                let t = self.0[i][j];
+                self.0[i][j] = self.0[j][i];
+                self.0[j][i] = t;
+            }
+        }
+    }
+
+    /// Invert this matrix in place. See [`Matrix4::invert`].
+    pub fn invert_in_place(&mut self) {
+        *self = self.invert();
+    }
+
+    /// The product of this matrix and the transpose of another, `self * rhs.transpose()`,
+    /// without computing the transpose.
+    pub fn mul_transposed(&self, rhs: &Matrix4) -> Self {
+        // Column i of the product weights the columns of `self` by row i of `rhs`
+        let mut r = Self::zero();
+        for i in 0..4 {
+            r.0[i] = self.0[0] * rhs.0[0][i]
+                + self.0[1] * rhs.0[1][i]
+                + self.0[2] * rhs.0[2][i]
+                + self.0[3] * rhs.0[3][i];
+        }
+        r
+    }
+
+    /// Transform a vector by the transpose of this matrix, `self.transpose() * v`, without
+    /// computing the transpose.
+    pub fn transform_by_transpose(&self, v: Vector4) -> Vector4 {
+        Vector4::new(
+            self.0[0].dot(v),
+            self.0[1].dot(v),
+            self.0[2].dot(v),
+            self.0[3].dot(v),
+        )
+    }
+
+    /// Transform every point in a slice in place, as `self * p` does.
+    ///
+    /// The columns are loaded once for the whole slice, and the loop has no branches, so it
+    /// suits large vertex buffers and vectorizes well.
+    pub fn transform_points(&self, points: &mut [Point]) {
+        let [x, y, z, w] = self.0.map(Vector3::from);
+        for p in points {
+            *p = Point::from(x * p.x + y * p.y + z * p.z + w);
+        }
+    }
+
+    /// Transform every direction in a slice in place, ignoring translation, as `self * v`
+    /// does. See [`Self::transform_points`].
+    pub fn transform_vectors(&self, vectors: &mut [Vector3]) {
+        let [x, y, z] = [0, 1, 2].map(|i| Vector3::from(self.0[i]));
+        for v in vectors {
+            *v = x * v.x + y * v.y + z * v.z;
+        }
+    }
+
+    /// Transform a direction by the transpose of the upper 3x3 part of this matrix.
+    ///
+    /// Applied to the inverse of a transform, this transforms normals correctly even when
+    /// the transform contains non-uniform scale.
+    pub fn transform_vector_by_transpose(&self, v: Vector3) -> Vector3 {
+        Vector3::new(
+            Vector3::from(self.0[0]).dot(v),
+            Vector3::from(self.0[1]).dot(v),
+            Vector3::from(self.0[2]).dot(v),
+        )
+    }
+
     /// The inverse of this matrix.
+    ///
+    /// The result is not finite if this matrix is singular. See [`Matrix4::try_invert`].
     pub fn invert(&self) -> Self {
+        let (inv, det) = self.adjugate();
+        validate!(det != 0.0, "matrix is singular");
+        crate::nan_policy::apply(inv.scaled(1.0 / det), Self::is_finite, Self::zero, "invert")
+    }
+
+    /// The inverse of this matrix, which must be affine (its bottom row is `0, 0, 0, 1`).
+    ///
+    /// This inverts only the upper 3x3 part, and applies the inverse to the negated
+    /// translation, which is several times cheaper and more accurate than [`Self::invert`].
+    /// Unlike simply transposing the rotation, this also handles scale and shear.
+    pub fn invert_affine(&self) -> Self {
+        validate!(self.is_affine(), "matrix is not affine");
+        let linear = Matrix3::from(*self).invert();
+        let translation = linear * -Vector3::from(self.0[3]);
+        let mut inv = Matrix4::from(linear);
+        inv.0[3] = Vector4::new(translation.x, translation.y, translation.z, 1.0);
+        inv
+    }
+
+    /// The matrix that transforms normals consistently with this transform: the inverse
+    /// transpose of the upper 3x3 part.
+    ///
+    /// Transforming normals by the transform itself skews them away from the surface when it
+    /// contains non-uniform scale. Normals transformed by this matrix may need renormalizing.
+    pub fn normal_matrix(&self) -> Matrix3 {
+        Matrix3::from(*self).invert().transpose()
+    }
+
+    /// The inverse of this matrix, or [`MathError::SingularMatrix`] if it has none.
+    ///
+    /// This returns a `Result` rather than an `Option`, like the crate's other fallible
+    /// operations, so that the reason for the failure travels with it. Use [`Result::ok`] where
+    /// an `Option` is more convenient.
+    pub fn try_invert(&self) -> Result<Self, MathError> {
+        let (inv, det) = self.adjugate();
+        let inv = inv.scaled(1.0 / det);
+        if det != 0.0 && inv.is_finite() {
+            Ok(inv)
+        } else {
+            Err(MathError::SingularMatrix)
+        }
+    }
+
+    /// Whether every element of this matrix is finite (neither infinite nor NaN)
+    pub fn is_finite(&self) -> bool {
+        self.as_slice().iter().all(|x| x.is_finite())
+    }
+
+    /// The determinant of this matrix. This is zero for singular matrices, and negative for
+    /// those containing a reflection.
+    pub fn determinant(&self) -> f32 {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// The 2x2 minors of the first two columns, and the complementary minors of the last two
+    /// columns, from which the determinant is expanded.
+    fn minors(&self) -> ([f32; 6], [f32; 6]) {
+        let a = &self.0;
+
+        let s = [
+            a[0][0] * a[1][1] - a[1][0] * a[0][1],
+            a[0][0] * a[1][2] - a[1][0] * a[0][2],
+            a[0][0] * a[1][3] - a[1][0] * a[0][3],
+            a[0][1] * a[1][2] - a[1][1] * a[0][2],
+            a[0][1] * a[1][3] - a[1][1] * a[0][3],
+            a[0][2] * a[1][3] - a[1][2] * a[0][3],
+        ];
+
+        let c = [
+            a[2][0] * a[3][1] - a[3][0] * a[2][1],
+            a[2][0] * a[3][2] - a[3][0] * a[2][2],
+            a[2][0] * a[3][3] - a[3][0] * a[2][3],
+            a[2][1] * a[3][2] - a[3][1] * a[2][2],
+            a[2][1] * a[3][3] - a[3][1] * a[2][3],
+            a[2][2] * a[3][3] - a[3][2] * a[2][3],
+        ];
+
+        (s, c)
+    }
+
+    /// Every element of this matrix multiplied by a scalar
+    fn scaled(mut self, s: f32) -> Self {
+        for x in self.as_array_mut() {
+            *x *= s;
+        }
+        self
+    }
+
+    /// The adjugate of this matrix (the transpose of its cofactor matrix), and its
+    /// determinant. The inverse is the adjugate divided by the determinant.
+    fn adjugate(&self) -> (Self, f32) {
         let mut inv = Matrix4::zero();
 
         inv.0[0][0] = self.0[1][1] * self.0[2][2] * self.0[3][3]
@@ -341,28 +793,144 @@ impl Matrix4 {
             + self.0[2][0] * self.0[0][1] * self.0[1][2]
             - self.0[2][0] * self.0[0][2] * self.0[1][1];
 
-        let mut det = self.0[0][0] * inv.0[0][0]
+        // The first row of the matrix against the first column of cofactors reuses the products
+        // already computed, and rounds consistently with them
+        let det = self.0[0][0] * inv.0[0][0]
             + self.0[0][1] * inv.0[1][0]
             + self.0[0][2] * inv.0[2][0]
             + self.0[0][3] * inv.0[3][0];
-        det = 1.0 / det;
 
-        for i in 0..4 {
-            for j in 0..4 {
-                inv.0[i][j] *= det;
+        (inv, det)
+    }
+
+    /// This matrix raised to an integer power, i.e. multiplied by itself `n` times.
+    /// Negative powers raise the inverse of this matrix. Uses exponentiation by squaring.
+    pub fn powi(&self, n: i32) -> Self {
+        let mut base = if n < 0 { self.invert() } else { *self };
+        let mut n = n.unsigned_abs();
+        let mut result = Self::identity();
+
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
             }
+            base = base * base;
+            n >>= 1;
         }
 
-        inv
+        result
+    }
+
+    /// Whether every element of this matrix is within `epsilon` of the identity matrix.
+    pub fn is_identity(&self, epsilon: f32) -> bool {
+        let identity = Self::identity();
+        (0..4).all(|i| (0..4).all(|j| (self.0[i][j] - identity.0[i][j]).abs() <= epsilon))
     }
 
+    /// Whether this matrix is an affine transform, i.e. its bottom row is exactly `[0, 0, 0, 1]`.
+    pub fn is_affine(&self) -> bool {
+        self.row(3) == Vector4::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Whether the upper 3x3 part of this matrix is orthonormal (a rotation or reflection,
+    /// with no scale or shear) to within `epsilon`. Translation is ignored.
+    pub fn is_orthonormal(&self, epsilon: f32) -> bool {
+        self.has_uniform_scale(epsilon)
+            && (0..3).all(|i| (Vector3::from(self.0[i]).magnitude_squared() - 1.0).abs() <= epsilon)
+    }
+
+    /// Whether the upper 3x3 part of this matrix scales uniformly along every axis, with no
+    /// shear, to within `epsilon`. Translation is ignored.
+    pub fn has_uniform_scale(&self, epsilon: f32) -> bool {
+        let [x, y, z] = [0, 1, 2].map(|i| Vector3::from(self.0[i]));
+        let scale = x.magnitude_squared();
+
+        // Tolerances are relative to the scale, so that large scales aren't held to tighter limits
+        let epsilon = epsilon * scale.max(1.0);
+        (y.magnitude_squared() - scale).abs() <= epsilon
+            && (z.magnitude_squared() - scale).abs() <= epsilon
+            && x.dot(y).abs() <= epsilon
+            && x.dot(z).abs() <= epsilon
+            && y.dot(z).abs() <= epsilon
+    }
+
+    /// The transform that applies this matrix first, followed by `next`.
+    ///
+    /// Note that this is equivalent to `next * self`, as the right-hand side of a matrix
+    /// product is applied first.
+    pub fn then(&self, next: &Matrix4) -> Self {
+        *next * *self
+    }
+
+    /// View the elements of this matrix as an array, in column-major order
+    pub fn as_array(&self) -> &[f32; 16] {
+        // Safety: `repr(C)` lays out the four columns of four floats contiguously
+        unsafe { &*(self as *const Self as *const [f32; 16]) }
+    }
+
+    /// View the elements of this matrix as a mutable array, in column-major order
+    pub fn as_array_mut(&mut self) -> &mut [f32; 16] {
+        // Safety: as for `as_array`
+        unsafe { &mut *(self as *mut Self as *mut [f32; 16]) }
+    }
+
+    /// View the elements of this matrix as a slice, in column-major order
     pub fn as_slice(&self) -> &[f32] {
-        unsafe {
-            std::slice::from_raw_parts(
-                &self.0[0][0],
-                std::mem::size_of::<Self>() / std::mem::size_of::<f32>(),
-            )
+        self.as_array()
+    }
+
+    /// Write this matrix to a formatting sink. See [`FixedBuffer`](crate::FixedBuffer).
+    pub fn write_to(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        write!(w, "{}", self)
+    }
+}
+
+impl From<[[f32; 4]; 4]> for Matrix4 {
+    fn from(a: [[f32; 4]; 4]) -> Self {
+        Self::from_2d_array(a)
+    }
+}
+
+impl AsRef<[f32; 16]> for Matrix4 {
+    /// The elements of this matrix, in column-major order
+    fn as_ref(&self) -> &[f32; 16] {
+        self.as_array()
+    }
+}
+
+impl AsMut<[f32; 16]> for Matrix4 {
+    /// The elements of this matrix, in column-major order
+    fn as_mut(&mut self) -> &mut [f32; 16] {
+        self.as_array_mut()
+    }
+}
+
+impl AsRef<[[f32; 4]; 4]> for Matrix4 {
+    /// The columns of this matrix
+    fn as_ref(&self) -> &[[f32; 4]; 4] {
+        // Safety: both are `repr(C)` arrays of 4 columns of 4 contiguous floats
+        unsafe { &*(self as *const Self as *const [[f32; 4]; 4]) }
+    }
+}
+
+impl AsMut<[[f32; 4]; 4]> for Matrix4 {
+    /// The columns of this matrix
+    fn as_mut(&mut self) -> &mut [[f32; 4]; 4] {
+        // Safety: both are `repr(C)` arrays of 4 columns of 4 contiguous floats
+        unsafe { &mut *(self as *mut Self as *mut [[f32; 4]; 4]) }
+    }
+}
+
+/// Formats one row per line, applying any width or precision to each element
+impl std::fmt::Display for Matrix4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for i in 0..4 {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            std::fmt::Display::fmt(&self.row(i), f)?;
         }
+        Ok(())
     }
 }
 
@@ -376,6 +944,34 @@ impl NearlyEqual for &Matrix4 {
 
         true
     }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        for i in 0..4 {
+            if !self.0[i].nearly_equals_within(&rhs.0[i], tolerance) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        for i in 0..4 {
+            if !self.0[i].nearly_equals_ulps(&rhs.0[i], max_ulps) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        (0..4).find_map(|i| {
+            self.0[i]
+                .describe_mismatch(&rhs.0[i], tolerance)
+                .map(|inner| nested_mismatch(format!("column {}", i), inner))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -404,56 +1000,16 @@ mod tests {
         assert_eq!(m.invert() * m, Matrix4::identity());
 
         let n = Matrix4([
-            Vector4 {
-                x: 0.9742785,
-                y: 0.0,
-                z: 0.0,
-                w: 0.0,
-            },
-            Vector4 {
-                x: 0.0,
-                y: 1.7320507,
-                z: 0.0,
-                w: 0.0,
-            },
-            Vector4 {
-                x: 0.0,
-                y: 0.0,
-                z: -1.0002,
-                w: -1.0,
-            },
-            Vector4 {
-                x: 0.0,
-                y: 0.0,
-                z: -2.0002,
-                w: 0.0,
-            },
+            Vector4::new(0.9742785, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 1.7320507, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, -1.0002, -1.0),
+            Vector4::new(0.0, 0.0, -2.0002, 0.0),
         ]);
         let inverse = Matrix4([
-            Vector4 {
-                x: 1.0264006,
-                y: -0.0,
-                z: -0.0,
-                w: -0.0,
-            },
-            Vector4 {
-                x: -0.0,
-                y: 0.5773504,
-                z: -0.0,
-                w: -0.0,
-            },
-            Vector4 {
-                x: -0.0,
-                y: -0.0,
-                z: -0.0,
-                w: -0.49995005,
-            },
-            Vector4 {
-                x: -0.0,
-                y: -0.0,
-                z: -1.0000001,
-                w: 0.50005007,
-            },
+            Vector4::new(1.0264006, -0.0, -0.0, -0.0),
+            Vector4::new(-0.0, 0.5773504, -0.0, -0.0),
+            Vector4::new(-0.0, -0.0, -0.0, -0.49995005),
+            Vector4::new(-0.0, -0.0, -1.0000001, 0.50005007),
         ]);
         assert_eq!(n.invert(), inverse);
     }
@@ -484,6 +1040,18 @@ mod tests {
         let m =
             Matrix4::rotation_axis_angle(Vector3::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
         assert_nearly_eq!(m * Point::new(1.0, 0.0, 0.0), &Point::new(0.0, -1.0, 0.0));
+
+        // Arbitrary axes match composed axis rotations, and are normalized first
+        let m = Matrix4::rotation_axis_angle(Vector3::new(1.0, 1.0, 0.0), std::f32::consts::PI);
+        assert_nearly_eq!(m * Point::new(1.0, 0.0, 0.0), &Point::new(0.0, 1.0, 0.0));
+        assert_nearly_eq!(
+            &Matrix4::rotation_axis_angle(Vector3::new(0.0, 0.0, 3.0), 0.4),
+            &Matrix4::rotation_z(0.4),
+            epsilon = 1e-6
+        );
+        assert!(
+            Matrix4::rotation_axis_angle(Vector3::new(2.0, -1.0, 5.0), 1.1).is_orthonormal(1e-6)
+        );
     }
 
     #[test]
@@ -497,6 +1065,135 @@ mod tests {
         assert_nearly_eq!(m * Point::new(0.0, 0.0, 1.0), &Point::new(-1.0, 0.0, 0.0));
     }
 
+    #[test]
+    fn powi() {
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 0.0)) * Matrix4::uniform_scale(2.0);
+
+        assert_eq!(m.powi(0), Matrix4::identity());
+        assert_eq!(m.powi(1), m);
+        assert_eq!(m.powi(3), m * m * m);
+        assert_nearly_eq!(&(m.powi(-2) * m * m), &Matrix4::identity(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn memory_order() {
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+        let cols = m.to_cols_array();
+        let rows = m.to_rows_array();
+
+        assert_eq!(&cols[12..15], &[1.0, 2.0, 3.0]);
+        assert_eq!([rows[3], rows[7], rows[11]], [1.0, 2.0, 3.0]);
+        assert_eq!(cols, m.as_slice());
+        assert_eq!(Matrix4::from_1d_array(cols), m);
+        assert_eq!(
+            Matrix4::from_cols(m.column(0), m.column(1), m.column(2), m.column(3)),
+            m
+        );
+        assert_eq!(
+            Matrix4::from_rows(m.row(0), m.row(1), m.row(2), m.row(3)),
+            m
+        );
+
+        let m = Matrix4::from_1d_array(std::array::from_fn(|i| i as f32));
+        assert_eq!(m.to_cols_array(), std::array::from_fn(|i| i as f32));
+        assert_eq!(m.to_rows_array(), m.transpose().to_cols_array());
+    }
+
+    #[test]
+    fn look_at_pair() {
+        let eye = Point::new(1.0, 2.0, 3.0);
+        let (view, inverse) = Matrix4::look_at_pair(eye, Point::new(-2.0, 0.0, 1.0), Vector3::UP);
+
+        assert_nearly_eq!(&(view * inverse), &Matrix4::identity(), epsilon = 1e-6);
+        assert_nearly_eq!(&inverse, &view.invert(), epsilon = 1e-5);
+        assert_eq!(inverse * Point::ORIGIN, eye);
+        assert_eq!(
+            view,
+            Matrix4::look_at(eye, Point::new(-2.0, 0.0, 1.0), Vector3::UP)
+        );
+    }
+
+    #[test]
+    fn classification() {
+        let rotation = Matrix4::rotation_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7);
+        let rigid = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * rotation;
+        let scaled = rigid * Matrix4::uniform_scale(3.0);
+        let mut sheared = Matrix4::identity();
+        sheared.0[1].x = 0.5;
+        let perspective = Matrix4::perspective(1.0, 1.0, 0.1, 100.0);
+
+        assert!(Matrix4::identity().is_identity(0.0));
+        assert!((rotation * rotation.transpose()).is_identity(1e-6));
+        assert!(!rigid.is_identity(1e-6));
+
+        assert!(scaled.is_affine());
+        assert!(!perspective.is_affine());
+
+        assert!(rigid.is_orthonormal(1e-5));
+        assert!(!scaled.is_orthonormal(1e-5));
+        assert!(!sheared.is_orthonormal(1e-5));
+
+        assert!(scaled.has_uniform_scale(1e-5));
+        assert!(!sheared.has_uniform_scale(1e-5));
+    }
+
+    #[test]
+    fn then() {
+        let scale = Matrix4::uniform_scale(2.0);
+        let translate = Matrix4::translation(Vector3::new(1.0, 0.0, 0.0));
+        let p = Point::new(1.0, 1.0, 1.0);
+
+        assert_eq!(scale.then(&translate) * p, Point::new(3.0, 2.0, 2.0));
+        assert_eq!(translate.then(&scale) * p, Point::new(4.0, 2.0, 2.0));
+
+        let mut m = translate;
+        m *= scale;
+        assert_eq!(m, translate * scale);
+        m *= &scale;
+        assert_eq!(m, translate * scale * scale);
+    }
+
+    #[test]
+    fn rotation_align() {
+        let a = Vector3::new(1.0, 2.0, -2.0).normalized();
+        for b in [
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(-1.0, -2.0, 2.1).normalized(),
+            -a,
+        ] {
+            let m = Matrix4::rotation_align(a, b);
+            assert_nearly_eq!(&(m * a), &b, epsilon = 1e-5);
+            assert_nearly_eq!(&(m.transpose() * m), &Matrix4::identity(), epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn from_euler() {
+        let (x, y, z) = (0.3, -1.2, 2.0);
+        let (rx, ry, rz) = (
+            Matrix4::rotation_x(x),
+            Matrix4::rotation_y(y),
+            Matrix4::rotation_z(z),
+        );
+
+        assert_eq!(Matrix4::from_euler(EulerOrder::Xyz, x, y, z), rz * ry * rx);
+        assert_eq!(Matrix4::from_euler(EulerOrder::Zyx, x, y, z), rx * ry * rz);
+
+        // The first rotation in the order is applied first
+        let quarter = std::f32::consts::FRAC_PI_2;
+        let v = Vector3::new(0.0, 1.0, 0.0);
+        assert_nearly_eq!(
+            &(Matrix4::from_euler(EulerOrder::Xyz, quarter, 0.0, quarter) * v),
+            &Vector3::new(0.0, 0.0, -1.0),
+            epsilon = 1e-6
+        );
+        assert_nearly_eq!(
+            &(Matrix4::from_euler(EulerOrder::Zyx, quarter, 0.0, quarter) * v),
+            &Vector3::new(1.0, 0.0, 0.0),
+            epsilon = 1e-6
+        );
+    }
+
     #[test]
     fn slice() {
         let a = [
@@ -505,5 +1202,287 @@ mod tests {
         let m = Matrix4::from_1d_array(a);
 
         assert_eq!(m.as_slice(), &a);
+        assert_eq!(m.as_array(), &a);
+        assert_eq!(AsRef::<[f32; 16]>::as_ref(&m), &a);
+
+        let mut m = Matrix4::identity();
+        m.as_array_mut()[12] = 5.0;
+        assert_eq!(m, Matrix4::translation(Vector3::new(5.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn transposed() {
+        let a = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::rotation_x(0.4);
+        let b = Matrix4::perspective(1.2, 1.0, 0.5, 50.0) * Matrix4::rotation_y(-1.1);
+
+        assert_nearly_eq!(&a.mul_transposed(&b), &(a * b.transpose()), epsilon = 1e-6);
+
+        let v = Vector4::new(1.0, -2.0, 0.5, 1.0);
+        assert_nearly_eq!(
+            &b.transform_by_transpose(v),
+            &(b.transpose() * v),
+            epsilon = 1e-6
+        );
+
+        // Normals stay perpendicular to surfaces under non-uniform scale
+        let m = Matrix4::from_cols(
+            Vector4::new(4.0, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 1.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 1.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        );
+        let tangent = m * Vector3::new(1.0, -1.0, 0.0);
+        let normal = m
+            .invert()
+            .transform_vector_by_transpose(Vector3::new(1.0, 1.0, 0.0));
+        assert_nearly_eq!(tangent.dot(normal), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn in_place() {
+        let m = Matrix4::perspective(1.2, 1.0, 0.5, 50.0) * Matrix4::rotation_y(-1.1);
+
+        let mut t = m;
+        t.transpose_in_place();
+        assert_eq!(t, m.transpose());
+
+        let mut i = m;
+        i.invert_in_place();
+        assert_eq!(i, m.invert());
+
+        let mut v = Vector3::new(3.0, 0.0, 4.0);
+        v.normalize_in_place();
+        assert_eq!(v, Vector3::new(0.6, 0.0, 0.8));
+    }
+
+    #[test]
+    fn iterators() {
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(m.rows().len(), 4);
+        assert_eq!(m.rows().next(), Some(Vector4::new(1.0, 0.0, 0.0, 1.0)));
+        assert!(m.rows().enumerate().all(|(i, r)| r == m.row(i)));
+        assert_eq!(m.columns().last(), Some(Vector4::new(1.0, 2.0, 3.0, 1.0)));
+        assert_eq!(m.iter().collect::<Vec<_>>(), m.to_cols_array());
+    }
+
+    #[test]
+    fn map() {
+        let m = Matrix4::rotation_z(0.3) * Matrix4::uniform_scale(3.0);
+
+        assert!(m
+            .map(|x| x * 2.0)
+            .iter()
+            .zip(m.iter())
+            .all(|(a, b)| a == b * 2.0));
+        assert!(m.map(|x| x.clamp(-1.0, 1.0)).iter().all(|x| x.abs() <= 1.0));
+        assert_eq!(
+            m.zip_with(&Matrix4::identity(), |a, b| a - b),
+            m - Matrix4::identity()
+        );
+    }
+
+    #[test]
+    fn aim() {
+        let forward = Vector3::new(1.0, 0.0, -1.0);
+        let m = Matrix4::aim(forward, Vector3::UP);
+
+        assert!(m.is_orthonormal(1e-6));
+        assert_eq!(m.column(3), Vector4::new(0.0, 0.0, 0.0, 1.0));
+        assert_nearly_eq!(
+            &(m * -Vector3::UNIT_Z),
+            &forward.normalized(),
+            epsilon = 1e-6
+        );
+        assert_nearly_eq!(&(m * Vector3::UP), &Vector3::UP, epsilon = 1e-6);
+        assert_nearly_eq!(
+            &m,
+            &Matrix4::look_at(Point::ORIGIN, Point::from(forward), Vector3::UP).invert(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn orthographic() {
+        let m = Matrix4::orthographic(0.0, 800.0, 0.0, 600.0, 1.0, 11.0);
+
+        assert!(m.is_affine());
+        assert_eq!(m * Point::new(0.0, 0.0, -1.0), Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(
+            m * Point::new(800.0, 600.0, -11.0),
+            Point::new(1.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            m * Vector4::new(400.0, 300.0, -6.0, 1.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0)
+        );
+
+        let symmetric = Matrix4::orthographic_symmetric(20.0, 10.0, 0.0, 100.0);
+        assert_eq!(
+            symmetric,
+            Matrix4::orthographic(-10.0, 10.0, -5.0, 5.0, 0.0, 100.0)
+        );
+        assert_eq!(
+            symmetric * Point::new(10.0, -5.0, 0.0),
+            Point::new(1.0, -1.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn depth() {
+        // The depth in normalized device coordinates of a point at a distance in front of the camera
+        let depth = |m: Matrix4, distance: f32| {
+            let clip = m * Vector4::new(0.0, 0.0, -distance, 1.0);
+            clip.z / clip.w
+        };
+
+        let standard = Matrix4::perspective(1.5, 1.0, 0.1, 100.0);
+        assert_nearly_eq!(depth(standard, 0.1), -1.0, epsilon = 1e-5);
+        assert_nearly_eq!(depth(standard, 100.0), 1.0, epsilon = 1e-5);
+
+        let reversed = Matrix4::perspective_reversed_z(1.5, 1.0, 0.1, 100.0);
+        assert_nearly_eq!(depth(reversed, 0.1), 1.0, epsilon = 1e-6);
+        assert_nearly_eq!(depth(reversed, 100.0), 0.0, epsilon = 1e-6);
+        assert!(depth(reversed, 1.0) > depth(reversed, 2.0));
+
+        let infinite = Matrix4::perspective_infinite(1.5, 1.0, 0.1);
+        assert_nearly_eq!(depth(infinite, 0.1), -1.0, epsilon = 1e-6);
+        assert!(depth(infinite, 1e6) < 1.0);
+        assert_nearly_eq!(depth(infinite, 1e6), 1.0, epsilon = 1e-5);
+
+        let infinite_reversed = Matrix4::perspective_infinite_reversed_z(1.5, 1.0, 0.1);
+        assert_nearly_eq!(depth(infinite_reversed, 0.1), 1.0, epsilon = 1e-6);
+        assert!(depth(infinite_reversed, 1e30) > 0.0);
+        assert_eq!(infinite_reversed.column(0), standard.column(0));
+        assert_eq!(infinite_reversed.column(1), standard.column(1));
+    }
+
+    #[test]
+    fn depth_range() {
+        let depth = |m: Matrix4, distance: f32| {
+            let clip = m * Vector4::new(0.0, 0.0, -distance, 1.0);
+            clip.z / clip.w
+        };
+
+        let gl = Matrix4::perspective_with_depth_range(1.5, 1.0, 0.1, 100.0, DepthRange::default());
+        assert_eq!(gl, Matrix4::perspective(1.5, 1.0, 0.1, 100.0));
+
+        let vk = Matrix4::perspective_with_depth_range(1.5, 1.0, 0.1, 100.0, DepthRange::ZeroToOne);
+        assert_nearly_eq!(depth(vk, 0.1), 0.0, epsilon = 1e-6);
+        assert_nearly_eq!(depth(vk, 100.0), 1.0, epsilon = 1e-6);
+        assert_nearly_eq!(depth(vk, 1.0), (depth(gl, 1.0) + 1.0) * 0.5, epsilon = 1e-6);
+        assert_eq!(vk.column(0), gl.column(0));
+
+        let ortho = Matrix4::orthographic_with_depth_range(
+            -1.0,
+            1.0,
+            -1.0,
+            1.0,
+            2.0,
+            10.0,
+            DepthRange::ZeroToOne,
+        );
+        assert_eq!(depth(ortho, 2.0), 0.0);
+        assert_eq!(depth(ortho, 6.0), 0.5);
+        assert_eq!(depth(ortho, 10.0), 1.0);
+
+        let reversed =
+            Matrix4::perspective_with_depth_range(1.5, 1.0, 0.1, 100.0, DepthRange::OneToZero);
+        assert_nearly_eq!(
+            &reversed,
+            &Matrix4::perspective_reversed_z(1.5, 1.0, 0.1, 100.0),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn determinant() {
+        let m = Matrix4::from_2d_array([
+            [3.0, 2.0, 1.0, 1.0],
+            [2.0, -3.0, 2.0, 2.0],
+            [1.0, 2.0, 3.0, 5.0],
+            [0.0, 1.0, 1.0, 0.5],
+        ]);
+
+        assert_eq!(m.determinant(), m.adjugate().1);
+        assert_nearly_eq!(
+            (m * m).determinant(),
+            m.determinant() * m.determinant(),
+            epsilon = 1e-2
+        );
+        assert_eq!(Matrix4::uniform_scale(2.0).determinant(), 8.0);
+        assert_nearly_eq!(
+            (Matrix4::rotation_x(0.3) * Matrix4::translation(Vector3::one())).determinant(),
+            1.0,
+            epsilon = 1e-6
+        );
+        assert_eq!(Matrix4::zero().determinant(), 0.0);
+        assert!(Matrix4::zero().try_invert().is_err());
+    }
+
+    #[test]
+    fn invert_affine() {
+        let rigid = Matrix4::translation(Vector3::new(1.0, -2.0, 3.0))
+            * Matrix4::rotation_axis_angle(Vector3::new(1.0, 2.0, 2.0), 0.8);
+        assert_nearly_eq!(&rigid.invert_affine(), &rigid.invert(), epsilon = 1e-6);
+        assert_nearly_eq!(
+            &(rigid * rigid.invert_affine()),
+            &Matrix4::identity(),
+            epsilon = 1e-6
+        );
+
+        let scaled = rigid * Matrix4::uniform_scale(4.0) * Matrix4::rotation_x(0.2);
+        assert_nearly_eq!(&scaled.invert_affine(), &scaled.invert(), epsilon = 1e-6);
+
+        let p = Point::new(5.0, 0.5, -1.0);
+        assert_nearly_eq!(&(scaled.invert_affine() * (scaled * p)), &p, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn normal_matrix() {
+        // Squash a 45 degree slope vertically, which makes it shallower
+        let m = Matrix4::translation(Vector3::new(3.0, 0.0, 0.0))
+            * Matrix4::from(Matrix3::from_2d_array([
+                [1.0, 0.0, 0.0],
+                [0.0, 0.5, 0.0],
+                [0.0, 0.0, 1.0],
+            ]));
+        let tangent = Vector3::new(1.0, 1.0, 0.0);
+        let normal = Vector3::new(-1.0, 1.0, 0.0);
+
+        let transformed = m.normal_matrix() * normal;
+        assert_nearly_eq!((m * tangent).dot(transformed), 0.0, epsilon = 1e-6);
+        assert!((m * normal).dot(m * tangent).abs() > 0.1);
+
+        let rotation = Matrix4::rotation_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.9);
+        assert_nearly_eq!(
+            &rotation.normal_matrix(),
+            &Matrix3::from(rotation),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn transform_batch() {
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0))
+            * Matrix4::rotation_axis_angle(Vector3::new(1.0, -1.0, 2.0), 0.6)
+            * Matrix4::uniform_scale(1.5);
+
+        let original: Vec<_> = (0..37)
+            .map(|i| Point::new(i as f32, (i * 7 % 5) as f32, -0.5 * i as f32))
+            .collect();
+        let mut points = original.clone();
+        m.transform_points(&mut points);
+        for (p, q) in original.iter().zip(&points) {
+            assert_nearly_eq!(&(m * *p), q, epsilon = 1e-5);
+        }
+
+        let mut vectors: Vec<_> = original.iter().map(|p| Vector3::from(*p)).collect();
+        m.transform_vectors(&mut vectors);
+        for (p, v) in original.iter().zip(&vectors) {
+            assert_nearly_eq!(&(m * Vector3::from(*p)), v, epsilon = 1e-5);
+        }
+
+        m.transform_points(&mut []);
     }
 }
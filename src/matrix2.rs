@@ -0,0 +1,207 @@
+use crate::validate::validate;
+use crate::{nested_mismatch, MathError, Matrix3, NearlyEqual, Tolerance, Vector2, Vector3};
+
+/// A 2x2 matrix, suitable for linear transformations in 2D.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Matrix2(pub [Vector2; 2]);
+
+impl Matrix2 {
+    /// A new matrix from its column vectors.
+    pub const fn from_cols(c0: Vector2, c1: Vector2) -> Self {
+        Self([c0, c1])
+    }
+
+    /// The identity matrix.
+    pub const fn identity() -> Self {
+        Self([Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)])
+    }
+
+    /// A matrix composed entirely of zeroes.
+    pub const fn zero() -> Self {
+        Self([Vector2::new(0.0, 0.0), Vector2::new(0.0, 0.0)])
+    }
+
+    /// A matrix that rotates about the origin.
+    /// Note that, as with [`Matrix3::rotation_2d`], positive angles rotate clockwise.
+    pub fn rotation(angle_radians: f32) -> Self {
+        let (sin, cos) = angle_radians.sin_cos();
+        Self([Vector2::new(cos, -sin), Vector2::new(sin, cos)])
+    }
+
+    /// A matrix that scales along each axis.
+    pub const fn scale(scale: Vector2) -> Self {
+        Self([Vector2::new(scale.x, 0.0), Vector2::new(0.0, scale.y)])
+    }
+
+    /// Obtain the specified row vector of this matrix.
+    pub fn row(&self, i: usize) -> Vector2 {
+        Vector2::new(self.0[0][i], self.0[1][i])
+    }
+    /// Obtain the specified column vector of this matrix.
+    pub fn column(&self, i: usize) -> Vector2 {
+        self.0[i]
+    }
+
+    /// The transpose of this matrix (i.e. this matrix flipped along the diagonal)
+    pub fn transpose(&self) -> Self {
+        Self([self.row(0), self.row(1)])
+    }
+
+    /// The determinant of this matrix, which is the factor by which it scales areas
+    pub fn determinant(&self) -> f32 {
+        self.0[0].cross(self.0[1])
+    }
+
+    /// The inverse of this matrix.
+    ///
+    /// The result is not finite if this matrix is singular. See [`Matrix2::try_invert`].
+    pub fn invert(&self) -> Self {
+        let det = self.determinant();
+        validate!(det != 0.0, "matrix is singular");
+        crate::nan_policy::apply(
+            self.adjugate().scaled(1.0 / det),
+            Self::is_finite,
+            Self::zero,
+            "invert",
+        )
+    }
+
+    /// The inverse of this matrix, or [`MathError::SingularMatrix`] if it has none.
+    pub fn try_invert(&self) -> Result<Self, MathError> {
+        let det = self.determinant();
+        let inv = self.adjugate().scaled(1.0 / det);
+        if det != 0.0 && inv.is_finite() {
+            Ok(inv)
+        } else {
+            Err(MathError::SingularMatrix)
+        }
+    }
+
+    /// Whether every element of this matrix is finite (neither infinite nor NaN)
+    pub fn is_finite(&self) -> bool {
+        self.as_array().iter().all(|x| x.is_finite())
+    }
+
+    /// View the elements of this matrix as an array, in column-major order
+    pub fn as_array(&self) -> &[f32; 4] {
+        // Safety: `repr(C)` lays out the two columns of two floats contiguously
+        unsafe { &*(self as *const Self as *const [f32; 4]) }
+    }
+
+    /// View the elements of this matrix as a mutable array, in column-major order
+    pub fn as_array_mut(&mut self) -> &mut [f32; 4] {
+        // Safety: as for `as_array`
+        unsafe { &mut *(self as *mut Self as *mut [f32; 4]) }
+    }
+
+    /// Every element of this matrix multiplied by a scalar. This scales the elements directly
+    /// rather than through vector arithmetic, so that [`Matrix2::try_invert`] can inspect a
+    /// non-finite result without tripping `debug-validate`.
+    fn scaled(mut self, s: f32) -> Self {
+        for x in self.as_array_mut() {
+            *x *= s;
+        }
+        self
+    }
+
+    /// The adjugate of this matrix. The inverse is the adjugate divided by the determinant.
+    fn adjugate(&self) -> Self {
+        let [a, b] = self.0;
+        Self([Vector2::new(b.y, -a.y), Vector2::new(-b.x, a.x)])
+    }
+}
+
+impl std::ops::Mul for Matrix2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self([self * rhs.0[0], self * rhs.0[1]])
+    }
+}
+
+impl std::ops::Mul<Vector2> for Matrix2 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Vector2 {
+        self.0[0] * rhs.x + self.0[1] * rhs.y
+    }
+}
+
+/// Embeds this matrix in the upper-left 2x2 block, as a 2D affine transform without
+/// translation
+impl From<Matrix2> for Matrix3 {
+    fn from(m: Matrix2) -> Self {
+        let [c0, c1] = m.0;
+        Matrix3([
+            Vector3::new(c0.x, c0.y, 0.0),
+            Vector3::new(c1.x, c1.y, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ])
+    }
+}
+
+/// Formats one row per line, applying any width or precision to each element
+impl std::fmt::Display for Matrix2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.row(0), f)?;
+        f.write_str("\n")?;
+        std::fmt::Display::fmt(&self.row(1), f)
+    }
+}
+
+impl NearlyEqual for &Matrix2 {
+    fn nearly_equals(self, rhs: Self) -> bool {
+        (0..2).all(|i| self.0[i].nearly_equals(&rhs.0[i]))
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        (0..2).all(|i| self.0[i].nearly_equals_within(&rhs.0[i], tolerance))
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        (0..2).all(|i| self.0[i].nearly_equals_ulps(&rhs.0[i], max_ulps))
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        (0..2).find_map(|i| {
+            self.0[i]
+                .describe_mismatch(&rhs.0[i], tolerance)
+                .map(|inner| nested_mismatch(format!("column {}", i), inner))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn transform() {
+        let r = Matrix2::rotation(std::f32::consts::FRAC_PI_2);
+        assert_nearly_eq!(
+            &(r * Vector2::new(1.0, 0.0)),
+            &Vector2::new(0.0, -1.0),
+            epsilon = 1e-6
+        );
+        assert_nearly_eq!(
+            &Matrix3::from(r),
+            &Matrix3::rotation_2d(std::f32::consts::FRAC_PI_2),
+            epsilon = 1e-6
+        );
+
+        let m = r * Matrix2::scale(Vector2::new(2.0, 3.0));
+        assert_nearly_eq!(m.determinant(), 6.0, epsilon = 1e-5);
+        assert_nearly_eq!(&(m * m.invert()), &Matrix2::identity(), epsilon = 1e-6);
+        assert_nearly_eq!(&(m.invert() * m), &Matrix2::identity(), epsilon = 1e-6);
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(
+            Matrix2::from_cols(Vector2::new(1.0, 2.0), Vector2::new(2.0, 4.0)).try_invert(),
+            Err(MathError::SingularMatrix)
+        );
+        assert_eq!(
+            format!("{:.1}", Matrix2::scale(Vector2::new(2.0, 3.0))),
+            "(2.0, 0.0)\n(0.0, 3.0)"
+        );
+    }
+}
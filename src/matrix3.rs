@@ -0,0 +1,394 @@
+use crate::validate::validate;
+use crate::{
+    nested_mismatch, MathError, Matrix4, NearlyEqual, Tolerance, Vector2, Vector3, Vector4,
+};
+
+/// A 3x3 matrix, suitable for linear transformations in 3D, or affine transformations in 2D.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Matrix3(pub [Vector3; 3]);
+
+impl Matrix3 {
+    /// A new matrix from a 2D array.
+    pub const fn from_2d_array(a: [[f32; 3]; 3]) -> Self {
+        Self([
+            Vector3::new(a[0][0], a[0][1], a[0][2]),
+            Vector3::new(a[1][0], a[1][1], a[1][2]),
+            Vector3::new(a[2][0], a[2][1], a[2][2]),
+        ])
+    }
+
+    /// The identity matrix.
+    pub const fn identity() -> Self {
+        Self([
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ])
+    }
+
+    /// A matrix composed entirely of zeroes.
+    pub const fn zero() -> Self {
+        Self([
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 0.0),
+        ])
+    }
+
+    /// A matrix that translates 2D points by the given offset.
+    pub const fn translation_2d(v: Vector2) -> Self {
+        Self([
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(v.x, v.y, 1.0),
+        ])
+    }
+
+    /// A matrix that rotates 2D points about the origin.
+    /// Note that, as with [`crate::Matrix4::rotation_z`], positive angles rotate clockwise.
+    pub fn rotation_2d(angle_radians: f32) -> Self {
+        let (sin, cos) = angle_radians.sin_cos();
+        Self([
+            Vector3::new(cos, -sin, 0.0),
+            Vector3::new(sin, cos, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ])
+    }
+
+    /// A matrix that scales 2D points along each axis.
+    pub const fn scale_2d(scale: Vector2) -> Self {
+        Self([
+            Vector3::new(scale.x, 0.0, 0.0),
+            Vector3::new(0.0, scale.y, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ])
+    }
+
+    /// Transform a 2D direction by this matrix, ignoring translation.
+    /// Note that `m * v` instead transforms `v` as a point.
+    pub fn transform_vector_2d(&self, v: Vector2) -> Vector2 {
+        let r = *self * Vector3::new(v.x, v.y, 0.0);
+        Vector2::new(r.x, r.y)
+    }
+
+    /// Obtain the specified row vector of this matrix.
+    pub fn row(&self, i: usize) -> Vector3 {
+        Vector3::new(self.0[0][i], self.0[1][i], self.0[2][i])
+    }
+    /// Obtain the specified column vector of this matrix.
+    pub fn column(&self, i: usize) -> Vector3 {
+        self.0[i]
+    }
+
+    /// Write this matrix to a formatting sink. See [`FixedBuffer`](crate::FixedBuffer).
+    pub fn write_to(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        write!(w, "{}", self)
+    }
+
+    /// The transpose of this matrix (i.e. this matrix flipped along the diagonal)
+    pub fn transpose(&self) -> Self {
+        Self([self.row(0), self.row(1), self.row(2)])
+    }
+
+    /// The determinant of this matrix, which is the factor by which it scales volumes
+    pub fn determinant(&self) -> f32 {
+        self.0[0].dot(self.0[1].cross(self.0[2]))
+    }
+
+    /// The inverse of this matrix.
+    ///
+    /// The result is not finite if this matrix is singular. See [`Matrix3::try_invert`].
+    pub fn invert(&self) -> Self {
+        let (inv, det) = self.adjugate();
+        validate!(det != 0.0, "matrix is singular");
+        crate::nan_policy::apply(inv.scaled(1.0 / det), Self::is_finite, Self::zero, "invert")
+    }
+
+    /// The inverse of this matrix, or [`MathError::SingularMatrix`] if it has none.
+    pub fn try_invert(&self) -> Result<Self, MathError> {
+        let (inv, det) = self.adjugate();
+        let inv = inv.scaled(1.0 / det);
+        if det != 0.0 && inv.is_finite() {
+            Ok(inv)
+        } else {
+            Err(MathError::SingularMatrix)
+        }
+    }
+
+    /// Whether every element of this matrix is finite (neither infinite nor NaN)
+    pub fn is_finite(&self) -> bool {
+        self.as_array().iter().all(|x| x.is_finite())
+    }
+
+    /// View the elements of this matrix as an array, in column-major order
+    pub fn as_array(&self) -> &[f32; 9] {
+        // Safety: `repr(C)` lays out the three columns of three floats contiguously
+        unsafe { &*(self as *const Self as *const [f32; 9]) }
+    }
+
+    /// View the elements of this matrix as a mutable array, in column-major order
+    pub fn as_array_mut(&mut self) -> &mut [f32; 9] {
+        // Safety: as for `as_array`
+        unsafe { &mut *(self as *mut Self as *mut [f32; 9]) }
+    }
+
+    /// Every element of this matrix multiplied by a scalar. This scales the elements directly
+    /// rather than through vector arithmetic, so that [`Matrix3::try_invert`] can inspect a
+    /// non-finite result without tripping `debug-validate`.
+    fn scaled(mut self, s: f32) -> Self {
+        for x in self.as_array_mut() {
+            *x *= s;
+        }
+        self
+    }
+
+    /// The adjugate of this matrix, and its determinant. The rows of the adjugate are the
+    /// cross products of pairs of columns.
+    fn adjugate(&self) -> (Self, f32) {
+        let [c0, c1, c2] = self.0;
+        let rows = Self([c1.cross(c2), c2.cross(c0), c0.cross(c1)]);
+        (rows.transpose(), c0.dot(rows.0[0]))
+    }
+
+    /// The eigenvalues and eigenvectors of this matrix, which must be symmetric.
+    ///
+    /// Returns the eigenvalues in decreasing order, and a matrix whose columns are the
+    /// corresponding unit-length eigenvectors. Computed using Jacobi rotations.
+    pub fn symmetric_eigen(&self) -> (Vector3, Matrix3) {
+        let mut a = [[0.0f32; 3]; 3];
+        for (r, row) in a.iter_mut().enumerate() {
+            for (c, a) in row.iter_mut().enumerate() {
+                *a = self.0[c][r];
+            }
+        }
+        let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        for _ in 0..32 {
+            let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            if off_diagonal < 1e-30 {
+                break;
+            }
+
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                // Choose the rotation that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = [0, 1, 2].map(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = [0, 1, 2].map(|k| s * row_p[k] + c * row_q[k]);
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| a[j][j].total_cmp(&a[i][i]));
+
+        let values = Vector3::new(
+            a[order[0]][order[0]],
+            a[order[1]][order[1]],
+            a[order[2]][order[2]],
+        );
+        let vectors = Matrix3(order.map(|i| Vector3::new(v[0][i], v[1][i], v[2][i])));
+
+        (values, vectors)
+    }
+}
+
+impl std::ops::Mul for Matrix3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self([self * rhs.0[0], self * rhs.0[1], self * rhs.0[2]])
+    }
+}
+
+impl std::ops::Mul<Vector3> for Matrix3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        self.0[0] * rhs.x + self.0[1] * rhs.y + self.0[2] * rhs.z
+    }
+}
+
+/// The upper-left 3x3 block, which holds the rotation and scale of an affine transform
+impl From<Matrix4> for Matrix3 {
+    fn from(m: Matrix4) -> Self {
+        Self([m.0[0].into(), m.0[1].into(), m.0[2].into()])
+    }
+}
+
+/// Embeds this matrix in the upper-left 3x3 block, as a 3D transform without translation
+impl From<Matrix3> for Matrix4 {
+    fn from(m: Matrix3) -> Self {
+        let [c0, c1, c2] = m.0;
+        Matrix4::from_cols(c0.into(), c1.into(), c2.into(), Vector4::UNIT_W)
+    }
+}
+
+impl From<[[f32; 3]; 3]> for Matrix3 {
+    fn from(a: [[f32; 3]; 3]) -> Self {
+        Self::from_2d_array(a)
+    }
+}
+
+impl AsRef<[[f32; 3]; 3]> for Matrix3 {
+    /// The columns of this matrix
+    fn as_ref(&self) -> &[[f32; 3]; 3] {
+        // Safety: both are `repr(C)` arrays of 3 columns of 3 contiguous floats
+        unsafe { &*(self as *const Self as *const [[f32; 3]; 3]) }
+    }
+}
+
+impl AsMut<[[f32; 3]; 3]> for Matrix3 {
+    /// The columns of this matrix
+    fn as_mut(&mut self) -> &mut [[f32; 3]; 3] {
+        // Safety: both are `repr(C)` arrays of 3 columns of 3 contiguous floats
+        unsafe { &mut *(self as *mut Self as *mut [[f32; 3]; 3]) }
+    }
+}
+
+/// Transforms a 2D point, including translation.
+impl std::ops::Mul<Vector2> for Matrix3 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Vector2 {
+        let r = self * Vector3::new(rhs.x, rhs.y, 1.0);
+        Vector2::new(r.x, r.y)
+    }
+}
+
+/// Formats one row per line, applying any width or precision to each element
+impl std::fmt::Display for Matrix3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for i in 0..3 {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            std::fmt::Display::fmt(&self.row(i), f)?;
+        }
+        Ok(())
+    }
+}
+
+impl NearlyEqual for &Matrix3 {
+    fn nearly_equals(self, rhs: Self) -> bool {
+        (0..3).all(|i| self.0[i].nearly_equals(&rhs.0[i]))
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        (0..3).all(|i| self.0[i].nearly_equals_within(&rhs.0[i], tolerance))
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        (0..3).all(|i| self.0[i].nearly_equals_ulps(&rhs.0[i], max_ulps))
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        (0..3).find_map(|i| {
+            self.0[i]
+                .describe_mismatch(&rhs.0[i], tolerance)
+                .map(|inner| nested_mismatch(format!("column {}", i), inner))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn multiply() {
+        let m = Matrix3::from_2d_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+
+        assert_eq!(m * Matrix3::identity(), m);
+        assert_eq!(m * Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(m.transpose().row(1), m.column(1));
+        assert_eq!(
+            m * m,
+            Matrix3::from_2d_array([
+                [30.0, 36.0, 45.0],
+                [66.0, 81.0, 102.0],
+                [109.0, 134.0, 169.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn transform_2d() {
+        let m = Matrix3::translation_2d(Vector2::new(10.0, 0.0))
+            * Matrix3::rotation_2d(std::f32::consts::FRAC_PI_2)
+            * Matrix3::scale_2d(Vector2::new(2.0, 3.0));
+
+        assert_nearly_eq!(
+            &(m * Vector2::new(1.0, 0.0)),
+            &Vector2::new(10.0, -2.0),
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            &(m * Vector2::new(0.0, 1.0)),
+            &Vector2::new(13.0, 0.0),
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            &m.transform_vector_2d(Vector2::new(1.0, 0.0)),
+            &Vector2::new(0.0, -2.0),
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            &(Matrix3::rotation_2d(0.4) * Vector2::new(1.0, 2.0)),
+            &Vector2::new(1.0, 2.0).rotated(0.4),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen() {
+        let m = Matrix3::from_2d_array([[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]]);
+        let (values, vectors) = m.symmetric_eigen();
+
+        assert_nearly_eq!(&values, &Vector3::new(5.0, 3.0, 1.0), epsilon = 1e-5);
+        for i in 0..3 {
+            let v = vectors.column(i);
+            assert_nearly_eq!(&(m * v), &(v * values[i]), epsilon = 1e-5);
+            assert_nearly_eq!(v.magnitude(), 1.0, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn invert() {
+        let m = Matrix3::from_2d_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+
+        assert_nearly_eq!(m.determinant(), -3.0, epsilon = 1e-5);
+        assert_nearly_eq!(&(m * m.invert()), &Matrix3::identity(), epsilon = 1e-5);
+        assert_eq!(m.try_invert(), Ok(m.invert()));
+        assert_eq!(
+            Matrix3::from_2d_array([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 0.0]])
+                .try_invert(),
+            Err(MathError::SingularMatrix)
+        );
+
+        let rotation = Matrix4::rotation_axis_angle(Vector3::new(0.0, 0.6, 0.8), 1.0);
+        let affine = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * rotation;
+        assert_eq!(Matrix4::from(Matrix3::from(affine)), rotation);
+        assert_nearly_eq!(Matrix3::from(rotation).determinant(), 1.0, epsilon = 1e-6);
+        let v = Vector3::new(1.0, -2.0, 0.5);
+        assert_eq!(Matrix3::from(affine) * v, rotation * v);
+    }
+}
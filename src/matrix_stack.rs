@@ -0,0 +1,94 @@
+use crate::Matrix4;
+
+/// A stack of transforms in the style of OpenGL's matrix stack, for traversing a hierarchy
+/// while accumulating transforms.
+///
+/// The stack always holds at least one matrix, starting with the identity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixStack {
+    stack: Vec<Matrix4>,
+}
+
+impl MatrixStack {
+    /// Construct a stack holding only the identity matrix
+    pub fn new() -> Self {
+        Self {
+            stack: vec![Matrix4::identity()],
+        }
+    }
+
+    /// The matrix on the top of the stack
+    pub fn current(&self) -> &Matrix4 {
+        self.stack.last().expect("the stack is never empty")
+    }
+
+    /// Replace the matrix on the top of the stack
+    pub fn set(&mut self, matrix: Matrix4) {
+        *self.stack.last_mut().expect("the stack is never empty") = matrix;
+    }
+
+    /// Multiply the matrix on the top of the stack by another, which applies before it
+    pub fn mul(&mut self, matrix: &Matrix4) {
+        let current = *self.current() * *matrix;
+        self.set(current);
+    }
+
+    /// Push a copy of the current matrix onto the stack
+    pub fn push(&mut self) {
+        self.stack.push(*self.current());
+    }
+
+    /// Push the current matrix multiplied by another, as when descending into a child
+    pub fn push_mul(&mut self, matrix: &Matrix4) {
+        self.stack.push(*self.current() * *matrix);
+    }
+
+    /// Pop the matrix on the top of the stack, restoring the one below it.
+    /// Returns `None`, leaving the stack unchanged, if only one matrix remains.
+    pub fn pop(&mut self) -> Option<Matrix4> {
+        if self.stack.len() > 1 {
+            self.stack.pop()
+        } else {
+            None
+        }
+    }
+
+    /// The number of matrices on the stack, which is always at least one
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+impl Default for MatrixStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn stack() {
+        let parent = Matrix4::translation(Vector3::new(1.0, 0.0, 0.0));
+        let child = Matrix4::uniform_scale(2.0);
+
+        let mut stack = MatrixStack::new();
+        stack.push_mul(&parent);
+        stack.push();
+        stack.mul(&child);
+        assert_eq!(stack.depth(), 3);
+        assert_eq!(*stack.current(), parent * child);
+        assert_eq!(
+            *stack.current() * Point::new(1.0, 0.0, 0.0),
+            Point::new(3.0, 0.0, 0.0)
+        );
+
+        assert_eq!(stack.pop(), Some(parent * child));
+        assert_eq!(*stack.current(), parent);
+        assert_eq!(stack.pop(), Some(parent));
+        assert_eq!(stack.pop(), None);
+        assert_eq!(*stack.current(), Matrix4::identity());
+    }
+}
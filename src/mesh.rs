@@ -0,0 +1,175 @@
+//! Processing routines for indexed triangle meshes.
+
+use crate::{Point, Vector2, Vector3, Vector4};
+
+/// How the faces around a vertex contribute to its smooth normal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum NormalWeighting {
+    /// Larger faces contribute more. This is the cheapest option, and works well for meshes
+    /// with evenly sized triangles.
+    #[default]
+    Area,
+    /// Faces contribute according to the angle they make at the vertex, so that the result
+    /// doesn't depend on how the surface is triangulated.
+    Angle,
+}
+
+/// Compute a smooth unit-length normal for each vertex of a mesh, by averaging the normals of
+/// the faces around it.
+///
+/// Triangles are listed as triples of indices into `positions`, and face the side from which
+/// they appear in counter-clockwise order. Vertices that belong to no (non-degenerate) triangle
+/// get a zero normal.
+pub fn compute_normals(
+    positions: &[Point],
+    indices: &[u32],
+    weighting: NormalWeighting,
+) -> Vec<Vector3> {
+    let mut normals = vec![Vector3::zero(); positions.len()];
+
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(|i| i as usize);
+        let (pa, pb, pc) = (positions[a], positions[b], positions[c]);
+
+        // The cross product's length is twice the area of the triangle
+        let face = (pb - pa).cross(pc - pa);
+
+        match weighting {
+            NormalWeighting::Area => {
+                for i in [a, b, c] {
+                    normals[i] += face;
+                }
+            }
+            NormalWeighting::Angle => {
+                let face = face.normalized();
+                for (i, p, q, r) in [(a, pa, pb, pc), (b, pb, pc, pa), (c, pc, pa, pb)] {
+                    let cos = (q - p).normalized().dot((r - p).normalized());
+                    normals[i] += face * cos.clamp(-1.0, 1.0).acos();
+                }
+            }
+        }
+    }
+
+    for n in &mut normals {
+        *n = n.normalized();
+    }
+    normals
+}
+
+/// Compute a unit-length tangent for each vertex of a mesh, for normal mapping, from its
+/// texture coordinates.
+///
+/// The tangent points along increasing `u`, and is made perpendicular to the normal. The `w`
+/// component holds the handedness of the tangent space, following the glTF convention that the
+/// bitangent is `normal.cross(tangent) * w`, so it is `-1` where the texture is mirrored.
+/// Vertices without a usable texture mapping get an arbitrary tangent perpendicular to the normal.
+pub fn compute_tangents(
+    positions: &[Point],
+    normals: &[Vector3],
+    uvs: &[Vector2],
+    indices: &[u32],
+) -> Vec<Vector4> {
+    let mut tangents = vec![Vector3::zero(); positions.len()];
+    let mut bitangents = vec![Vector3::zero(); positions.len()];
+
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(|i| i as usize);
+        let (e1, e2) = (positions[b] - positions[a], positions[c] - positions[a]);
+        let (d1, d2) = (uvs[b] - uvs[a], uvs[c] - uvs[a]);
+
+        let determinant = d1.cross(d2);
+        if determinant == 0.0 {
+            continue;
+        }
+
+        // Solve for the directions of increasing u and v across the triangle
+        let r = 1.0 / determinant;
+        let t = (e1 * d2.y - e2 * d1.y) * r;
+        let bt = (e2 * d1.x - e1 * d2.x) * r;
+        for i in [a, b, c] {
+            tangents[i] += t;
+            bitangents[i] += bt;
+        }
+    }
+
+    normals
+        .iter()
+        .zip(tangents.iter().zip(&bitangents))
+        .map(|(n, (t, b))| {
+            let t = *t - *n * n.dot(*t);
+            let t = if t.magnitude_squared() > 0.0 {
+                t.normalized()
+            } else {
+                n.any_perpendicular()
+            };
+            let w = if n.cross(t).dot(*b) < 0.0 { -1.0 } else { 1.0 };
+            Vector4::new(t.x, t.y, t.z, w)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn normals() {
+        // Two triangles folded along the x-axis, one much larger than the other
+        let positions = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, -10.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(5.0, 5.0, 5.0),
+        ];
+        let indices = [0, 1, 2, 0, 1, 3];
+
+        let area = compute_normals(&positions, &indices, NormalWeighting::Area);
+        assert_nearly_eq!(&area[2], &Vector3::UP, epsilon = 1e-6);
+        assert_nearly_eq!(&area[3], &Vector3::BACK, epsilon = 1e-6);
+        assert!(area[0].y > area[0].z);
+        assert_nearly_eq!(area[0].magnitude(), 1.0, epsilon = 1e-6);
+        assert_eq!(area[4], Vector3::zero());
+
+        // Both faces meet vertex 0 at a right angle, so contribute equally
+        let angle = compute_normals(&positions, &indices, NormalWeighting::Angle);
+        assert_nearly_eq!(
+            &angle[0],
+            &Vector3::new(0.0, 1.0, 1.0).normalized(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn tangents() {
+        // A quad in the xy-plane, with u along x and v along y
+        let positions = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(2.0, 2.0, 0.0),
+            Point::new(0.0, 2.0, 0.0),
+        ];
+        let mut uvs = [
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 0.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(0.0, 1.0),
+        ];
+        let indices = [0, 1, 2, 0, 2, 3];
+        let normals = compute_normals(&positions, &indices, NormalWeighting::Area);
+
+        let tangents = compute_tangents(&positions, &normals, &uvs, &indices);
+        for t in &tangents {
+            assert_nearly_eq!(t, &Vector4::new(1.0, 0.0, 0.0, 1.0), epsilon = 1e-6);
+        }
+
+        // Mirroring the texture vertically flips the handedness
+        for uv in &mut uvs {
+            uv.y = 1.0 - uv.y;
+        }
+        let tangents = compute_tangents(&positions, &normals, &uvs, &indices);
+        for t in &tangents {
+            assert_nearly_eq!(t, &Vector4::new(1.0, 0.0, 0.0, -1.0), epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! What critical operations like normalization and inversion do when their result is not
+//! finite, chosen at compile time: by default the result is returned as-is, so NaNs propagate
+//! silently. The `nan-panic` feature panics instead, and `nan-saturate` returns zero. If both
+//! are enabled, `nan-panic` takes precedence, so that features remain additive.
+
+/// Apply the NaN policy to the result of an operation. Neither closure is called unless a
+/// policy other than propagation is enabled.
+#[inline(always)]
+pub(crate) fn apply<T>(
+    result: T,
+    is_finite: impl FnOnce(&T) -> bool,
+    zero: impl FnOnce() -> T,
+    operation: &str,
+) -> T {
+    #[cfg(feature = "nan-panic")]
+    {
+        let _ = zero;
+        assert!(
+            is_finite(&result),
+            "{} produced a non-finite result",
+            operation
+        );
+        result
+    }
+
+    #[cfg(all(feature = "nan-saturate", not(feature = "nan-panic")))]
+    {
+        let _ = operation;
+        if is_finite(&result) {
+            result
+        } else {
+            zero()
+        }
+    }
+
+    #[cfg(not(any(feature = "nan-panic", feature = "nan-saturate")))]
+    {
+        let _ = (is_finite, zero, operation);
+        result
+    }
+}
+
+// With `debug-validate`, validation rejects non-finite results before a propagating or saturating
+// policy sees them
+#[cfg(all(test, any(feature = "nan-panic", not(feature = "debug-validate"))))]
+mod tests {
+    use crate::*;
+
+    /// The results of each operation under the policy, from non-finite or singular inputs. The
+    /// inputs are built without validation, so that `debug-validate` doesn't reject them first.
+    fn non_finite() -> (Vector3, Matrix4, Quaternion, Vec<Vector3>, Vector3xN) {
+        let infinite = Vector3::from_scalar(f32::INFINITY);
+        // Enough vectors to fill a SIMD chunk and leave a remainder
+        let mut batch = vec![infinite; 5];
+        normalize_all(&mut batch);
+        let mut components = Vector3xN::from_elem(infinite, 5);
+        components.normalize();
+
+        (
+            infinite.normalized(),
+            Matrix4::uniform_scale(0.0).invert(),
+            Quaternion::new(f32::INFINITY, 0.0, 0.0, 1.0).normalized(),
+            batch,
+            components,
+        )
+    }
+
+    #[test]
+    #[cfg(not(any(
+        feature = "nan-panic",
+        feature = "nan-saturate",
+        feature = "debug-validate"
+    )))]
+    fn propagate() {
+        let (v, m, q, batch, components) = non_finite();
+        assert!(!v.is_finite());
+        assert!(!m.is_finite());
+        assert!(!q.is_finite());
+        assert!(batch.iter().all(|v| !v.is_finite()));
+        assert!(components.iter().all(|v| !v.is_finite()));
+    }
+
+    #[test]
+    #[cfg(feature = "nan-panic")]
+    // With `debug-validate`, validation may reject the intermediate result first
+    #[should_panic(expected = "non-finite")]
+    fn panic() {
+        non_finite();
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "nan-saturate",
+        not(feature = "nan-panic"),
+        not(feature = "debug-validate")
+    ))]
+    fn saturate() {
+        assert_eq!(
+            non_finite(),
+            (
+                Vector3::zero(),
+                Matrix4::zero(),
+                Quaternion::new(0.0, 0.0, 0.0, 0.0),
+                vec![Vector3::zero(); 5],
+                Vector3xN::from_elem(Vector3::zero(), 5)
+            )
+        );
+    }
+}
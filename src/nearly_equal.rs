@@ -1,11 +1,187 @@
-/// Compare floating-point values using an epsilon
+/// Tolerances used when comparing floating-point values.
+///
+/// Two values are considered nearly equal if their difference is within the absolute
+/// tolerance, or within the relative tolerance scaled by the larger of the two magnitudes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tolerance {
+    pub absolute: f32,
+    pub relative: f32,
+}
+
+impl Tolerance {
+    /// Construct a tolerance from both an absolute and a relative epsilon
+    pub const fn new(absolute: f32, relative: f32) -> Self {
+        Self { absolute, relative }
+    }
+
+    /// A tolerance that only considers the absolute difference between values
+    pub const fn absolute(epsilon: f32) -> Self {
+        Self::new(epsilon, 0.0)
+    }
+
+    /// A tolerance that only considers the difference relative to the magnitude of the values
+    pub const fn relative(epsilon: f32) -> Self {
+        Self::new(0.0, epsilon)
+    }
+}
+
+impl Default for Tolerance {
+    /// The default tolerance for single-precision values, an absolute epsilon of `f32::EPSILON`
+    fn default() -> Self {
+        Self::absolute(f32::EPSILON)
+    }
+}
+
+/// Compare floating-point values using an epsilon.
+///
+/// Only [`NearlyEqual::nearly_equals`] is required. Implement the other methods to support
+/// custom tolerances and more detailed assertion failures.
 pub trait NearlyEqual {
+    /// Compare using a fixed epsilon matching the precision of the compared values: each
+    /// component must differ by strictly less than `f32::EPSILON` (or `f64::EPSILON`)
     fn nearly_equals(self, rhs: Self) -> bool;
+
+    /// Compare using the given absolute and relative tolerances. Types that don't implement
+    /// this ignore the tolerance, and compare with [`NearlyEqual::nearly_equals`].
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = tolerance;
+        self.nearly_equals(rhs)
+    }
+
+    /// Compare by the number of representable floats (units in the last place) between values.
+    /// Types that don't implement this compare with [`NearlyEqual::nearly_equals`].
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = max_ulps;
+        self.nearly_equals(rhs)
+    }
+
+    /// The tolerance [`assert_nearly_eq!`](crate::assert_nearly_eq) uses when none is given. This is an absolute epsilon
+    /// matching the precision of the compared values: `f32::EPSILON` unless overridden.
+    fn default_tolerance() -> Tolerance
+    where
+        Self: Sized,
+    {
+        Tolerance::default()
+    }
+
+    /// Describe which component differs by more than the tolerance, or `None` if the values
+    /// are nearly equal. Floats describe both values and their difference, and types that don't
+    /// implement this describe a mismatch with an empty string.
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String>
+    where
+        Self: Sized,
+    {
+        if self.nearly_equals_within(rhs, tolerance) {
+            None
+        } else {
+            Some(String::new())
+        }
+    }
+}
+
+/// Prefix the description of a nested mismatch with the location of the component.
+#[doc(hidden)]
+pub fn nested_mismatch(location: String, inner: String) -> String {
+    if inner.is_empty() {
+        location
+    } else {
+        format!("{}, {}", location, inner)
+    }
 }
 
 impl NearlyEqual for f32 {
     fn nearly_equals(self, rhs: Self) -> bool {
-        (self - rhs).abs() < std::f32::EPSILON
+        (self - rhs).abs() < f32::EPSILON
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        let diff = (self - rhs).abs();
+        diff <= tolerance.absolute || diff <= tolerance.relative * self.abs().max(rhs.abs())
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        if self.is_nan() || rhs.is_nan() {
+            return false;
+        }
+
+        // Map the sign-magnitude bit patterns onto a monotonic integer line,
+        // so that adjacent floats (including across zero) are adjacent integers.
+        fn ordered(f: f32) -> i64 {
+            let bits = f.to_bits() as i32;
+            if bits < 0 {
+                i32::MIN as i64 - bits as i64
+            } else {
+                bits as i64
+            }
+        }
+
+        (ordered(self) - ordered(rhs)).unsigned_abs() <= max_ulps as u64
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        if self.nearly_equals_within(rhs, tolerance) {
+            None
+        } else {
+            Some(format!(
+                "`{:?}` vs `{:?}` (diff {:?})",
+                self,
+                rhs,
+                rhs - self
+            ))
+        }
+    }
+}
+
+impl NearlyEqual for f64 {
+    fn nearly_equals(self, rhs: Self) -> bool {
+        (self - rhs).abs() < f64::EPSILON
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        let diff = (self - rhs).abs();
+        diff <= tolerance.absolute as f64
+            || diff <= tolerance.relative as f64 * self.abs().max(rhs.abs())
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        if self.is_nan() || rhs.is_nan() {
+            return false;
+        }
+
+        fn ordered(f: f64) -> i128 {
+            let bits = f.to_bits() as i64;
+            if bits < 0 {
+                i64::MIN as i128 - bits as i128
+            } else {
+                bits as i128
+            }
+        }
+
+        (ordered(self) - ordered(rhs)).unsigned_abs() <= max_ulps as u128
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        if self.nearly_equals_within(rhs, tolerance) {
+            None
+        } else {
+            Some(format!(
+                "`{:?}` vs `{:?}` (diff {:?})",
+                self,
+                rhs,
+                rhs - self
+            ))
+        }
+    }
+
+    /// An absolute epsilon of `f64::EPSILON`
+    fn default_tolerance() -> Tolerance {
+        Tolerance::absolute(f64::EPSILON as f32)
     }
 }
 
@@ -20,17 +196,449 @@ where
             _ => false,
         }
     }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        match (self, rhs) {
+            (Some(a), Some(b)) => a.nearly_equals_within(b, tolerance),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        match (self, rhs) {
+            (Some(a), Some(b)) => a.nearly_equals_ulps(b, max_ulps),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn default_tolerance() -> Tolerance {
+        T::default_tolerance()
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        match (self, rhs) {
+            (Some(a), Some(b)) => a.describe_mismatch(b, tolerance),
+            (None, None) => None,
+            _ => Some(String::new()),
+        }
+    }
+}
+
+impl NearlyEqual for &f32 {
+    fn nearly_equals(self, rhs: Self) -> bool {
+        (*self).nearly_equals(*rhs)
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        (*self).nearly_equals_within(*rhs, tolerance)
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        (*self).nearly_equals_ulps(*rhs, max_ulps)
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        (*self).describe_mismatch(*rhs, tolerance)
+    }
+}
+
+impl NearlyEqual for &f64 {
+    fn nearly_equals(self, rhs: Self) -> bool {
+        (*self).nearly_equals(*rhs)
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        (*self).nearly_equals_within(*rhs, tolerance)
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        (*self).nearly_equals_ulps(*rhs, max_ulps)
+    }
+
+    fn default_tolerance() -> Tolerance {
+        f64::default_tolerance()
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        (*self).describe_mismatch(*rhs, tolerance)
+    }
+}
+
+impl<'a, T> NearlyEqual for &'a [T]
+where
+    &'a T: NearlyEqual,
+{
+    fn nearly_equals(self, rhs: Self) -> bool {
+        self.len() == rhs.len() && self.iter().zip(rhs).all(|(a, b)| a.nearly_equals(b))
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        self.len() == rhs.len()
+            && self
+                .iter()
+                .zip(rhs)
+                .all(|(a, b)| a.nearly_equals_within(b, tolerance))
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        self.len() == rhs.len()
+            && self
+                .iter()
+                .zip(rhs)
+                .all(|(a, b)| a.nearly_equals_ulps(b, max_ulps))
+    }
+
+    fn default_tolerance() -> Tolerance {
+        <&T>::default_tolerance()
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        if self.len() != rhs.len() {
+            return Some(format!("length: `{}` vs `{}`", self.len(), rhs.len()));
+        }
+
+        self.iter().zip(rhs).enumerate().find_map(|(i, (a, b))| {
+            a.describe_mismatch(b, tolerance)
+                .map(|inner| nested_mismatch(format!("index {}", i), inner))
+        })
+    }
+}
+
+impl<'a, T, const N: usize> NearlyEqual for &'a [T; N]
+where
+    &'a T: NearlyEqual,
+{
+    fn nearly_equals(self, rhs: Self) -> bool {
+        self.as_slice().nearly_equals(rhs)
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        self.as_slice().nearly_equals_within(rhs, tolerance)
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        self.as_slice().nearly_equals_ulps(rhs, max_ulps)
+    }
+
+    fn default_tolerance() -> Tolerance {
+        <&T>::default_tolerance()
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        self.as_slice().describe_mismatch(rhs, tolerance)
+    }
+}
+
+impl<T, const N: usize> NearlyEqual for [T; N]
+where
+    for<'a> &'a T: NearlyEqual,
+{
+    fn nearly_equals(self, rhs: Self) -> bool {
+        (&self).nearly_equals(&rhs)
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        (&self).nearly_equals_within(&rhs, tolerance)
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        (&self).nearly_equals_ulps(&rhs, max_ulps)
+    }
+
+    fn default_tolerance() -> Tolerance {
+        <&T>::default_tolerance()
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        (&self).describe_mismatch(&rhs, tolerance)
+    }
+}
+
+macro_rules! implement_tuple {
+    ($($T:ident : $i:tt),+) => {
+        impl<$($T),+> NearlyEqual for ($($T,)+)
+        where
+            $($T: NearlyEqual),+
+        {
+            fn nearly_equals(self, rhs: Self) -> bool {
+                $(self.$i.nearly_equals(rhs.$i))&&+
+            }
+
+            fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+                $(self.$i.nearly_equals_within(rhs.$i, tolerance))&&+
+            }
+
+            fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+                $(self.$i.nearly_equals_ulps(rhs.$i, max_ulps))&&+
+            }
+
+            /// The tightest of the default tolerances of the elements
+            fn default_tolerance() -> Tolerance {
+                let tolerances = [$($T::default_tolerance()),+];
+                Tolerance::new(
+                    tolerances.iter().map(|t| t.absolute).fold(f32::INFINITY, f32::min),
+                    tolerances.iter().map(|t| t.relative).fold(f32::INFINITY, f32::min),
+                )
+            }
+
+            fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+                $(if let Some(inner) = self.$i.describe_mismatch(rhs.$i, tolerance) {
+                    return Some(nested_mismatch(format!("element {}", $i), inner));
+                })+
+                None
+            }
+        }
+    };
+}
+
+implement_tuple!(A: 0);
+implement_tuple!(A: 0, B: 1);
+implement_tuple!(A: 0, B: 1, C: 2);
+implement_tuple!(A: 0, B: 1, C: 2, D: 3);
+
+/// Look up the default tolerance of a value's type through method call syntax, so that
+/// [`assert_nearly_eq!`] resolves the same (possibly auto-referenced) impl as the comparison.
+#[doc(hidden)]
+pub trait DefaultTolerance {
+    fn default_tolerance_of(self) -> Tolerance;
+}
+
+impl<T: NearlyEqual> DefaultTolerance for T {
+    fn default_tolerance_of(self) -> Tolerance {
+        T::default_tolerance()
+    }
+}
+
+/// Compare single-precision results against a double-precision reference computation.
+///
+/// The comparison is performed in double precision, so the tolerance only needs to account for
+/// the error of the single-precision computation. Values of different lengths are never equal.
+pub fn nearly_equals_reference(values: &[f32], reference: &[f64], tolerance: Tolerance) -> bool {
+    values.len() == reference.len()
+        && values
+            .iter()
+            .zip(reference)
+            .all(|(&v, &r)| (v as f64).nearly_equals_within(r, tolerance))
 }
 
 /// Asserts that two expressions are nearly equal to each other (using [`NearlyEqual`]).
+///
+/// An absolute `epsilon = ...` or a full `tolerance = ...` may be supplied, followed by
+/// an optional custom panic message. On failure, the first diverging component is reported.
+///
+/// ```
+/// # use mini_math::*;
+/// let v = Vector3::new(1.0, 2.0, 3.0);
+///
+/// assert_nearly_eq!(&v, &Vector3::new(1.0, 2.0, 3.0));
+/// assert_nearly_eq!(&v, &Vector3::new(1.0, 2.0, 3.001), epsilon = 0.01);
+/// assert_nearly_eq!(&v, &(v * 1.001), tolerance = Tolerance::relative(0.01), "scaled {:?}", v);
+/// ```
 #[macro_export]
 macro_rules! assert_nearly_eq {
-    ($left:expr, $right:expr) => {{
-        if !($left.nearly_equals($right)) {
-            panic!(
-                "assertion failed: `(left == right)`\nleft: `{:?}`,\nright: `{:?}`",
-                $left, $right
-            )
+    (@check $left:expr, $right:expr, $tolerance:expr, $message:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::NearlyEqual as _;
+
+        match ($left, $right, $tolerance) {
+            (left, right, tolerance) => {
+                if let Some(mismatch) = left.describe_mismatch(right, tolerance) {
+                    panic!(
+                        "assertion failed: `(left == right)`{}\nleft: `{:?}`,\nright: `{:?}`{}",
+                        $message,
+                        left,
+                        right,
+                        if mismatch.is_empty() {
+                            String::new()
+                        } else {
+                            format!(",\nmismatch: {}", mismatch)
+                        }
+                    )
+                }
+            }
         }
     }};
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_nearly_eq!($left, $right, tolerance = $crate::Tolerance::absolute($epsilon))
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, $($arg:tt)+) => {
+        $crate::assert_nearly_eq!($left, $right, tolerance = $crate::Tolerance::absolute($epsilon), $($arg)+)
+    };
+    ($left:expr, $right:expr, tolerance = $tolerance:expr $(,)?) => {
+        $crate::assert_nearly_eq!(@check $left, $right, $tolerance, "")
+    };
+    ($left:expr, $right:expr, tolerance = $tolerance:expr, $($arg:tt)+) => {
+        $crate::assert_nearly_eq!(@check $left, $right, $tolerance, format!(": {}", format_args!($($arg)+)))
+    };
+    ($left:expr, $right:expr $(,)?) => {
+        match ($left, $right) {
+            (left, right) => {
+                #[allow(unused_imports)]
+                use $crate::DefaultTolerance as _;
+
+                let tolerance = left.default_tolerance_of();
+                $crate::assert_nearly_eq!(left, right, tolerance = tolerance)
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match ($left, $right) {
+            (left, right) => {
+                #[allow(unused_imports)]
+                use $crate::DefaultTolerance as _;
+
+                let tolerance = left.default_tolerance_of();
+                $crate::assert_nearly_eq!(left, right, tolerance = tolerance, $($arg)+)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn absolute() {
+        assert!(1.0f32.nearly_equals(1.0));
+        // The default comparison is strict, while an explicit tolerance is inclusive
+        assert!(!1.0f32.nearly_equals(1.0 + f32::EPSILON));
+        assert!(1.0f32.nearly_equals_within(1.0 + f32::EPSILON, Tolerance::default()));
+        assert!(!1000.0f32.nearly_equals(1000.0001));
+        assert!(1000.0f32.nearly_equals_within(1000.0001, Tolerance::absolute(1e-3)));
+    }
+
+    #[test]
+    fn relative() {
+        let t = Tolerance::relative(1e-6);
+        assert!(1000.0f32.nearly_equals_within(1000.0001, t));
+        assert!(!1e-6f32.nearly_equals_within(2e-6, t));
+        assert!(1e-6f32.nearly_equals_within(2e-6, Tolerance::new(1e-5, 1e-6)));
+    }
+
+    #[test]
+    fn ulps() {
+        let a = 1.0f32;
+        let b = f32::from_bits(a.to_bits() + 2);
+
+        assert!(a.nearly_equals_ulps(b, 2));
+        assert!(!a.nearly_equals_ulps(b, 1));
+        assert!(0.0f32.nearly_equals_ulps(-0.0, 0));
+        assert!(f32::from_bits(1).nearly_equals_ulps(-f32::from_bits(1), 2));
+        assert!(!f32::NAN.nearly_equals_ulps(f32::NAN, u32::MAX));
+
+        let m = Matrix4::from_2d_array([
+            [3.0, 2.0, 1.0, 1.0],
+            [2.0, 3.0, 2.0, 2.0],
+            [1.0, 2.0, 3.0, 3.0],
+            [0.0, 1.0, 1.0, 0.0],
+        ]);
+        assert!((m.invert().invert()).nearly_equals_ulps(&m, 64));
+    }
+
+    #[test]
+    fn double_precision() {
+        assert!(0.5f64.nearly_equals(0.5 + f64::EPSILON / 2.0));
+        assert!(!1.0f64.nearly_equals(1.0 + f64::EPSILON));
+        assert!(!1.0f64.nearly_equals(1.0 + 1e-12));
+        assert!(1.0f64.nearly_equals_within(1.0 + 1e-12, Tolerance::relative(1e-9)));
+        assert!(1.0f64.nearly_equals_ulps(1.0 + f64::EPSILON, 1));
+        assert!([0.5f64, 0.25].nearly_equals([0.5, 0.25]));
+        assert!(![1.0f64].nearly_equals([1.0 + 1e-12]));
+        assert!(!(1.0f32, 1.0f64).nearly_equals((1.0, 1.0 + 1e-12)));
+        assert_nearly_eq!(&[1.0f64][..], &[1.0 + f64::EPSILON][..]);
+
+        let v = Vector3::new(1.0, 2.0, 3.0) / 3.0;
+        let reference = [1.0 / 3.0, 2.0 / 3.0, 1.0];
+        assert!(nearly_equals_reference(
+            v.as_slice(),
+            &reference,
+            Tolerance::relative(1e-7)
+        ));
+        assert!(!nearly_equals_reference(
+            v.as_slice(),
+            &reference[..2],
+            Tolerance::relative(1e-7)
+        ));
+    }
+
+    #[test]
+    fn minimal_impl() {
+        // Implementing only the required method still supports the other comparisons
+        #[derive(Copy, Clone, Debug)]
+        struct Meters(f32);
+
+        impl NearlyEqual for Meters {
+            fn nearly_equals(self, rhs: Self) -> bool {
+                self.0.nearly_equals(rhs.0)
+            }
+        }
+
+        assert!(Meters(1.0).nearly_equals_within(Meters(1.0), Tolerance::absolute(0.5)));
+        assert!(!Meters(1.0).nearly_equals_ulps(Meters(2.0), 1));
+        assert_nearly_eq!(Meters(1.0), Meters(1.0));
+        assert_nearly_eq!(Some(Meters(1.0)), Some(Meters(1.0)), epsilon = 0.1);
+    }
+
+    #[test]
+    fn collections() {
+        let a = [Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.0)];
+        let b = [Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.5)];
+
+        assert!(a.nearly_equals(a));
+        assert!(!a.nearly_equals(b));
+        assert!(a[..1].nearly_equals(&b[..1]));
+        assert!(!a[..].nearly_equals(&b[..1]));
+        assert!(a.nearly_equals_within(b, Tolerance::absolute(0.5)));
+
+        assert!([1.0f32, 2.0].nearly_equals([1.0, 2.0]));
+        assert!((1.0f32, &Vector2::one()).nearly_equals((1.0, &Vector2::one())));
+        assert!(!(1.0f32, 2.0f32, 3.0f32).nearly_equals((1.0, 2.0, 3.1)));
+    }
+
+    #[test]
+    fn mismatch() {
+        let m = Matrix4::identity();
+        let mut n = m;
+        n.0[2].y = 0.5;
+
+        assert_eq!(m.describe_mismatch(&m, Tolerance::default()), None);
+        assert_eq!(
+            m.describe_mismatch(&n, Tolerance::default()),
+            Some("column 2, y: `0.0` vs `0.5`".to_string())
+        );
+        assert_eq!(
+            [1.0f32, 2.0].describe_mismatch([1.0, 3.0], Tolerance::default()),
+            Some("index 1, `2.0` vs `3.0` (diff 1.0)".to_string())
+        );
+        assert_eq!(
+            (1.0f32, 0.5f64).describe_mismatch((1.0, 0.25), Tolerance::default()),
+            Some("element 1, `0.5` vs `0.25` (diff -0.25)".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "mismatch: column 3, x")]
+    fn assert_reports_component() {
+        let m = Matrix4::translation(Vector3::new(1.0, 0.0, 0.0));
+        assert_nearly_eq!(&m, &Matrix4::identity(), epsilon = 0.5, "translation {}", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn assert_uses_double_precision_tolerance() {
+        assert_nearly_eq!(1.0f64, 1.0 + 1e-12);
+    }
+
+    #[test]
+    fn composite() {
+        let a = Vector3::new(1000.0, -0.0, 1.0);
+        let b = Vector3::new(1000.0001, 1e-7, 1.0);
+
+        assert!(!a.nearly_equals(&b));
+        assert!(a.nearly_equals_within(&b, Tolerance::new(1e-6, 1e-6)));
+        assert!(Some(&a).nearly_equals_within(Some(&b), Tolerance::new(1e-6, 1e-6)));
+    }
 }
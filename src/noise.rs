@@ -0,0 +1,133 @@
+use crate::{Point, Vector3};
+
+/// Gradient (Perlin) noise at a point, in roughly the range `[-1, 1]`, with a lattice spacing
+/// of one unit. Different seeds give unrelated noise fields.
+pub fn gradient_noise(p: Point, seed: u32) -> f32 {
+    gradient_noise_derivative(p, seed).0
+}
+
+/// Gradient (Perlin) noise at a point, along with its analytic derivative.
+/// See [`gradient_noise`].
+pub fn gradient_noise_derivative(p: Point, seed: u32) -> (f32, Vector3) {
+    let cell = [p.x.floor(), p.y.floor(), p.z.floor()];
+    let f = [p.x - cell[0], p.y - cell[1], p.z - cell[2]];
+    let cell = cell.map(|c| c as i32);
+
+    let u = f.map(fade);
+    let du = f.map(|t| 30.0 * t * t * (t - 1.0) * (t - 1.0));
+
+    let mut value = 0.0;
+    let mut derivative = Vector3::zero();
+    for corner in 0..8 {
+        let c = [corner & 1, (corner >> 1) & 1, (corner >> 2) & 1];
+        let g = gradient(hash([cell[0] + c[0], cell[1] + c[1], cell[2] + c[2]], seed));
+        let offset = Vector3::new(f[0] - c[0] as f32, f[1] - c[1] as f32, f[2] - c[2] as f32);
+        let d = g.dot(offset);
+
+        // The trilinear weight of this corner along each axis, and their derivatives
+        let w = [0, 1, 2].map(|i| if c[i] == 1 { u[i] } else { 1.0 - u[i] });
+        let dw = [0, 1, 2].map(|i| if c[i] == 1 { du[i] } else { -du[i] });
+        let weight = w[0] * w[1] * w[2];
+
+        value += weight * d;
+        derivative += g * weight
+            + Vector3::new(
+                dw[0] * w[1] * w[2],
+                w[0] * dw[1] * w[2],
+                w[0] * w[1] * dw[2],
+            ) * d;
+    }
+
+    (value, derivative)
+}
+
+/// A divergence-free velocity field, found as the curl of a vector potential made of three
+/// gradient noise fields. Particles advected through it swirl like smoke without bunching
+/// up or spreading out.
+pub fn curl_noise(p: Point, seed: u32) -> Vector3 {
+    let (_, dx) = gradient_noise_derivative(p, seed);
+    let (_, dy) = gradient_noise_derivative(p, seed.wrapping_add(0x9e37_79b9));
+    let (_, dz) = gradient_noise_derivative(p, seed.wrapping_add(0x3c6e_f372));
+
+    Vector3::new(dz.y - dy.z, dx.z - dz.x, dy.x - dx.y)
+}
+
+/// The quintic fade curve, whose first and second derivatives vanish at 0 and 1
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// A well-mixed hash of a lattice point
+fn hash(cell: [i32; 3], seed: u32) -> u32 {
+    let mut h = seed
+        ^ (cell[0] as u32).wrapping_mul(0x8da6_b343)
+        ^ (cell[1] as u32).wrapping_mul(0xd816_3841)
+        ^ (cell[2] as u32).wrapping_mul(0xcb1a_b31f);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2c1b_3c6d);
+    h ^= h >> 12;
+    h = h.wrapping_mul(0x297a_2d39);
+    h ^ (h >> 15)
+}
+
+/// One of the twelve directions to the edges of a cube, as in improved Perlin noise
+fn gradient(hash: u32) -> Vector3 {
+    match hash % 12 {
+        0 => Vector3::new(1.0, 1.0, 0.0),
+        1 => Vector3::new(-1.0, 1.0, 0.0),
+        2 => Vector3::new(1.0, -1.0, 0.0),
+        3 => Vector3::new(-1.0, -1.0, 0.0),
+        4 => Vector3::new(1.0, 0.0, 1.0),
+        5 => Vector3::new(-1.0, 0.0, 1.0),
+        6 => Vector3::new(1.0, 0.0, -1.0),
+        7 => Vector3::new(-1.0, 0.0, -1.0),
+        8 => Vector3::new(0.0, 1.0, 1.0),
+        9 => Vector3::new(0.0, -1.0, 1.0),
+        10 => Vector3::new(0.0, 1.0, -1.0),
+        _ => Vector3::new(0.0, -1.0, -1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn noise() {
+        // Gradient noise vanishes on the lattice
+        assert_eq!(gradient_noise(Point::new(3.0, -2.0, 7.0), 1), 0.0);
+
+        let h = 1e-2;
+        for i in 0..20 {
+            let p = Point::new(i as f32 * 0.37, i as f32 * -0.21 + 0.5, i as f32 * 0.13);
+            let (value, derivative) = gradient_noise_derivative(p, 7);
+            assert!(value.abs() <= 1.5);
+
+            // The analytic derivative matches a central difference
+            let difference = |axis: Vector3| {
+                (gradient_noise(p + axis * h, 7) - gradient_noise(p - axis * h, 7)) / (2.0 * h)
+            };
+            let numeric = Vector3::new(
+                difference(Vector3::UNIT_X),
+                difference(Vector3::UNIT_Y),
+                difference(Vector3::UNIT_Z),
+            );
+            assert_nearly_eq!(&derivative, &numeric, epsilon = 1e-2);
+
+            // The curl field has no divergence
+            let divergence = (curl_noise(p + Vector3::UNIT_X * h, 3).x
+                - curl_noise(p - Vector3::UNIT_X * h, 3).x
+                + curl_noise(p + Vector3::UNIT_Y * h, 3).y
+                - curl_noise(p - Vector3::UNIT_Y * h, 3).y
+                + curl_noise(p + Vector3::UNIT_Z * h, 3).z
+                - curl_noise(p - Vector3::UNIT_Z * h, 3).z)
+                / (2.0 * h);
+            assert!(divergence.abs() < 1e-2, "divergence {divergence}");
+        }
+
+        assert_ne!(
+            gradient_noise(Point::new(0.5, 0.5, 0.5), 1),
+            gradient_noise(Point::new(0.5, 0.5, 0.5), 2)
+        );
+    }
+}
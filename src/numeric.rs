@@ -0,0 +1,95 @@
+//! Integration with [`num_traits`], so that vectors and matrices can be used in generic
+//! numeric code.
+
+use crate::vector::count_fields;
+use crate::{Matrix4, Vector2, Vector3, Vector3A, Vector4};
+use num_traits::{Float, One, Zero};
+
+macro_rules! implement_numeric {
+    ($($VectorT:ident { $($field:ident),+ }),+) => {
+        $(
+            impl Zero for $VectorT {
+                fn zero() -> Self {
+                    $VectorT::zero()
+                }
+
+                fn is_zero(&self) -> bool {
+                    $(self.$field == 0.0)&&+
+                }
+            }
+
+            /// The component-wise multiplicative identity
+            impl One for $VectorT {
+                fn one() -> Self {
+                    $VectorT::one()
+                }
+            }
+
+            impl $VectorT {
+                /// Construct a vector from coordinates of any floating-point type, rounding
+                /// them to the nearest `f32`
+                pub fn from_float_array<T: Float>(a: [T; count_fields!($($field)+)]) -> Self {
+                    let [$($field),+] = a;
+                    Self::new($($field.to_f32().unwrap_or(f32::NAN)),+)
+                }
+
+                /// The coordinates of this vector, converted to any floating-point type
+                pub fn to_float_array<T: Float>(&self) -> [T; count_fields!($($field)+)] {
+                    [$(T::from(self.$field).unwrap_or_else(T::nan)),+]
+                }
+            }
+        )+
+    };
+}
+
+implement_numeric!(
+    Vector2 { x, y },
+    Vector3 { x, y, z },
+    Vector3A { x, y, z },
+    Vector4 { x, y, z, w }
+);
+
+impl Zero for Matrix4 {
+    fn zero() -> Self {
+        Matrix4::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.as_slice().iter().all(|x| *x == 0.0)
+    }
+}
+
+/// The identity matrix
+impl One for Matrix4 {
+    fn one() -> Self {
+        Matrix4::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use num_traits::{One, Zero};
+
+    fn power<T: One + Copy>(base: T, n: u32) -> T {
+        (0..n).fold(T::one(), |a, _| a * base)
+    }
+
+    #[test]
+    fn numeric() {
+        assert!(<Vector3 as Zero>::zero().is_zero());
+        assert!(!Vector2::new(0.0, 1.0).is_zero());
+        assert_eq!(power(Vector2::new(2.0, 3.0), 2), Vector2::new(4.0, 9.0));
+
+        let m = Matrix4::translation(Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            power(m, 3),
+            Matrix4::translation(Vector3::new(3.0, 0.0, 0.0))
+        );
+        assert!(<Matrix4 as Zero>::zero().is_zero());
+
+        let v = Vector3::from_float_array([1.0f64, 0.1, -2.0]);
+        assert_eq!(v, Vector3::new(1.0, 0.1, -2.0));
+        assert_eq!(v.to_float_array::<f64>(), [1.0, 0.1f32 as f64, -2.0]);
+    }
+}
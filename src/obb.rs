@@ -0,0 +1,88 @@
+use crate::{Aabb, Point, PrincipalComponents, Vector3};
+
+/// An oriented bounding box in 3D space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb {
+    pub center: Point,
+    /// The orthonormal axes of the box.
+    pub axes: [Vector3; 3],
+    /// Half the size of the box along each of its axes.
+    pub half_extents: Vector3,
+}
+
+impl Obb {
+    /// Construct a box from its center, orthonormal axes, and half its size along each axis
+    pub const fn new(center: Point, axes: [Vector3; 3], half_extents: Vector3) -> Self {
+        Self {
+            center,
+            axes,
+            half_extents,
+        }
+    }
+
+    /// The eight corners of this box
+    pub fn corners(&self) -> [Point; 8] {
+        let [x, y, z] = [0, 1, 2].map(|i| self.axes[i] * self.half_extents[i]);
+        std::array::from_fn(|i| {
+            let sign = |bit: usize| if i & bit == 0 { -1.0 } else { 1.0 };
+            self.center + x * sign(1) + y * sign(2) + z * sign(4)
+        })
+    }
+
+    /// The interval covered by this box when projected onto an axis, relative to the origin
+    pub fn project(&self, axis: Vector3) -> (f32, f32) {
+        let center = Vector3::from(self.center).dot(axis);
+        let radius = (0..3)
+            .map(|i| self.half_extents[i] * self.axes[i].dot(axis).abs())
+            .sum::<f32>();
+        (center - radius, center + radius)
+    }
+}
+
+impl From<Aabb> for Obb {
+    fn from(aabb: Aabb) -> Self {
+        Obb::new(
+            aabb.center(),
+            [Vector3::UNIT_X, Vector3::UNIT_Y, Vector3::UNIT_Z],
+            aabb.half_extents(),
+        )
+    }
+}
+
+impl From<PrincipalComponents> for Obb {
+    fn from(pca: PrincipalComponents) -> Self {
+        Obb::new(pca.center, pca.axes, pca.half_extents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn corners() {
+        let axes = [
+            Vector3::new(1.0, 1.0, 0.0).normalized(),
+            Vector3::new(-1.0, 1.0, 0.0).normalized(),
+            Vector3::UNIT_Z,
+        ];
+        let obb = Obb::new(Point::new(1.0, 0.0, 0.0), axes, Vector3::new(2.0, 1.0, 0.5));
+
+        let corners = obb.corners();
+        assert_eq!(corners.len(), 8);
+        for c in corners {
+            let local = c - obb.center;
+            for i in 0..3 {
+                assert_nearly_eq!(
+                    local.dot(obb.axes[i]).abs(),
+                    obb.half_extents[i],
+                    epsilon = 1e-5
+                );
+            }
+        }
+
+        let (lo, hi) = obb.project(Vector3::UNIT_Z);
+        assert_nearly_eq!(lo, -0.5, epsilon = 1e-6);
+        assert_nearly_eq!(hi, 0.5, epsilon = 1e-6);
+    }
+}
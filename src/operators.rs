@@ -1,29 +1,38 @@
-use crate::{Matrix4, Point, Vector2, Vector3, Vector4};
+use crate::validate::validate;
+use crate::{Matrix2, Matrix3, Matrix4, Point, Quaternion, Vector2, Vector3, Vector3A, Vector4};
 
-impl std::ops::Mul<&Matrix4> for Matrix4 {
+impl std::ops::Mul for Matrix4 {
     type Output = Self;
 
-    fn mul(self, rhs: &Matrix4) -> Self {
-        self * *rhs
+    fn mul(self, rhs: Self) -> Self {
+        let result = crate::simd::mul_matrix4(&self, &rhs);
+        validate!(result.is_finite(), "Matrix4 has a non-finite element");
+        result
     }
 }
 
-impl std::ops::Mul for Matrix4 {
+/// Component-wise addition
+impl std::ops::Add for Matrix4 {
     type Output = Self;
 
-    fn mul(self, rhs: Self) -> Self {
-        let mut result = Self::zero();
-
-        for i in 0..4 {
-            for j in 0..4 {
-                result.0[i][j] = rhs.0[i][0] * self.0[0][j]
-                    + rhs.0[i][1] * self.0[1][j]
-                    + rhs.0[i][2] * self.0[2][j]
-                    + rhs.0[i][3] * self.0[3][j];
-            }
-        }
+    fn add(self, rhs: Self) -> Self {
+        Matrix4([0, 1, 2, 3].map(|i| self.0[i] + rhs.0[i]))
+    }
+}
 
-        result
+/// Component-wise subtraction
+impl std::ops::Sub for Matrix4 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Matrix4([0, 1, 2, 3].map(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl std::ops::MulAssign for Matrix4 {
+    /// Note that `a *= b` computes `a * b`, so `b` is applied before the existing transform.
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
     }
 }
 
@@ -55,48 +64,55 @@ impl std::ops::Mul<Vector4> for Matrix4 {
     type Output = Vector4;
 
     fn mul(self, rhs: Vector4) -> Vector4 {
-        Vector4::new(
-            self.row(0).dot(rhs),
-            self.row(1).dot(rhs),
-            self.row(2).dot(rhs),
-            self.row(3).dot(rhs),
-        )
+        crate::simd::transform_vector4(&self, rhs)
     }
 }
 
+/// Multiplication with a row vector on the left, which treats the matrix as storing its
+/// transform in the row-vector convention (translation in the bottom row).
+///
+/// Note that `p * m` is equivalent to `m.transpose() * p`.
 impl std::ops::Mul<Matrix4> for Point {
     type Output = Point;
 
     fn mul(self, rhs: Matrix4) -> Point {
+        let v = Vector4::from(self);
         Point::new(
-            Vector3::from_scalar(self.x).dot(Vector3::from(rhs.column(0))),
-            Vector3::from_scalar(self.y).dot(Vector3::from(rhs.column(1))),
-            Vector3::from_scalar(self.z).dot(Vector3::from(rhs.column(2))),
+            rhs.column(0).dot(v),
+            rhs.column(1).dot(v),
+            rhs.column(2).dot(v),
         )
     }
 }
 
+/// Multiplication with a row vector on the left, which ignores the translation row.
+///
+/// Note that `v * m` is equivalent to `m.transpose() * v`.
 impl std::ops::Mul<Matrix4> for Vector3 {
     type Output = Vector3;
 
     fn mul(self, rhs: Matrix4) -> Vector3 {
+        let v = Vector4::from(self);
         Vector3::new(
-            Vector3::from_scalar(self.x).dot(Vector3::from(rhs.column(0))),
-            Vector3::from_scalar(self.y).dot(Vector3::from(rhs.column(1))),
-            Vector3::from_scalar(self.z).dot(Vector3::from(rhs.column(2))),
+            rhs.column(0).dot(v),
+            rhs.column(1).dot(v),
+            rhs.column(2).dot(v),
         )
     }
 }
 
+/// Multiplication with a row vector on the left.
+///
+/// Note that `v * m` is equivalent to `m.transpose() * v`.
 impl std::ops::Mul<Matrix4> for Vector4 {
     type Output = Vector4;
 
     fn mul(self, rhs: Matrix4) -> Vector4 {
         Vector4::new(
-            Vector4::from_scalar(self.x).dot(rhs.column(0)),
-            Vector4::from_scalar(self.y).dot(rhs.column(1)),
-            Vector4::from_scalar(self.z).dot(rhs.column(2)),
-            Vector4::from_scalar(self.w).dot(rhs.column(3)),
+            rhs.column(0).dot(self),
+            rhs.column(1).dot(self),
+            rhs.column(2).dot(self),
+            rhs.column(3).dot(self),
         )
     }
 }
@@ -121,7 +137,11 @@ macro_rules! vector_assign_op {
     }) => {
         impl std::ops::$trait<$other_type> for $type {
             fn $op_fn(&mut self, rhs: $other_type) {
-                $(self.$field $op rhs.$field);+
+                $(self.$field $op rhs.$field;)+
+                validate!(
+                    self.is_finite(),
+                    concat!(stringify!($type), " has a non-finite coordinate")
+                );
             }
         }
     };
@@ -131,31 +151,347 @@ vector_op!(impl Add<Vector2> for Vector2 { fn add -> Vector2, + {x, y} });
 vector_op!(impl Sub<Vector2> for Vector2 { fn sub -> Vector2, - {x, y} });
 vector_op!(impl Mul<Vector2> for Vector2 { fn mul -> Vector2, * {x, y} });
 vector_op!(impl Div<Vector2> for Vector2 { fn div -> Vector2, / {x, y} });
+vector_op!(impl Rem<Vector2> for Vector2 { fn rem -> Vector2, % {x, y} });
 vector_assign_op!(impl AddAssign<Vector2> for Vector2 { fn add_assign, += {x, y} });
 vector_assign_op!(impl SubAssign<Vector2> for Vector2 { fn sub_assign, -= {x, y} });
 vector_assign_op!(impl MulAssign<Vector2> for Vector2 { fn mul_assign, *= {x, y} });
 vector_assign_op!(impl DivAssign<Vector2> for Vector2 { fn div_assign, /= {x, y} });
+vector_assign_op!(impl RemAssign<Vector2> for Vector2 { fn rem_assign, %= {x, y} });
 
 vector_op!(impl Add<Vector3> for Vector3 { fn add -> Vector3, + {x, y, z} });
 vector_op!(impl Sub<Vector3> for Vector3 { fn sub -> Vector3, - {x, y, z} });
 vector_op!(impl Mul<Vector3> for Vector3 { fn mul -> Vector3, * {x, y, z} });
 vector_op!(impl Div<Vector3> for Vector3 { fn div -> Vector3, / {x, y, z} });
+vector_op!(impl Rem<Vector3> for Vector3 { fn rem -> Vector3, % {x, y, z} });
 vector_assign_op!(impl AddAssign<Vector3> for Vector3 { fn add_assign, += {x, y, z} });
 vector_assign_op!(impl SubAssign<Vector3> for Vector3 { fn sub_assign, -= {x, y, z} });
 vector_assign_op!(impl MulAssign<Vector3> for Vector3 { fn mul_assign, *= {x, y, z} });
 vector_assign_op!(impl DivAssign<Vector3> for Vector3 { fn div_assign, /= {x, y, z} });
+vector_assign_op!(impl RemAssign<Vector3> for Vector3 { fn rem_assign, %= {x, y, z} });
+
+vector_op!(impl Add<Vector3A> for Vector3A { fn add -> Vector3A, + {x, y, z} });
+vector_op!(impl Sub<Vector3A> for Vector3A { fn sub -> Vector3A, - {x, y, z} });
+vector_op!(impl Mul<Vector3A> for Vector3A { fn mul -> Vector3A, * {x, y, z} });
+vector_op!(impl Div<Vector3A> for Vector3A { fn div -> Vector3A, / {x, y, z} });
+vector_op!(impl Rem<Vector3A> for Vector3A { fn rem -> Vector3A, % {x, y, z} });
+vector_assign_op!(impl AddAssign<Vector3A> for Vector3A { fn add_assign, += {x, y, z} });
+vector_assign_op!(impl SubAssign<Vector3A> for Vector3A { fn sub_assign, -= {x, y, z} });
+vector_assign_op!(impl MulAssign<Vector3A> for Vector3A { fn mul_assign, *= {x, y, z} });
+vector_assign_op!(impl DivAssign<Vector3A> for Vector3A { fn div_assign, /= {x, y, z} });
+vector_assign_op!(impl RemAssign<Vector3A> for Vector3A { fn rem_assign, %= {x, y, z} });
 
 vector_op!(impl Add<Vector4> for Vector4 { fn add -> Vector4, + {x, y, z, w} });
 vector_op!(impl Sub<Vector4> for Vector4 { fn sub -> Vector4, - {x, y, z, w} });
 vector_op!(impl Mul<Vector4> for Vector4 { fn mul -> Vector4, * {x, y, z, w} });
 vector_op!(impl Div<Vector4> for Vector4 { fn div -> Vector4, / {x, y, z, w} });
+vector_op!(impl Rem<Vector4> for Vector4 { fn rem -> Vector4, % {x, y, z, w} });
 vector_assign_op!(impl AddAssign<Vector4> for Vector4 { fn add_assign, += {x, y, z, w} });
 vector_assign_op!(impl SubAssign<Vector4> for Vector4 { fn sub_assign, -= {x, y, z, w} });
 vector_assign_op!(impl MulAssign<Vector4> for Vector4 { fn mul_assign, *= {x, y, z, w} });
 vector_assign_op!(impl DivAssign<Vector4> for Vector4 { fn div_assign, /= {x, y, z, w} });
+vector_assign_op!(impl RemAssign<Vector4> for Vector4 { fn rem_assign, %= {x, y, z, w} });
 
 vector_op!(impl Add<Vector3> for Point { fn add -> Point, + {x, y, z} });
+vector_op!(impl Add<Point> for Vector3 { fn add -> Point, + {x, y, z} });
 vector_op!(impl Sub<Vector3> for Point { fn sub -> Point, - {x, y, z} });
 vector_op!(impl Sub<Point> for Point { fn sub -> Vector3, - {x, y, z} });
 vector_assign_op!(impl AddAssign<Vector3> for Point { fn add_assign, += {x, y, z} });
 vector_assign_op!(impl SubAssign<Vector3> for Point { fn sub_assign, -= {x, y, z} });
+
+macro_rules! vector_sum {
+    ($($type:ty),+) => {
+        $(
+            impl std::iter::Sum for $type {
+                fn sum<I: Iterator<Item = $type>>(iter: I) -> $type {
+                    iter.fold(<$type>::zero(), |a, b| a + b)
+                }
+            }
+
+            impl<'a> std::iter::Sum<&'a $type> for $type {
+                fn sum<I: Iterator<Item = &'a $type>>(iter: I) -> $type {
+                    iter.fold(<$type>::zero(), |a, b| a + *b)
+                }
+            }
+        )+
+    };
+}
+
+// Points are deliberately excluded, as the sum of positions has no geometric meaning
+vector_sum!(Vector2, Vector3, Vector3A, Vector4);
+
+/// Implement an operator for every combination of references, in terms of the implementation by value
+macro_rules! forward_ref_op {
+    ($(impl $trait:ident<$other_type:ty> for $type:ty { fn $op_fn:ident -> $result_type:ty })+) => {
+        $(
+            impl std::ops::$trait<&$other_type> for $type {
+                type Output = $result_type;
+
+                fn $op_fn(self, rhs: &$other_type) -> $result_type {
+                    std::ops::$trait::$op_fn(self, *rhs)
+                }
+            }
+
+            impl std::ops::$trait<$other_type> for &$type {
+                type Output = $result_type;
+
+                fn $op_fn(self, rhs: $other_type) -> $result_type {
+                    std::ops::$trait::$op_fn(*self, rhs)
+                }
+            }
+
+            impl std::ops::$trait<&$other_type> for &$type {
+                type Output = $result_type;
+
+                fn $op_fn(self, rhs: &$other_type) -> $result_type {
+                    std::ops::$trait::$op_fn(*self, *rhs)
+                }
+            }
+        )+
+    };
+}
+
+/// Implement an assignment operator taking a reference, in terms of the implementation by value
+macro_rules! forward_ref_assign_op {
+    ($(impl $trait:ident<$other_type:ty> for $type:ty { fn $op_fn:ident })+) => {
+        $(
+            impl std::ops::$trait<&$other_type> for $type {
+                fn $op_fn(&mut self, rhs: &$other_type) {
+                    std::ops::$trait::$op_fn(self, *rhs)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! forward_ref_vector_ops {
+    ($($type:ty),+) => {
+        $(
+            impl std::ops::Neg for &$type {
+                type Output = $type;
+
+                fn neg(self) -> $type {
+                    -*self
+                }
+            }
+
+            forward_ref_op! {
+                impl Add<$type> for $type { fn add -> $type }
+                impl Sub<$type> for $type { fn sub -> $type }
+                impl Mul<$type> for $type { fn mul -> $type }
+                impl Div<$type> for $type { fn div -> $type }
+                impl Rem<$type> for $type { fn rem -> $type }
+            }
+            forward_ref_assign_op! {
+                impl AddAssign<$type> for $type { fn add_assign }
+                impl SubAssign<$type> for $type { fn sub_assign }
+                impl MulAssign<$type> for $type { fn mul_assign }
+                impl DivAssign<$type> for $type { fn div_assign }
+                impl RemAssign<$type> for $type { fn rem_assign }
+            }
+        )+
+    };
+}
+
+macro_rules! forward_ref_scalar_ops {
+    ($($type:ty),+) => {
+        $(
+            forward_ref_op! {
+                impl Add<f32> for $type { fn add -> $type }
+                impl Sub<f32> for $type { fn sub -> $type }
+                impl Mul<f32> for $type { fn mul -> $type }
+                impl Div<f32> for $type { fn div -> $type }
+                impl Rem<f32> for $type { fn rem -> $type }
+                impl Add<$type> for f32 { fn add -> $type }
+                impl Sub<$type> for f32 { fn sub -> $type }
+                impl Mul<$type> for f32 { fn mul -> $type }
+                impl Div<$type> for f32 { fn div -> $type }
+            }
+            forward_ref_assign_op! {
+                impl AddAssign<f32> for $type { fn add_assign }
+                impl SubAssign<f32> for $type { fn sub_assign }
+                impl MulAssign<f32> for $type { fn mul_assign }
+                impl DivAssign<f32> for $type { fn div_assign }
+                impl RemAssign<f32> for $type { fn rem_assign }
+            }
+        )+
+    };
+}
+
+forward_ref_vector_ops!(Vector2, Vector3, Vector3A, Vector4);
+forward_ref_scalar_ops!(Vector2, Vector3, Vector3A, Point, Vector4);
+
+impl std::ops::Neg for &Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        -*self
+    }
+}
+
+impl std::ops::Neg for &Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        -*self
+    }
+}
+
+forward_ref_op! {
+    impl Add<Vector3> for Point { fn add -> Point }
+    impl Add<Point> for Vector3 { fn add -> Point }
+    impl Sub<Vector3> for Point { fn sub -> Point }
+    impl Sub<Point> for Point { fn sub -> Vector3 }
+
+    impl Mul<Matrix4> for Matrix4 { fn mul -> Matrix4 }
+    impl Add<Matrix4> for Matrix4 { fn add -> Matrix4 }
+    impl Sub<Matrix4> for Matrix4 { fn sub -> Matrix4 }
+    impl Mul<Point> for Matrix4 { fn mul -> Point }
+    impl Mul<Vector3> for Matrix4 { fn mul -> Vector3 }
+    impl Mul<Vector4> for Matrix4 { fn mul -> Vector4 }
+    impl Mul<Matrix4> for Point { fn mul -> Point }
+    impl Mul<Matrix4> for Vector3 { fn mul -> Vector3 }
+    impl Mul<Matrix4> for Vector4 { fn mul -> Vector4 }
+
+    impl Mul<Matrix3> for Matrix3 { fn mul -> Matrix3 }
+    impl Mul<Vector3> for Matrix3 { fn mul -> Vector3 }
+    impl Mul<Vector2> for Matrix3 { fn mul -> Vector2 }
+
+    impl Mul<Matrix2> for Matrix2 { fn mul -> Matrix2 }
+    impl Mul<Vector2> for Matrix2 { fn mul -> Vector2 }
+
+    impl Mul<Quaternion> for Quaternion { fn mul -> Quaternion }
+    impl Mul<Vector3> for Quaternion { fn mul -> Vector3 }
+    impl Mul<Point> for Quaternion { fn mul -> Point }
+    impl Mul<f32> for Quaternion { fn mul -> Quaternion }
+    impl Add<Quaternion> for Quaternion { fn add -> Quaternion }
+}
+
+forward_ref_assign_op! {
+    impl MulAssign<Matrix4> for Matrix4 { fn mul_assign }
+    impl MulAssign<Quaternion> for Quaternion { fn mul_assign }
+    impl AddAssign<Vector3> for Point { fn add_assign }
+    impl SubAssign<Vector3> for Point { fn sub_assign }
+}
+
+/// Sums matrices component-wise.
+impl std::iter::Sum for Matrix4 {
+    fn sum<I: Iterator<Item = Matrix4>>(iter: I) -> Matrix4 {
+        iter.fold(Matrix4::zero(), |a, b| a + b)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Matrix4> for Matrix4 {
+    fn sum<I: Iterator<Item = &'a Matrix4>>(iter: I) -> Matrix4 {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies matrices in iteration order, so that `[a, b, c]` yields `a * b * c`.
+///
+/// Note that this means the last transform in the sequence is applied first.
+impl std::iter::Product for Matrix4 {
+    fn product<I: Iterator<Item = Matrix4>>(iter: I) -> Matrix4 {
+        iter.fold(Matrix4::identity(), |a, b| a * b)
+    }
+}
+
+impl<'a> std::iter::Product<&'a Matrix4> for Matrix4 {
+    fn product<I: Iterator<Item = &'a Matrix4>>(iter: I) -> Matrix4 {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn affine() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        let v = Vector3::new(0.5, 0.5, 0.5);
+
+        assert_eq!(v + p, p + v);
+        assert_eq!((p + v) - p, v);
+        assert_eq!(p.lerp(p + v * 2.0, 0.5), p + v);
+
+        let displacements = [v, v, -v];
+        assert_eq!(displacements.iter().sum::<Vector3>(), v);
+        assert_eq!(p + displacements.into_iter().sum::<Vector3>(), p + v);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn references() {
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0));
+        let points = [Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)];
+        let v = Vector3::new(1.0, 2.0, 3.0);
+
+        let transformed: Vec<Point> = points.iter().map(|p| &m * p).collect();
+        assert_eq!(transformed, vec![points[0] + v, points[1] + v]);
+
+        assert_eq!(&v + &v, v * 2.0);
+        assert_eq!(&v * 2.0, 2.0 * &v);
+        assert_eq!(-&v, v * -1.0);
+        assert_eq!(&points[1] - points[0], points[1] - points[0]);
+        assert_eq!(&m * &m, m * m);
+        assert_eq!(&m + &m - m, m + m - m);
+
+        let r = Matrix2::rotation(0.5);
+        assert_eq!(&r * r, r * r);
+        assert_eq!(r * &Vector2::one(), r * Vector2::one());
+
+        let q = Quaternion::from_axis_angle(Vector3::UP, 0.5);
+        assert_eq!(&q * &q, q * q);
+        assert_eq!(&q * v, q * v);
+        assert_eq!(q * &points[0], q * points[0]);
+        assert_eq!(&q * 2.0 + &q, q * 2.0 + q);
+        assert_eq!(-&q, -q);
+        let mut composed = q;
+        composed *= &q;
+        assert_eq!(composed, q * q);
+
+        let mut p = points[0];
+        p += &v;
+        assert_eq!(p, points[0] + v);
+    }
+
+    #[test]
+    fn matrix_sum_and_product() {
+        let transforms = [
+            Matrix4::translation(Vector3::new(1.0, 0.0, 0.0)),
+            Matrix4::rotation_z(0.5),
+            Matrix4::uniform_scale(2.0),
+        ];
+
+        assert_eq!(
+            transforms.iter().product::<Matrix4>(),
+            transforms[0] * transforms[1] * transforms[2]
+        );
+        assert_eq!(
+            std::iter::empty::<Matrix4>().product::<Matrix4>(),
+            Matrix4::identity()
+        );
+
+        let sum: Matrix4 = [Matrix4::identity(), Matrix4::identity()].into_iter().sum();
+        assert_eq!(
+            sum.as_array(),
+            &Matrix4::identity().as_array().map(|x| x * 2.0)
+        );
+    }
+
+    #[test]
+    fn row_vector() {
+        let m = Matrix4::translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::rotation_z(0.5);
+        let row_major = m.transpose();
+        let p = Point::new(3.0, -1.0, 2.0);
+        let v = Vector3::new(3.0, -1.0, 2.0);
+
+        assert_nearly_eq!(&(p * row_major), &(m * p), epsilon = 1e-6);
+        assert_nearly_eq!(&(v * row_major), &(m * v), epsilon = 1e-6);
+        assert_nearly_eq!(
+            &(Vector4::new(3.0, -1.0, 2.0, 1.0) * row_major),
+            &(m * Vector4::new(3.0, -1.0, 2.0, 1.0)),
+            epsilon = 1e-6
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{Point, Vector2, Vector3, Vector4};
+
+/// Pack a value in the range `[0, 1]` into an 8-bit unsigned normalized integer
+pub fn pack_unorm8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8
+}
+
+/// Pack a value in the range `[-1, 1]` into an 8-bit signed normalized integer
+pub fn pack_snorm8(value: f32) -> i8 {
+    (value.clamp(-1.0, 1.0) * i8::MAX as f32).round() as i8
+}
+
+/// Pack a value in the range `[0, 1]` into a 16-bit unsigned normalized integer
+pub fn pack_unorm16(value: f32) -> u16 {
+    (value.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16
+}
+
+/// Pack a value in the range `[-1, 1]` into a 16-bit signed normalized integer
+pub fn pack_snorm16(value: f32) -> i16 {
+    (value.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16
+}
+
+/// The bits of the nearest half-precision float to a value. Values too large to represent
+/// become infinite.
+pub fn pack_half(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        // Infinity, or NaN with a mantissa bit kept set so it stays NaN
+        return sign | 0x7c00 | if mantissa != 0 { 0x200 } else { 0 };
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Round to nearest, ties to even. A carry out of the mantissa correctly bumps the exponent.
+    let round = |value: u32, shift: u32| {
+        let truncated = value >> shift;
+        let remainder = value & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        if remainder > halfway || (remainder == halfway && truncated & 1 == 1) {
+            truncated + 1
+        } else {
+            truncated
+        }
+    };
+
+    if exponent <= 0 {
+        // Too small for a normal half, so produce a subnormal (or zero)
+        if exponent < -10 {
+            return sign;
+        }
+        let shift = (14 - exponent) as u32;
+        return sign | round(mantissa | 0x80_0000, shift) as u16;
+    }
+
+    sign | round(((exponent as u32) << 23) | mantissa, 13) as u16
+}
+
+/// The value of a half-precision float, given its bits
+pub fn unpack_half(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1f) as u32;
+    let mantissa = (bits & 0x3ff) as u32;
+
+    match exponent {
+        0 => {
+            let magnitude = mantissa as f32 * f32::powi(2.0, -24);
+            f32::from_bits(sign | magnitude.to_bits())
+        }
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (mantissa << 13)),
+        _ => f32::from_bits(sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)),
+    }
+}
+
+/// Map a unit-length vector onto the square `[-1, 1]²` by projecting it onto an octahedron
+/// and unfolding the lower half. This stores normals in two components with nearly uniform
+/// precision over the sphere.
+pub fn encode_octahedral(v: Vector3) -> Vector2 {
+    let sign = |x: f32| if x >= 0.0 { 1.0 } else { -1.0 };
+
+    let p = Vector2::new(v.x, v.y) / (v.x.abs() + v.y.abs() + v.z.abs());
+    if v.z < 0.0 {
+        Vector2::new((1.0 - p.y.abs()) * sign(p.x), (1.0 - p.x.abs()) * sign(p.y))
+    } else {
+        p
+    }
+}
+
+/// The unit-length vector encoded by [`encode_octahedral`]
+pub fn decode_octahedral(p: Vector2) -> Vector3 {
+    let z = 1.0 - p.x.abs() - p.y.abs();
+    // Folding the lower half back moves each component towards zero by the same amount
+    let t = (-z).max(0.0);
+    let x = if p.x >= 0.0 { p.x - t } else { p.x + t };
+    let y = if p.y >= 0.0 { p.y - t } else { p.y + t };
+    Vector3::new(x, y, z).normalized()
+}
+
+/// The source data for one attribute of a vertex
+#[derive(Copy, Clone, Debug)]
+pub enum AttributeData<'a> {
+    Vector2(&'a [Vector2]),
+    Vector3(&'a [Vector3]),
+    Point(&'a [Point]),
+    /// Also used for colors, in RGBA order
+    Vector4(&'a [Vector4]),
+}
+
+impl AttributeData<'_> {
+    /// The number of vertices
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Vector2(data) => data.len(),
+            Self::Vector3(data) => data.len(),
+            Self::Point(data) => data.len(),
+            Self::Vector4(data) => data.len(),
+        }
+    }
+
+    /// Whether there are no vertices
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of components in each vertex
+    pub fn components(&self) -> usize {
+        match self {
+            Self::Vector2(_) => 2,
+            Self::Vector3(_) | Self::Point(_) => 3,
+            Self::Vector4(_) => 4,
+        }
+    }
+
+    fn get(&self, index: usize) -> &[f32] {
+        match self {
+            Self::Vector2(data) => data[index].as_slice(),
+            Self::Vector3(data) => data[index].as_slice(),
+            Self::Point(data) => data[index].as_slice(),
+            Self::Vector4(data) => data[index].as_slice(),
+        }
+    }
+}
+
+/// How each component of an attribute is stored in a vertex buffer
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AttributeFormat {
+    Float32,
+    Float16,
+    Unorm8,
+    Snorm8,
+    Unorm16,
+    Snorm16,
+    /// A unit-length [`AttributeData::Vector3`], such as a normal, stored as two 16-bit signed
+    /// normalized components using [`encode_octahedral`]
+    Octahedral16,
+}
+
+/// One attribute of an interleaved vertex layout
+#[derive(Copy, Clone, Debug)]
+pub struct VertexAttribute<'a> {
+    pub data: AttributeData<'a>,
+    pub format: AttributeFormat,
+}
+
+impl<'a> VertexAttribute<'a> {
+    /// Construct an attribute from its data and format
+    pub const fn new(data: AttributeData<'a>, format: AttributeFormat) -> Self {
+        Self { data, format }
+    }
+
+    /// The size in bytes of this attribute within each vertex, padded to a multiple of four
+    /// bytes as graphics APIs require
+    pub fn size(&self) -> usize {
+        let size = match self.format {
+            AttributeFormat::Float32 => 4 * self.data.components(),
+            AttributeFormat::Float16 | AttributeFormat::Unorm16 | AttributeFormat::Snorm16 => {
+                2 * self.data.components()
+            }
+            AttributeFormat::Unorm8 | AttributeFormat::Snorm8 => self.data.components(),
+            AttributeFormat::Octahedral16 => 4,
+        };
+        size.next_multiple_of(4)
+    }
+
+    fn write(&self, index: usize, out: &mut Vec<u8>) {
+        let start = out.len();
+        let values = self.data.get(index);
+
+        match self.format {
+            AttributeFormat::Float32 => {
+                values.iter().for_each(|v| out.extend(v.to_le_bytes()));
+            }
+            AttributeFormat::Float16 => {
+                values
+                    .iter()
+                    .for_each(|v| out.extend(pack_half(*v).to_le_bytes()));
+            }
+            AttributeFormat::Unorm8 => out.extend(values.iter().map(|v| pack_unorm8(*v))),
+            AttributeFormat::Snorm8 => out.extend(values.iter().map(|v| pack_snorm8(*v) as u8)),
+            AttributeFormat::Unorm16 => {
+                values
+                    .iter()
+                    .for_each(|v| out.extend(pack_unorm16(*v).to_le_bytes()));
+            }
+            AttributeFormat::Snorm16 => {
+                values
+                    .iter()
+                    .for_each(|v| out.extend(pack_snorm16(*v).to_le_bytes()));
+            }
+            AttributeFormat::Octahedral16 => {
+                assert_eq!(values.len(), 3, "octahedral encoding requires a 3D vector");
+                let p = encode_octahedral(Vector3::new(values[0], values[1], values[2]));
+                out.extend(pack_snorm16(p.x).to_le_bytes());
+                out.extend(pack_snorm16(p.y).to_le_bytes());
+            }
+        }
+
+        out.resize(start + self.size(), 0);
+    }
+}
+
+/// The size in bytes of each vertex with the given attributes
+pub fn vertex_stride(attributes: &[VertexAttribute]) -> usize {
+    attributes.iter().map(VertexAttribute::size).sum()
+}
+
+/// Pack the given attributes into an interleaved vertex buffer, in little-endian byte order.
+///
+/// Each vertex holds the attributes in the order given, each starting at the byte offset
+/// given by the sizes of the attributes before it, and the vertices are
+/// [`vertex_stride`] bytes apart. Every attribute must have the same number of vertices.
+pub fn interleave_vertices(attributes: &[VertexAttribute]) -> Vec<u8> {
+    let count = attributes.first().map_or(0, |a| a.data.len());
+    assert!(
+        attributes.iter().all(|a| a.data.len() == count),
+        "every attribute must have the same number of vertices"
+    );
+
+    let mut out = Vec::with_capacity(count * vertex_stride(attributes));
+    for index in 0..count {
+        for attribute in attributes {
+            attribute.write(index, &mut out);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn scalars() {
+        assert_eq!(pack_unorm8(1.0), 255);
+        assert_eq!(pack_unorm8(0.5), 128);
+        assert_eq!(pack_unorm8(-2.0), 0);
+        assert_eq!(pack_snorm8(-1.0), -127);
+        assert_eq!(pack_unorm16(1.0), 65535);
+        assert_eq!(pack_snorm16(0.5), 16384);
+
+        assert_eq!(pack_half(1.0), 0x3c00);
+        assert_eq!(pack_half(-2.0), 0xc000);
+        assert_eq!(pack_half(65504.0), 0x7bff);
+        assert_eq!(pack_half(1e6), 0x7c00);
+        assert_eq!(pack_half(f32::powi(2.0, -24)), 0x0001);
+        assert!(unpack_half(pack_half(f32::NAN)).is_nan());
+        for v in [0.0, 0.1, -3.75, 1000.5, 6e-5, 1e-7] {
+            assert_nearly_eq!(
+                unpack_half(pack_half(v)),
+                v,
+                epsilon = v.abs() * 1e-3 + 6e-8
+            );
+        }
+    }
+
+    #[test]
+    fn octahedral() {
+        for v in [
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(1.0, -2.0, 3.0).normalized(),
+            Vector3::new(-1.0, 2.0, -0.5).normalized(),
+            Vector3::new(-0.3, -0.2, -0.9).normalized(),
+        ] {
+            let p = encode_octahedral(v);
+            assert!(p.x.abs() <= 1.0 && p.y.abs() <= 1.0);
+            assert_nearly_eq!(&decode_octahedral(p), &v, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn interleave() {
+        let positions = [Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.0)];
+        let colors = [Vector4::new(1.0, 0.0, 0.5, 1.0), Vector4::one()];
+        let normals = [Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, 1.0)];
+
+        let attributes = [
+            VertexAttribute::new(AttributeData::Point(&positions), AttributeFormat::Float32),
+            VertexAttribute::new(AttributeData::Vector4(&colors), AttributeFormat::Unorm8),
+            VertexAttribute::new(
+                AttributeData::Vector3(&normals),
+                AttributeFormat::Octahedral16,
+            ),
+        ];
+        assert_eq!(vertex_stride(&attributes), 20);
+
+        let bytes = interleave_vertices(&attributes);
+        assert_eq!(bytes.len(), 40);
+        assert_eq!(&bytes[0..4], &1.0f32.to_le_bytes());
+        assert_eq!(&bytes[12..16], &[255, 0, 128, 255]);
+        assert_eq!(&bytes[16..20], &[0, 0, 0, 0]);
+        assert_eq!(&bytes[20..24], &4.0f32.to_le_bytes());
+
+        // Three-byte attributes are padded
+        let unorm = [VertexAttribute::new(
+            AttributeData::Vector3(&normals),
+            AttributeFormat::Unorm8,
+        )];
+        assert_eq!(interleave_vertices(&unorm), [0, 0, 255, 0, 0, 0, 255, 0]);
+    }
+}
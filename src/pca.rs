@@ -0,0 +1,140 @@
+use crate::{CompensatedSum, Matrix3, Point, Vector3};
+
+/// The covariance matrix of a set of points, about their centroid.
+///
+/// Returns the zero matrix if there are no points.
+pub fn covariance(points: &[Point]) -> Matrix3 {
+    let center = match Point::centroid(points) {
+        Some(center) => center,
+        None => return Matrix3::zero(),
+    };
+
+    // Accumulate the six unique entries of the symmetric matrix
+    let mut sums = [CompensatedSum::new(); 6];
+    for p in points {
+        let d = *p - center;
+        for (sum, value) in sums.iter_mut().zip([
+            d.x * d.x,
+            d.x * d.y,
+            d.x * d.z,
+            d.y * d.y,
+            d.y * d.z,
+            d.z * d.z,
+        ]) {
+            sum.add(value);
+        }
+    }
+
+    let n = points.len() as f32;
+    let [xx, xy, xz, yy, yz, zz] = sums.map(|s| s.value() / n);
+
+    Matrix3::from_2d_array([[xx, xy, xz], [xy, yy, yz], [xz, yz, zz]])
+}
+
+/// The principal axes of a set of points, along with the extents of the points along those axes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PrincipalComponents {
+    /// The center of the box bounding the points along the principal axes.
+    pub center: Point,
+    /// Orthonormal axes, in order of decreasing variance. For roughly planar point sets the
+    /// last axis approximates the surface normal.
+    pub axes: [Vector3; 3],
+    /// The variance of the points along each axis.
+    pub variances: Vector3,
+    /// Half the extent of the points along each axis, measured from the center.
+    pub half_extents: Vector3,
+}
+
+/// Compute the principal components of a set of points, which is suitable for fitting an
+/// oriented bounding box or estimating a surface normal. Returns `None` if there are no points.
+pub fn principal_components(points: &[Point]) -> Option<PrincipalComponents> {
+    let centroid = Point::centroid(points)?;
+    let (variances, vectors) = covariance(points).symmetric_eigen();
+
+    // Ensure a right-handed basis
+    let mut axes = [vectors.column(0), vectors.column(1), vectors.column(2)];
+    axes[2] = axes[0].cross(axes[1]);
+
+    let mut min = Vector3::from_scalar(f32::INFINITY);
+    let mut max = Vector3::from_scalar(f32::NEG_INFINITY);
+    for p in points {
+        let d = *p - centroid;
+        let projected = Vector3::new(axes[0].dot(d), axes[1].dot(d), axes[2].dot(d));
+        min = min.min(projected);
+        max = max.max(projected);
+    }
+
+    let mid = (min + max) * 0.5;
+    let center = centroid + axes[0] * mid.x + axes[1] * mid.y + axes[2] * mid.z;
+
+    Some(PrincipalComponents {
+        center,
+        axes,
+        variances,
+        half_extents: (max - min) * 0.5,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn covariance_of_line() {
+        let points = [
+            Point::new(-1.0, -2.0, 0.0),
+            Point::new(1.0, 2.0, 0.0),
+            Point::new(3.0, 6.0, 0.0),
+        ];
+        let c = covariance(&points);
+
+        assert_nearly_eq!(
+            &c,
+            &Matrix3::from_2d_array([
+                [8.0 / 3.0, 16.0 / 3.0, 0.0],
+                [16.0 / 3.0, 32.0 / 3.0, 0.0],
+                [0.0, 0.0, 0.0]
+            ]),
+            epsilon = 1e-5
+        );
+        assert_eq!(covariance(&[]), Matrix3::zero());
+    }
+
+    #[test]
+    fn principal_axes_of_box() {
+        let rotation = Matrix4::rotation_z(0.5);
+        let mut points = vec![];
+        for x in [-4.0, 4.0] {
+            for y in [-1.0, 1.0] {
+                for z in [-0.25, 0.25] {
+                    points.push(
+                        Matrix4::translation(Vector3::new(1.0, 2.0, 3.0))
+                            * rotation
+                            * Point::new(x, y, z),
+                    );
+                }
+            }
+        }
+
+        let pca = principal_components(&points).unwrap();
+        assert_nearly_eq!(&pca.center, &Point::new(1.0, 2.0, 3.0), epsilon = 1e-5);
+        assert_nearly_eq!(
+            &pca.half_extents,
+            &Vector3::new(4.0, 1.0, 0.25),
+            epsilon = 1e-4
+        );
+        assert_nearly_eq!(
+            pca.axes[0]
+                .dot(rotation * Vector3::new(1.0, 0.0, 0.0))
+                .abs(),
+            1.0,
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            pca.axes[2].dot(Vector3::new(0.0, 0.0, 1.0)).abs(),
+            1.0,
+            epsilon = 1e-5
+        );
+        assert_eq!(principal_components(&[]), None);
+    }
+}
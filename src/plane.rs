@@ -0,0 +1,249 @@
+use crate::validate::validate;
+use crate::{covariance, Aabb, MathError, Point, Side, Sphere, Vector3};
+
+/// A plane in 3D space, consisting of all points `p` where `normal.dot(p) + d == 0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Plane {
+    pub normal: Vector3,
+    pub d: f32,
+}
+
+impl Plane {
+    /// Construct a plane from the coefficients of its equation
+    pub const fn new(normal: Vector3, d: f32) -> Self {
+        Self { normal, d }
+    }
+
+    /// Construct a plane passing through the given point, with the given normal
+    pub fn from_point_normal(point: Point, normal: Vector3) -> Self {
+        Self::new(normal, -normal.dot(point.into()))
+    }
+
+    /// Construct the plane passing through three points, facing the side from which they
+    /// appear in counter-clockwise order
+    pub fn from_points(a: Point, b: Point, c: Point) -> Self {
+        let normal = (b - a).cross(c - a).normalized();
+        Self::from_point_normal(a, normal)
+    }
+
+    /// Fit a plane to a set of points, minimizing the sum of squared distances to the plane.
+    ///
+    /// The fitted normal has unit length, but which side of the plane it faces is arbitrary.
+    /// Returns `None` if there are fewer than three points.
+    pub fn fit(points: &[Point]) -> Option<Self> {
+        if points.len() < 3 {
+            return None;
+        }
+
+        let centroid = Point::centroid(points)?;
+        let (_, vectors) = covariance(points).symmetric_eigen();
+
+        // The direction of least variance is the normal
+        Some(Self::from_point_normal(
+            centroid,
+            vectors.column(2).normalized(),
+        ))
+    }
+
+    /// This plane, scaled so that its normal has unit length
+    pub fn normalized(&self) -> Self {
+        let length = self.normal.magnitude();
+        if length > 0.0 {
+            Self::new(self.normal / length, self.d / length)
+        } else {
+            *self
+        }
+    }
+
+    /// The signed distance from the plane to a point, positive on the side the normal faces.
+    /// Note that this is scaled by the length of the normal, if it is not unit length.
+    pub fn signed_distance(&self, point: Point) -> f32 {
+        self.normal.dot(point.into()) + self.d
+    }
+
+    /// Which side of the plane a point lies on, treating points within `epsilon` of the plane
+    /// as intersecting it
+    pub fn classify_point(&self, point: Point, epsilon: f32) -> Side {
+        Side::from_signed_distance(self.signed_distance(point), epsilon)
+    }
+
+    /// Which side of the plane a sphere lies on. The normal must be unit length.
+    pub fn classify_sphere(&self, sphere: &Sphere) -> Side {
+        self.validate_unit_normal();
+        Side::from_signed_distance(self.signed_distance(sphere.center), sphere.radius)
+    }
+
+    /// Which side of the plane a box lies on. The normal must be unit length.
+    pub fn classify_aabb(&self, aabb: &Aabb) -> Side {
+        self.validate_unit_normal();
+        // The extent of the box along the normal, measured from its center
+        let n = self.normal;
+        let radius = aabb
+            .half_extents()
+            .dot(Vector3::new(n.x.abs(), n.y.abs(), n.z.abs()));
+        Side::from_signed_distance(self.signed_distance(aabb.center()), radius)
+    }
+
+    fn validate_unit_normal(&self) {
+        validate!(
+            (self.normal.magnitude_squared() - 1.0).abs() < 1e-4,
+            "plane normal must be unit length"
+        );
+    }
+}
+
+/// A plane whose normal is guaranteed to have unit length, so that its signed distances are
+/// true distances. Derefs to [`Plane`] for queries.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct UnitPlane(Plane);
+
+impl UnitPlane {
+    /// Normalize a plane, or return [`MathError::ZeroVector`] if its normal has no length (or
+    /// its length is not finite)
+    pub fn new(plane: Plane) -> Result<Self, MathError> {
+        let length = plane.normal.magnitude();
+        if length > 0.0 && length.is_finite() {
+            Ok(Self(Plane::new(plane.normal / length, plane.d / length)))
+        } else {
+            Err(MathError::ZeroVector)
+        }
+    }
+
+    /// Construct a plane passing through the given point, normalizing the given normal
+    pub fn from_point_normal(point: Point, normal: Vector3) -> Result<Self, MathError> {
+        Self::new(Plane::from_point_normal(point, normal))
+    }
+
+    /// The underlying plane
+    pub fn plane(&self) -> Plane {
+        self.0
+    }
+}
+
+impl std::ops::Deref for UnitPlane {
+    type Target = Plane;
+
+    fn deref(&self) -> &Plane {
+        &self.0
+    }
+}
+
+impl From<UnitPlane> for Plane {
+    fn from(plane: UnitPlane) -> Self {
+        plane.0
+    }
+}
+
+impl TryFrom<Plane> for UnitPlane {
+    type Error = MathError;
+
+    fn try_from(plane: Plane) -> Result<Self, MathError> {
+        Self::new(plane)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn distance() {
+        let plane = Plane::from_points(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 1.0),
+            Point::new(1.0, 1.0, 0.0),
+        );
+
+        assert_eq!(plane.normal, Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(plane.signed_distance(Point::new(5.0, 3.0, -2.0)), 2.0);
+        assert_eq!(plane.signed_distance(Point::new(5.0, -3.0, -2.0)), -4.0);
+    }
+
+    #[test]
+    fn classify() {
+        let plane = Plane::from_point_normal(Point::new(0.0, 1.0, 0.0), Vector3::UP);
+
+        assert_eq!(
+            plane.classify_point(Point::new(0.0, 2.0, 0.0), 1e-5),
+            Side::Front
+        );
+        assert_eq!(
+            plane.classify_point(Point::new(0.0, 1.0, 3.0), 1e-5),
+            Side::Intersecting
+        );
+        assert_eq!(
+            plane.classify_sphere(&Sphere::new(Point::new(0.0, -1.0, 0.0), 1.5)),
+            Side::Back
+        );
+        assert_eq!(
+            plane.classify_sphere(&Sphere::new(Point::new(0.0, 0.0, 0.0), 1.5)),
+            Side::Intersecting
+        );
+
+        let aabb = Aabb::new(Point::new(-1.0, 1.5, -1.0), Point::new(1.0, 2.5, 1.0));
+        assert_eq!(plane.classify_aabb(&aabb), Side::Front);
+
+        let tilted = Plane::from_point_normal(
+            Point::new(0.0, 0.5, 0.0),
+            Vector3::new(1.0, 1.0, 0.0).normalized(),
+        );
+        assert_eq!(tilted.classify_aabb(&aabb), Side::Intersecting);
+        assert_eq!(
+            tilted.classify_aabb(&Aabb::new(
+                Point::new(-4.0, -4.0, 0.0),
+                Point::new(-3.0, -3.0, 1.0)
+            )),
+            Side::Back
+        );
+    }
+
+    #[test]
+    fn fit() {
+        let normal = Vector3::new(1.0, 2.0, 2.0).normalized();
+        let base = Plane::from_point_normal(Point::new(1.0, 0.0, 0.0), normal);
+        let tangent = normal.cross(Vector3::new(0.0, 0.0, 1.0)).normalized();
+        let bitangent = normal.cross(tangent);
+
+        let points: Vec<_> = (0..50)
+            .map(|i| {
+                let (u, v) = ((i % 7) as f32 - 3.0, (i / 7) as f32 - 3.0);
+                let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+                Point::new(1.0, 0.0, 0.0) + tangent * u + bitangent * v + normal * noise
+            })
+            .collect();
+
+        let plane = Plane::fit(&points).unwrap();
+        assert_nearly_eq!(plane.normal.dot(base.normal).abs(), 1.0, epsilon = 1e-4);
+        for p in &points {
+            assert!(plane.signed_distance(*p).abs() < 0.02);
+        }
+
+        assert_eq!(Plane::fit(&points[..2]), None);
+    }
+
+    #[test]
+    fn unit() {
+        // Planes extracted from a projection matrix are scaled
+        let plane = Plane::new(Vector3::new(0.0, 2.0, 0.0), -4.0);
+        let p = Point::new(3.0, 5.0, 0.0);
+        assert_eq!(plane.signed_distance(p), 6.0);
+
+        let unit = UnitPlane::new(plane).unwrap();
+        assert_eq!(unit.normal, Vector3::UNIT_Y);
+        assert_eq!(unit.signed_distance(p), 3.0);
+        assert_eq!(Plane::from(unit), plane.normalized());
+
+        assert_eq!(
+            UnitPlane::try_from(Plane::new(Vector3::zero(), 1.0)),
+            Err(MathError::ZeroVector)
+        );
+        assert_eq!(
+            UnitPlane::from_point_normal(Point::ORIGIN, Vector3::new(0.0, 0.0, -3.0))
+                .unwrap()
+                .plane(),
+            Plane::new(-Vector3::UNIT_Z, 0.0)
+        );
+    }
+}
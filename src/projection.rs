@@ -0,0 +1,238 @@
+use crate::{DepthRange, Matrix4, Vector4};
+
+/// The parameters of a camera projection, from which the projection matrix is built.
+///
+/// Keeping the parameters rather than the matrix lets the projection be adjusted in place,
+/// such as when the window is resized.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Projection {
+    /// A perspective projection. See [`Matrix4::perspective`].
+    Perspective {
+        aspect_ratio: f32,
+        /// The vertical field of view
+        fov_radians: f32,
+        near: f32,
+        far: f32,
+        depth_range: DepthRange,
+    },
+    /// An orthographic projection of the given box. See [`Matrix4::orthographic`].
+    Orthographic {
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+        depth_range: DepthRange,
+    },
+}
+
+impl Projection {
+    /// A perspective projection, with OpenGL's -1 to 1 depth range
+    pub const fn perspective(aspect_ratio: f32, fov_radians: f32, near: f32, far: f32) -> Self {
+        Self::Perspective {
+            aspect_ratio,
+            fov_radians,
+            near,
+            far,
+            depth_range: DepthRange::NegativeOneToOne,
+        }
+    }
+
+    /// An orthographic projection, with OpenGL's -1 to 1 depth range
+    pub const fn orthographic(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        Self::Orthographic {
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            depth_range: DepthRange::NegativeOneToOne,
+        }
+    }
+
+    /// The projection (camera-to-clip) matrix
+    pub fn matrix(&self) -> Matrix4 {
+        match *self {
+            Self::Perspective {
+                aspect_ratio,
+                fov_radians,
+                near,
+                far,
+                depth_range,
+            } => Matrix4::perspective_with_depth_range(
+                aspect_ratio,
+                fov_radians,
+                near,
+                far,
+                depth_range,
+            ),
+            Self::Orthographic {
+                left,
+                right,
+                bottom,
+                top,
+                near,
+                far,
+                depth_range,
+            } => Matrix4::orthographic_with_depth_range(
+                left,
+                right,
+                bottom,
+                top,
+                near,
+                far,
+                depth_range,
+            ),
+        }
+    }
+
+    /// The inverse of the projection matrix, which maps clip coordinates back to camera space
+    pub fn inverse_matrix(&self) -> Matrix4 {
+        match *self {
+            Self::Perspective { .. } => {
+                // Invert the nonzero elements directly, rather than the whole matrix
+                let m = self.matrix();
+                let (c, d) = (m.0[2][2], m.0[3][2]);
+                Matrix4::from_cols(
+                    Vector4::new(1.0 / m.0[0][0], 0.0, 0.0, 0.0),
+                    Vector4::new(0.0, 1.0 / m.0[1][1], 0.0, 0.0),
+                    Vector4::new(0.0, 0.0, 0.0, 1.0 / d),
+                    Vector4::new(0.0, 0.0, -1.0, c / d),
+                )
+            }
+            Self::Orthographic { .. } => self.matrix().invert(),
+        }
+    }
+
+    /// Adjust the aspect ratio to match a viewport of the given size. Orthographic projections
+    /// keep their vertical extent and center, and are widened or narrowed to match.
+    pub fn resize(&mut self, width: f32, height: f32) {
+        let ratio = width / height;
+        match self {
+            Self::Perspective { aspect_ratio, .. } => *aspect_ratio = ratio,
+            Self::Orthographic {
+                left,
+                right,
+                bottom,
+                top,
+                ..
+            } => {
+                let center = (*left + *right) * 0.5;
+                let half_width = (*top - *bottom) * 0.5 * ratio;
+                *left = center - half_width;
+                *right = center + half_width;
+            }
+        }
+    }
+
+    /// Change the vertical field of view. This has no effect on orthographic projections.
+    pub fn set_fov(&mut self, fov: f32) {
+        if let Self::Perspective { fov_radians, .. } = self {
+            *fov_radians = fov;
+        }
+    }
+
+    /// Change the distances to the near and far clipping planes
+    pub fn set_depth(&mut self, near_plane: f32, far_plane: f32) {
+        match self {
+            Self::Perspective { near, far, .. } | Self::Orthographic { near, far, .. } => {
+                *near = near_plane;
+                *far = far_plane;
+            }
+        }
+    }
+
+    /// The range of depths the projection matrix produces
+    pub fn depth_range(&self) -> DepthRange {
+        match *self {
+            Self::Perspective { depth_range, .. } | Self::Orthographic { depth_range, .. } => {
+                depth_range
+            }
+        }
+    }
+
+    /// Change the range of depths the projection matrix produces, such as to target an API
+    /// with a 0 to 1 depth range
+    pub fn set_depth_range(&mut self, range: DepthRange) {
+        match self {
+            Self::Perspective { depth_range, .. } | Self::Orthographic { depth_range, .. } => {
+                *depth_range = range;
+            }
+        }
+    }
+}
+
+impl From<Projection> for Matrix4 {
+    fn from(projection: Projection) -> Self {
+        projection.matrix()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn projection() {
+        let mut projection = Projection::perspective(1.0, 1.2, 0.1, 100.0);
+        projection.resize(1920.0, 1080.0);
+        assert_eq!(
+            projection.matrix(),
+            Matrix4::perspective(1920.0 / 1080.0, 1.2, 0.1, 100.0)
+        );
+        assert_nearly_eq!(
+            &(projection.inverse_matrix() * projection.matrix()),
+            &Matrix4::identity(),
+            epsilon = 1e-5
+        );
+
+        projection.set_fov(0.8);
+        projection.set_depth(1.0, 10.0);
+        assert_eq!(
+            Matrix4::from(projection),
+            Matrix4::perspective(1920.0 / 1080.0, 0.8, 1.0, 10.0)
+        );
+
+        let mut ortho = Projection::orthographic(-1.0, 1.0, -1.0, 1.0, 0.0, 10.0);
+        ortho.resize(200.0, 100.0);
+        ortho.set_fov(0.8);
+        assert_eq!(
+            ortho,
+            Projection::orthographic(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0)
+        );
+
+        projection.set_depth_range(DepthRange::ZeroToOne);
+        assert_eq!(projection.depth_range(), DepthRange::ZeroToOne);
+        assert_eq!(
+            projection.matrix(),
+            Matrix4::perspective_with_depth_range(
+                1920.0 / 1080.0,
+                0.8,
+                1.0,
+                10.0,
+                DepthRange::ZeroToOne
+            )
+        );
+        assert_nearly_eq!(
+            &(projection.inverse_matrix() * projection.matrix()),
+            &Matrix4::identity(),
+            epsilon = 1e-5
+        );
+
+        ortho.set_depth_range(DepthRange::OneToZero);
+        assert_nearly_eq!(
+            &(ortho.inverse_matrix() * ortho.matrix()),
+            &Matrix4::identity(),
+            epsilon = 1e-5
+        );
+    }
+}
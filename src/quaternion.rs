@@ -0,0 +1,388 @@
+use crate::{Matrix4, NearlyEqual, Point, Tolerance, Vector3, Vector4};
+
+/// A quaternion, with vector part `(x, y, z)` and scalar part `w`. Unit-length quaternions
+/// represent rotations.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quaternion {
+    /// Construct a quaternion from its components
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// The quaternion representing no rotation
+    pub const fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// A rotation around a unit-length axis, matching [`Matrix4::rotation_axis_angle`]
+    pub fn from_axis_angle(axis: Vector3, angle_radians: f32) -> Self {
+        // Negate the angle, since the matrix rotates clockwise looking along the axis
+        let (sin, cos) = (-0.5 * angle_radians).sin_cos();
+        let v = axis * sin;
+        Self::new(v.x, v.y, v.z, cos)
+    }
+
+    /// The unit-length axis and angle of the rotation described by this unit quaternion. This
+    /// is the inverse of [`Self::from_axis_angle`], with the angle in `[0, 2π]`. An arbitrary
+    /// axis is returned when there is no rotation.
+    pub fn to_axis_angle(&self) -> (Vector3, f32) {
+        let v = self.vector();
+        let sin = v.magnitude();
+        if sin > 0.0 {
+            (-v / sin, 2.0 * sin.atan2(self.w))
+        } else {
+            (Vector3::UNIT_X, 0.0)
+        }
+    }
+
+    /// A uniformly distributed random rotation, using Shoemake's subgroup algorithm.
+    ///
+    /// `random` must return independent values uniformly distributed in `[0, 1)`. Unlike
+    /// choosing three random Euler angles, every orientation is equally likely.
+    pub fn random(mut random: impl FnMut() -> f32) -> Self {
+        use std::f32::consts::TAU;
+
+        let (u1, u2, u3) = (random(), random(), random());
+        let (a, b) = ((1.0 - u1).sqrt(), u1.sqrt());
+        let (sin2, cos2) = (TAU * u2).sin_cos();
+        let (sin3, cos3) = (TAU * u3).sin_cos();
+        Self::new(a * sin2, a * cos2, b * sin3, b * cos3)
+    }
+
+    /// The rotation described by the rotation part of a matrix, which must be orthonormal
+    pub fn from_matrix(m: &Matrix4) -> Self {
+        let r = |row: usize, col: usize| m.0[col][row];
+
+        // Solve for the largest component first, to avoid dividing by a small value
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::new(
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+                s * 0.25,
+            )
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = (1.0 + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * 2.0;
+            Self::new(
+                s * 0.25,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(2, 1) - r(1, 2)) / s,
+            )
+        } else if r(1, 1) > r(2, 2) {
+            let s = (1.0 + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * 2.0;
+            Self::new(
+                (r(0, 1) + r(1, 0)) / s,
+                s * 0.25,
+                (r(1, 2) + r(2, 1)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+            )
+        } else {
+            let s = (1.0 + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * 2.0;
+            Self::new(
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                s * 0.25,
+                (r(1, 0) - r(0, 1)) / s,
+            )
+        }
+    }
+
+    /// The rotation matrix equivalent to this quaternion, which must be unit length
+    pub fn to_matrix(&self) -> Matrix4 {
+        let Self { x, y, z, w } = *self;
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, yy, zz) = (x * x2, y * y2, z * z2);
+        let (xy, xz, yz) = (x * y2, x * z2, y * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+
+        Matrix4::from_cols(
+            Vector4::new(1.0 - yy - zz, xy + wz, xz - wy, 0.0),
+            Vector4::new(xy - wz, 1.0 - xx - zz, yz + wx, 0.0),
+            Vector4::new(xz + wy, yz - wx, 1.0 - xx - yy, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// The vector part of this quaternion
+    pub fn vector(&self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// The dot product of two quaternions, which is the cosine of half the angle between
+    /// them for unit quaternions
+    pub fn dot(&self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// The length of this quaternion
+    pub fn magnitude(&self) -> f32 {
+        self.dot(*self).sqrt()
+    }
+
+    /// This quaternion scaled to unit length
+    pub fn normalized(&self) -> Self {
+        crate::nan_policy::apply(
+            *self * (1.0 / self.magnitude()),
+            Self::is_finite,
+            || Self::new(0.0, 0.0, 0.0, 0.0),
+            "normalize",
+        )
+    }
+
+    /// Whether every component of this quaternion is finite (neither infinite nor NaN)
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// The conjugate of this quaternion, which for unit quaternions is the inverse rotation
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// The inverse of this quaternion, which need not be unit length. Prefer
+    /// [`Self::conjugate`] for rotations, which is equivalent and cheaper.
+    pub fn inverse(&self) -> Self {
+        self.conjugate() * (1.0 / self.dot(*self))
+    }
+
+    /// Spherical linear interpolation between two unit quaternions, rotating at a constant
+    /// rate along the shortest path between them
+    pub fn slerp(&self, rhs: Self, factor: f32) -> Self {
+        // Flip to the same hemisphere, since q and -q represent the same rotation
+        let (rhs, cos) = if self.dot(rhs) < 0.0 {
+            (-rhs, -self.dot(rhs))
+        } else {
+            (rhs, self.dot(rhs))
+        };
+
+        if cos > 0.9995 {
+            // Nearly parallel, where linear interpolation is accurate and avoids dividing by zero
+            return (*self * (1.0 - factor) + rhs * factor).normalized();
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        *self * (((1.0 - factor) * angle).sin() / sin) + rhs * ((factor * angle).sin() / sin)
+    }
+
+    /// Rotate a vector by this quaternion, which must be unit length
+    pub fn rotate(&self, v: Vector3) -> Vector3 {
+        let u = self.vector();
+        let t = u.cross(v) * 2.0;
+        v + t * self.w + u.cross(t)
+    }
+
+    /// Rotate a point about the origin by this quaternion, which must be unit length
+    pub fn rotate_point(&self, p: Point) -> Point {
+        Point::from(self.rotate(Vector3::from(p)))
+    }
+}
+
+impl Matrix4 {
+    /// A uniformly distributed random rotation. See [`Quaternion::random`].
+    pub fn random_rotation(random: impl FnMut() -> f32) -> Self {
+        Quaternion::random(random).to_matrix()
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl std::ops::Mul for Quaternion {
+    type Output = Quaternion;
+
+    /// The composition of two rotations, applying `rhs` first
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        let (a, b) = (self.vector(), rhs.vector());
+        let v = b * self.w + a * rhs.w + a.cross(b);
+        Quaternion::new(v.x, v.y, v.z, self.w * rhs.w - a.dot(b))
+    }
+}
+
+impl std::ops::Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    /// Rotate a vector. See [`Quaternion::rotate`].
+    fn mul(self, v: Vector3) -> Vector3 {
+        self.rotate(v)
+    }
+}
+
+impl std::ops::Mul<Point> for Quaternion {
+    type Output = Point;
+
+    /// Rotate a point about the origin. See [`Quaternion::rotate_point`].
+    fn mul(self, p: Point) -> Point {
+        self.rotate_point(p)
+    }
+}
+
+impl std::ops::MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Quaternion) {
+        *self = *self * rhs;
+    }
+}
+
+impl std::ops::Mul<f32> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, s: f32) -> Quaternion {
+        Quaternion::new(self.x * s, self.y * s, self.z * s, self.w * s)
+    }
+}
+
+impl std::ops::Add for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl std::ops::Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl From<Quaternion> for Matrix4 {
+    fn from(q: Quaternion) -> Self {
+        q.to_matrix()
+    }
+}
+
+impl From<Quaternion> for Vector4 {
+    fn from(q: Quaternion) -> Self {
+        Vector4::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Vector4> for Quaternion {
+    fn from(v: Vector4) -> Self {
+        Quaternion::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl NearlyEqual for &Quaternion {
+    fn nearly_equals(self, rhs: Self) -> bool {
+        Vector4::from(*self).nearly_equals(&Vector4::from(*rhs))
+    }
+
+    fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+        Vector4::from(*self).nearly_equals_within(&Vector4::from(*rhs), tolerance)
+    }
+
+    fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+        Vector4::from(*self).nearly_equals_ulps(&Vector4::from(*rhs), max_ulps)
+    }
+
+    fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+        Vector4::from(*self).describe_mismatch(&Vector4::from(*rhs), tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn matrix() {
+        let m = Matrix4::rotation_axis_angle(Vector3::new(1.0, 2.0, -2.0).normalized(), 2.5);
+        let q = Quaternion::from_matrix(&m);
+
+        assert_nearly_eq!(q.magnitude(), 1.0, epsilon = 1e-6);
+        assert_nearly_eq!(&q.to_matrix(), &m, epsilon = 1e-6);
+
+        let v = Vector3::new(0.5, -1.0, 3.0);
+        assert_nearly_eq!(&q.rotate(v), &(m * v), epsilon = 1e-5);
+
+        let n = Matrix4::rotation_x(0.7) * Matrix4::rotation_z(-2.0);
+        let r = Quaternion::from_matrix(&n);
+        assert_nearly_eq!(&(q * r).to_matrix(), &(m * n), epsilon = 1e-5);
+        assert_nearly_eq!(
+            &(q * q.conjugate()),
+            &Quaternion::identity(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn axis_angle() {
+        let axis = Vector3::new(2.0, -1.0, 2.0).normalized();
+        let q = Quaternion::from_axis_angle(axis, 1.2);
+
+        assert_nearly_eq!(
+            &Matrix4::from(q),
+            &Matrix4::rotation_axis_angle(axis, 1.2),
+            epsilon = 1e-6
+        );
+        assert_nearly_eq!(
+            &(Quaternion::from_axis_angle(Vector3::UNIT_Z, std::f32::consts::FRAC_PI_2)
+                * Point::new(1.0, 0.0, 5.0)),
+            &(Matrix4::rotation_z(std::f32::consts::FRAC_PI_2) * Point::new(1.0, 0.0, 5.0)),
+            epsilon = 1e-6
+        );
+
+        let (a, angle) = q.to_axis_angle();
+        assert_nearly_eq!(&a, &axis, epsilon = 1e-6);
+        assert_nearly_eq!(angle, 1.2, epsilon = 1e-6);
+        assert_eq!(
+            Quaternion::identity().to_axis_angle(),
+            (Vector3::UNIT_X, 0.0)
+        );
+
+        let scaled = q * 3.0;
+        assert_nearly_eq!(
+            &(scaled * scaled.inverse()),
+            &Quaternion::identity(),
+            epsilon = 1e-6
+        );
+        let mut r = q;
+        r *= q.conjugate();
+        assert_nearly_eq!(&r, &Quaternion::identity(), epsilon = 1e-6);
+        assert_nearly_eq!(&(q * (q.inverse() * axis)), &axis, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn random() {
+        // A simple linear congruential generator, so the test is repeatable
+        let mut state = 12345u32;
+        let mut random = || {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            (state >> 8) as f32 / (1 << 24) as f32
+        };
+
+        // Uniformly rotated vectors average out to nothing
+        let count = 10000;
+        let mut sum = Vector3::zero();
+        for _ in 0..count {
+            let q = Quaternion::random(&mut random);
+            assert_nearly_eq!(q.magnitude(), 1.0, epsilon = 1e-5);
+            sum += q.rotate(Vector3::UNIT_Z);
+        }
+        assert!((sum / count as f32).magnitude() < 0.03);
+
+        assert!(Matrix4::random_rotation(&mut random).is_orthonormal(1e-5));
+    }
+}
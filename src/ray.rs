@@ -0,0 +1,69 @@
+use crate::{Point, Vector3};
+
+/// A ray in 3D space, starting at `origin` and extending along `direction`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector3,
+}
+
+impl Ray {
+    /// Construct a ray from its origin and direction
+    pub const fn new(origin: Point, direction: Vector3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// The point at the given distance along the ray, in multiples of `direction`
+    pub fn at(&self, t: f32) -> Point {
+        self.origin + self.direction * t
+    }
+
+    /// The closest point on the ray to the given point
+    pub fn closest_point(&self, point: Point) -> Point {
+        let length_squared = self.direction.magnitude_squared();
+        if length_squared == 0.0 {
+            return self.origin;
+        }
+
+        let t = (point - self.origin).dot(self.direction) / length_squared;
+        self.at(t.max(0.0))
+    }
+
+    /// The distance from the ray to a point
+    pub fn distance(&self, point: Point) -> f32 {
+        (point - self.closest_point(point)).magnitude()
+    }
+}
+
+/// The smaller root of `a·t² + 2·b·t + c = 0` that is not negative, as used by ray-quadric
+/// intersection tests. Returns `None` if there are no real roots, or both are negative.
+pub(crate) fn first_root(a: f32, b: f32, c: f32) -> Option<f32> {
+    let discriminant = b * b - a * c;
+    if a == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    [(-b - root) / a, (-b + root) / a]
+        .into_iter()
+        .find(|t| *t >= 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn closest_point() {
+        let ray = Ray::new(Point::new(1.0, 1.0, 0.0), Vector3::new(2.0, 0.0, 0.0));
+
+        assert_eq!(ray.at(1.5), Point::new(4.0, 1.0, 0.0));
+        assert_eq!(
+            ray.closest_point(Point::new(3.0, 5.0, 0.0)),
+            Point::new(3.0, 1.0, 0.0)
+        );
+        assert_eq!(ray.closest_point(Point::new(-3.0, 1.0, 0.0)), ray.origin);
+        assert_eq!(ray.distance(Point::new(3.0, 1.0, -2.0)), 2.0);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::Vector2;
+
+/// An axis-aligned rectangle in 2D space, spanning from `min` to `max`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Rect {
+    pub min: Vector2,
+    pub max: Vector2,
+}
+
+impl Rect {
+    /// Construct a rectangle from its minimum and maximum corners
+    pub const fn new(min: Vector2, max: Vector2) -> Self {
+        Self { min, max }
+    }
+
+    /// Construct a rectangle from its minimum corner and its size
+    pub fn from_origin_size(origin: Vector2, size: Vector2) -> Self {
+        Self::new(origin, origin + size)
+    }
+
+    /// Construct a rectangle from its center and half its size
+    pub fn from_center_half_size(center: Vector2, half_size: Vector2) -> Self {
+        Self::new(center - half_size, center + half_size)
+    }
+
+    /// The smallest rectangle containing all of the given points.
+    /// Returns `None` if there are no points.
+    pub fn from_points(points: &[Vector2]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self::new(*first, *first), |r, p| {
+            Self::new(r.min.min(*p), r.max.max(*p))
+        }))
+    }
+
+    /// The width and height of this rectangle
+    pub fn size(&self) -> Vector2 {
+        self.max - self.min
+    }
+
+    /// The center of this rectangle
+    pub fn center(&self) -> Vector2 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// The area of this rectangle, which is zero if it is empty
+    pub fn area(&self) -> f32 {
+        let size = self.size().max(Vector2::zero());
+        size.x * size.y
+    }
+
+    /// Whether this rectangle encloses no area, i.e. `max` is not greater than `min` along some axis
+    pub fn is_empty(&self) -> bool {
+        self.max.x <= self.min.x || self.max.y <= self.min.y
+    }
+
+    /// Whether a point lies within this rectangle, including its edges
+    pub fn contains(&self, point: Vector2) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Whether another rectangle lies entirely within this one
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Whether this rectangle overlaps another. Rectangles that only share an edge intersect.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// The overlapping region of this rectangle and another, or `None` if they don't intersect
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        if self.intersects(other) {
+            Some(Rect::new(self.min.max(other.min), self.max.min(other.max)))
+        } else {
+            None
+        }
+    }
+
+    /// The smallest rectangle containing both this rectangle and another
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// This rectangle grown by the given amount on every side
+    pub fn expanded(&self, amount: f32) -> Rect {
+        Rect::new(self.min - amount, self.max + amount)
+    }
+
+    /// The closest point within this rectangle to the given point
+    pub fn clamp(&self, point: Vector2) -> Vector2 {
+        point.max(self.min).min(self.max)
+    }
+
+    /// Map a point within this rectangle to the range `[0, 1]` along each axis, as for texture
+    /// atlas coordinates
+    pub fn normalize(&self, point: Vector2) -> Vector2 {
+        (point - self.min) / self.size()
+    }
+
+    /// Map a point in the range `[0, 1]` along each axis to a point within this rectangle
+    pub fn denormalize(&self, uv: Vector2) -> Vector2 {
+        self.min + uv * self.size()
+    }
+
+    /// Map a point within this rectangle, treated as a viewport, to normalized device
+    /// coordinates in the range `[-1, 1]`.
+    ///
+    /// Note that `min` maps to `(-1, -1)`, so for window coordinates where y points down the
+    /// resulting y-axis must be flipped.
+    pub fn to_ndc(&self, point: Vector2) -> Vector2 {
+        self.normalize(point) * 2.0 - 1.0
+    }
+
+    /// Map normalized device coordinates in the range `[-1, 1]` to a point within this rectangle,
+    /// treated as a viewport. This is the inverse of [`Self::to_ndc`].
+    pub fn from_ndc(&self, ndc: Vector2) -> Vector2 {
+        self.denormalize((ndc + 1.0) * 0.5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn overlap() {
+        let a = Rect::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 2.0));
+        let b = Rect::from_origin_size(Vector2::new(3.0, 1.0), Vector2::new(2.0, 2.0));
+        let c = Rect::from_center_half_size(Vector2::new(10.0, 10.0), Vector2::one());
+
+        assert!(a.contains(Vector2::new(4.0, 1.0)));
+        assert!(!a.contains(Vector2::new(4.5, 1.0)));
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new(Vector2::new(3.0, 1.0), Vector2::new(4.0, 2.0)))
+        );
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(
+            a.union(&b),
+            Rect::new(Vector2::zero(), Vector2::new(5.0, 3.0))
+        );
+        assert!(a.union(&c).contains_rect(&c));
+        assert_eq!(a.area(), 8.0);
+        assert_eq!(
+            Rect::from_points(&[Vector2::new(1.0, 5.0), Vector2::new(-1.0, 2.0)]),
+            Some(Rect::new(Vector2::new(-1.0, 2.0), Vector2::new(1.0, 5.0)))
+        );
+    }
+
+    #[test]
+    fn viewport() {
+        let viewport =
+            Rect::from_origin_size(Vector2::new(100.0, 50.0), Vector2::new(800.0, 600.0));
+
+        assert_eq!(viewport.to_ndc(viewport.min), Vector2::new(-1.0, -1.0));
+        assert_eq!(viewport.to_ndc(viewport.center()), Vector2::zero());
+        assert_eq!(viewport.from_ndc(Vector2::one()), viewport.max);
+        assert_eq!(
+            viewport.normalize(Vector2::new(300.0, 200.0)),
+            Vector2::new(0.25, 0.25)
+        );
+        assert_eq!(
+            viewport.denormalize(Vector2::new(0.25, 0.25)),
+            Vector2::new(300.0, 200.0)
+        );
+    }
+}
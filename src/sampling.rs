@@ -0,0 +1,68 @@
+use crate::{Vector2, Vector3};
+
+/// The golden angle in radians, `π(3 - √5)`, which spaces successive points so that none
+/// line up with each other
+const GOLDEN_ANGLE: f32 = 2.399_963_2;
+
+/// `count` unit-length directions spread approximately evenly over the sphere, for sampling
+/// kernels and probe placement.
+///
+/// The directions spiral from the `+z` pole to the `-z` pole, with each covering an equal
+/// area of the sphere.
+pub fn fibonacci_sphere(count: usize) -> impl ExactSizeIterator<Item = Vector3> {
+    (0..count).map(move |i| {
+        let z = 1.0 - (2 * i + 1) as f32 / count as f32;
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+        let (sin, cos) = (i as f32 * GOLDEN_ANGLE).sin_cos();
+        Vector3::new(cos * radius, sin * radius, z)
+    })
+}
+
+/// `count` points spread approximately evenly over the unit disk, in a golden-angle (sunflower)
+/// spiral outwards from the center
+pub fn golden_spiral_disk(count: usize) -> impl ExactSizeIterator<Item = Vector2> {
+    (0..count).map(move |i| {
+        let radius = ((i as f32 + 0.5) / count as f32).sqrt();
+        let (sin, cos) = (i as f32 * GOLDEN_ANGLE).sin_cos();
+        Vector2::new(cos * radius, sin * radius)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn sphere() {
+        let points: Vec<_> = fibonacci_sphere(500).collect();
+        assert_eq!(points.len(), 500);
+
+        let mut sum = Vector3::zero();
+        for p in &points {
+            assert_nearly_eq!(p.magnitude(), 1.0, epsilon = 1e-5);
+            sum += *p;
+        }
+        assert!(sum.magnitude() / 500.0 < 1e-3);
+
+        // Evenly spread, so no two points are much closer than the average spacing
+        let spacing = (4.0 * std::f32::consts::PI / 500.0).sqrt();
+        for (i, a) in points.iter().enumerate() {
+            for b in &points[i + 1..] {
+                assert!((*a - *b).magnitude() > spacing * 0.5);
+            }
+        }
+    }
+
+    #[test]
+    fn disk() {
+        let points: Vec<_> = golden_spiral_disk(200).collect();
+
+        // Equal numbers of points in the inner and outer halves of the disk's area
+        let inner = points
+            .iter()
+            .filter(|p| p.magnitude_squared() < 0.5)
+            .count();
+        assert_eq!(inner, 100);
+        assert!(points.iter().all(|p| p.magnitude() <= 1.0));
+    }
+}
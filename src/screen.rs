@@ -0,0 +1,85 @@
+use crate::{Rect, Vector2};
+
+/// How a graphics API orients normalized device coordinates relative to window coordinates.
+///
+/// The APIs disagree on which way y points in each space, so the y-axis must be flipped when
+/// converting for some APIs and not others.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScreenConvention {
+    /// NDC y points up, and window coordinates start at the bottom-left. Note that windowing
+    /// systems report mouse positions from the top-left, which must first be flipped to
+    /// match.
+    OpenGl,
+    /// NDC y points up, and window coordinates start at the top-left. This is also the
+    /// convention of Metal and wgpu.
+    Direct3D,
+    /// NDC y points down, and window coordinates start at the top-left
+    Vulkan,
+}
+
+impl ScreenConvention {
+    /// Whether y points in opposite directions in NDC and window coordinates
+    pub fn flips_y(&self) -> bool {
+        matches!(self, Self::Direct3D)
+    }
+}
+
+/// Map normalized device coordinates to window coordinates within a viewport, following the
+/// given API's convention
+pub fn ndc_to_screen(ndc: Vector2, viewport: &Rect, convention: ScreenConvention) -> Vector2 {
+    if convention.flips_y() {
+        viewport.from_ndc(Vector2::new(ndc.x, -ndc.y))
+    } else {
+        viewport.from_ndc(ndc)
+    }
+}
+
+/// Map window coordinates within a viewport to normalized device coordinates, following the
+/// given API's convention. This is the inverse of [`ndc_to_screen`].
+pub fn screen_to_ndc(screen: Vector2, viewport: &Rect, convention: ScreenConvention) -> Vector2 {
+    let ndc = viewport.to_ndc(screen);
+    if convention.flips_y() {
+        Vector2::new(ndc.x, -ndc.y)
+    } else {
+        ndc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn conventions() {
+        let viewport = Rect::from_origin_size(Vector2::zero(), Vector2::new(800.0, 600.0));
+
+        // The top-left corner of the image, in each API's own coordinates
+        let top_left = Vector2::new(-1.0, 1.0);
+        assert_eq!(
+            ndc_to_screen(top_left, &viewport, ScreenConvention::OpenGl),
+            Vector2::new(0.0, 600.0)
+        );
+        assert_eq!(
+            ndc_to_screen(top_left, &viewport, ScreenConvention::Direct3D),
+            Vector2::zero()
+        );
+        assert_eq!(
+            ndc_to_screen(-Vector2::one(), &viewport, ScreenConvention::Vulkan),
+            Vector2::zero()
+        );
+
+        let mouse = Vector2::new(200.0, 450.0);
+        for convention in [
+            ScreenConvention::OpenGl,
+            ScreenConvention::Direct3D,
+            ScreenConvention::Vulkan,
+        ] {
+            let ndc = screen_to_ndc(mouse, &viewport, convention);
+            assert_eq!(ndc_to_screen(ndc, &viewport, convention), mouse);
+        }
+        assert_eq!(
+            screen_to_ndc(mouse, &viewport, ScreenConvention::Direct3D),
+            Vector2::new(-0.5, -0.5)
+        );
+    }
+}
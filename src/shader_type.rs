@@ -0,0 +1,55 @@
+//! Integration with [`encase`], so that vectors and matrices can be written directly into
+//! wgpu uniform and storage buffers with WGSL layout rules applied.
+
+use crate::{Matrix3, Matrix4, Point, Vector2, Vector3, Vector3A, Vector4};
+
+encase::impl_vector!(2, Vector2, f32; using AsRef AsMut From);
+encase::impl_vector!(3, Vector3, f32; using AsRef AsMut From);
+encase::impl_vector!(3, Vector3A, f32; using AsRef AsMut From);
+encase::impl_vector!(3, Point, f32; using AsRef AsMut From);
+encase::impl_vector!(4, Vector4, f32; using AsRef AsMut From);
+
+encase::impl_matrix!(3, 3, Matrix3, f32; using AsRef AsMut From);
+encase::impl_matrix!(4, 4, Matrix4, f32; using AsRef AsMut From);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use encase::{ShaderType, UniformBuffer};
+
+    #[derive(ShaderType)]
+    struct Uniforms {
+        position: Point,
+        scale: f32,
+        direction: Vector3,
+        transform: Matrix4,
+    }
+
+    #[test]
+    fn uniform_layout() {
+        let uniforms = Uniforms {
+            position: Point::new(1.0, 2.0, 3.0),
+            scale: 4.0,
+            direction: Vector3::new(5.0, 6.0, 7.0),
+            transform: Matrix4::translation(Vector3::new(8.0, 9.0, 10.0)),
+        };
+
+        let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&uniforms).unwrap();
+        let bytes = buffer.into_inner();
+        let floats: Vec<f32> = bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+
+        assert_eq!(Uniforms::min_size().get(), 96);
+        assert_eq!(&floats[..4], &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(&floats[4..7], &[5.0, 6.0, 7.0]);
+        assert_eq!(&floats[8..24], uniforms.transform.as_slice());
+
+        let mut read: Matrix4 = Matrix4::zero();
+        let buffer = UniformBuffer::new(&bytes[32..]);
+        buffer.read(&mut read).unwrap();
+        assert_eq!(read, uniforms.transform);
+    }
+}
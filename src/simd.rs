@@ -0,0 +1,461 @@
+//! The hot kernels behind matrix multiplication and batch operations, with vectorized
+//! implementations selected at compile time and a scalar fallback.
+//!
+//! The `portable-simd` feature takes precedence. Otherwise, x86 and x86-64 use SSE2 with the `sse`
+//! feature, AArch64 uses NEON, and WebAssembly uses SIMD128 when built with
+//! `-C target-feature=+simd128`.
+
+use crate::Vector3;
+
+#[cfg(feature = "portable-simd")]
+pub(crate) use portable::*;
+
+#[cfg(all(
+    not(feature = "portable-simd"),
+    feature = "sse",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+pub(crate) use sse::*;
+
+#[cfg(all(
+    not(feature = "portable-simd"),
+    target_arch = "aarch64",
+    target_feature = "neon"
+))]
+pub(crate) use neon::*;
+
+#[cfg(all(
+    not(feature = "portable-simd"),
+    target_arch = "wasm32",
+    target_feature = "simd128"
+))]
+pub(crate) use wasm::*;
+
+#[cfg(not(any(
+    feature = "portable-simd",
+    all(
+        feature = "sse",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+)))]
+pub(crate) use scalar::*;
+
+/// Normalize four vectors given as lanes of coordinates, then apply the NaN policy to each, as
+/// [`Vector3::normalized`] does for the scalar remainder of a batch, so that every vector in a
+/// batch is treated the same.
+fn normalize_lanes(x: [f32; 4], y: [f32; 4], z: [f32; 4]) -> [Vector3; 4] {
+    let [x, y, z] = normalize4(x, y, z);
+    std::array::from_fn(|i| {
+        crate::nan_policy::apply(
+            Vector3::new(x[i], y[i], z[i]),
+            Vector3::is_finite,
+            Vector3::zero,
+            "normalize",
+        )
+    })
+}
+
+/// Normalize each vector to unit length, leaving zero vectors unchanged
+pub(crate) fn normalize_vector3s(vectors: &mut [Vector3]) {
+    let mut chunks = vectors.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        // Normalize four vectors at once, with one lane per vector
+        let normalized = normalize_lanes(
+            std::array::from_fn(|i| chunk[i].x),
+            std::array::from_fn(|i| chunk[i].y),
+            std::array::from_fn(|i| chunk[i].z),
+        );
+        chunk.copy_from_slice(&normalized);
+    }
+
+    for v in chunks.into_remainder() {
+        *v = v.normalized();
+    }
+}
+
+/// Normalize each vector given as separate arrays of coordinates to unit length, leaving zero
+/// vectors unchanged.
+///
+/// # Panics
+///
+/// Panics if the arrays differ in length.
+pub(crate) fn normalize_components(x: &mut [f32], y: &mut [f32], z: &mut [f32]) {
+    assert!(x.len() == y.len() && x.len() == z.len());
+    let len = x.len() - x.len() % 4;
+    let (x, x_remainder) = x.split_at_mut(len);
+    let (y, y_remainder) = y.split_at_mut(len);
+    let (z, z_remainder) = z.split_at_mut(len);
+
+    for ((x, y), z) in x
+        .chunks_exact_mut(4)
+        .zip(y.chunks_exact_mut(4))
+        .zip(z.chunks_exact_mut(4))
+    {
+        let normalized = normalize_lanes(
+            std::array::from_fn(|i| x[i]),
+            std::array::from_fn(|i| y[i]),
+            std::array::from_fn(|i| z[i]),
+        );
+        for (i, v) in normalized.iter().enumerate() {
+            (x[i], y[i], z[i]) = (v.x, v.y, v.z);
+        }
+    }
+
+    for ((x, y), z) in x_remainder
+        .iter_mut()
+        .zip(y_remainder.iter_mut())
+        .zip(z_remainder.iter_mut())
+    {
+        let v = Vector3::new(*x, *y, *z).normalized();
+        (*x, *y, *z) = (v.x, v.y, v.z);
+    }
+}
+
+#[allow(dead_code)]
+mod scalar {
+    use crate::{Matrix4, Vector4};
+
+    /// The product of two matrices, `a * b`
+    pub fn mul_matrix4(a: &Matrix4, b: &Matrix4) -> Matrix4 {
+        let mut result = Matrix4::zero();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                result.0[i][j] = b.0[i][0] * a.0[0][j]
+                    + b.0[i][1] * a.0[1][j]
+                    + b.0[i][2] * a.0[2][j]
+                    + b.0[i][3] * a.0[3][j];
+            }
+        }
+
+        result
+    }
+
+    /// The product of a matrix and a vector, `m * v`
+    pub fn transform_vector4(m: &Matrix4, v: Vector4) -> Vector4 {
+        Vector4::new(
+            m.row(0).dot(v),
+            m.row(1).dot(v),
+            m.row(2).dot(v),
+            m.row(3).dot(v),
+        )
+    }
+
+    /// Normalize four vectors given as lanes of coordinates, leaving zero vectors unchanged
+    pub fn normalize4(x: [f32; 4], y: [f32; 4], z: [f32; 4]) -> [[f32; 4]; 3] {
+        let mut result = [x, y, z];
+        for i in 0..4 {
+            let length_squared = x[i] * x[i] + y[i] * y[i] + z[i] * z[i];
+            if length_squared > 0.0 {
+                let scale = 1.0 / length_squared.sqrt();
+                for lanes in &mut result {
+                    lanes[i] *= scale;
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+mod portable {
+    use crate::{Matrix4, Vector4};
+    use std::simd::{cmp::SimdPartialOrd, f32x4, Select, StdFloat};
+
+    /// A linear combination of the columns of a matrix, weighted by the components of a vector
+    fn combine(columns: &[f32x4; 4], v: Vector4) -> f32x4 {
+        columns[0] * f32x4::splat(v.x)
+            + columns[1] * f32x4::splat(v.y)
+            + columns[2] * f32x4::splat(v.z)
+            + columns[3] * f32x4::splat(v.w)
+    }
+
+    pub fn mul_matrix4(a: &Matrix4, b: &Matrix4) -> Matrix4 {
+        let columns = a.0.map(f32x4::from);
+        Matrix4(b.0.map(|column| Vector4::from(combine(&columns, column))))
+    }
+
+    pub fn transform_vector4(m: &Matrix4, v: Vector4) -> Vector4 {
+        Vector4::from(combine(&m.0.map(f32x4::from), v))
+    }
+
+    pub fn normalize4(x: [f32; 4], y: [f32; 4], z: [f32; 4]) -> [[f32; 4]; 3] {
+        let (x, y, z) = (
+            f32x4::from_array(x),
+            f32x4::from_array(y),
+            f32x4::from_array(z),
+        );
+
+        let length_squared = x * x + y * y + z * z;
+        let one = f32x4::splat(1.0);
+        let scale = length_squared
+            .simd_gt(f32x4::splat(0.0))
+            .select(one / length_squared.sqrt(), one);
+
+        [
+            (x * scale).to_array(),
+            (y * scale).to_array(),
+            (z * scale).to_array(),
+        ]
+    }
+}
+
+#[cfg(all(
+    not(feature = "portable-simd"),
+    feature = "sse",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+// Whether these intrinsics are safe to call depends on the compiler version
+#[allow(unused_unsafe)]
+mod sse {
+    use crate::{Matrix4, Vector4};
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    fn load(v: &Vector4) -> __m128 {
+        // Safety: `Vector4` is `repr(C, align(16))`, holding four contiguous floats
+        unsafe { _mm_load_ps(v.as_array().as_ptr()) }
+    }
+
+    fn store(v: __m128) -> Vector4 {
+        let mut result = Vector4::zero();
+        // Safety: as above
+        unsafe { _mm_store_ps(result.as_array_mut().as_mut_ptr(), v) };
+        result
+    }
+
+    /// A linear combination of the columns of a matrix, weighted by the components of a vector
+    fn combine(columns: &[__m128; 4], v: Vector4) -> __m128 {
+        unsafe {
+            let result = _mm_mul_ps(columns[0], _mm_set1_ps(v.x));
+            let result = _mm_add_ps(result, _mm_mul_ps(columns[1], _mm_set1_ps(v.y)));
+            let result = _mm_add_ps(result, _mm_mul_ps(columns[2], _mm_set1_ps(v.z)));
+            _mm_add_ps(result, _mm_mul_ps(columns[3], _mm_set1_ps(v.w)))
+        }
+    }
+
+    pub fn mul_matrix4(a: &Matrix4, b: &Matrix4) -> Matrix4 {
+        let columns = [load(&a.0[0]), load(&a.0[1]), load(&a.0[2]), load(&a.0[3])];
+        Matrix4(b.0.map(|column| store(combine(&columns, column))))
+    }
+
+    pub fn transform_vector4(m: &Matrix4, v: Vector4) -> Vector4 {
+        let columns = [load(&m.0[0]), load(&m.0[1]), load(&m.0[2]), load(&m.0[3])];
+        store(combine(&columns, v))
+    }
+
+    pub fn normalize4(x: [f32; 4], y: [f32; 4], z: [f32; 4]) -> [[f32; 4]; 3] {
+        let mut result = [[0.0; 4]; 3];
+        // Safety: each array holds four contiguous floats, loaded and stored unaligned
+        unsafe {
+            let (x, y, z) = (
+                _mm_loadu_ps(x.as_ptr()),
+                _mm_loadu_ps(y.as_ptr()),
+                _mm_loadu_ps(z.as_ptr()),
+            );
+
+            let length_squared = _mm_add_ps(
+                _mm_add_ps(_mm_mul_ps(x, x), _mm_mul_ps(y, y)),
+                _mm_mul_ps(z, z),
+            );
+            let one = _mm_set1_ps(1.0);
+            let positive = _mm_cmpgt_ps(length_squared, _mm_setzero_ps());
+            let scale = _mm_or_ps(
+                _mm_and_ps(positive, _mm_div_ps(one, _mm_sqrt_ps(length_squared))),
+                _mm_andnot_ps(positive, one),
+            );
+
+            _mm_storeu_ps(result[0].as_mut_ptr(), _mm_mul_ps(x, scale));
+            _mm_storeu_ps(result[1].as_mut_ptr(), _mm_mul_ps(y, scale));
+            _mm_storeu_ps(result[2].as_mut_ptr(), _mm_mul_ps(z, scale));
+        }
+        result
+    }
+}
+
+#[cfg(all(
+    not(feature = "portable-simd"),
+    target_arch = "aarch64",
+    target_feature = "neon"
+))]
+// Whether these intrinsics are safe to call depends on the compiler version
+#[allow(unused_unsafe)]
+mod neon {
+    use crate::{Matrix4, Vector4};
+    use core::arch::aarch64::*;
+
+    fn load(v: &Vector4) -> float32x4_t {
+        // Safety: `Vector4` is `repr(C)`, holding four contiguous floats
+        unsafe { vld1q_f32(v.as_array().as_ptr()) }
+    }
+
+    fn store(v: float32x4_t) -> Vector4 {
+        let mut result = Vector4::zero();
+        // Safety: as above
+        unsafe { vst1q_f32(result.as_array_mut().as_mut_ptr(), v) };
+        result
+    }
+
+    /// A linear combination of the columns of a matrix, weighted by the components of a vector.
+    /// This multiplies and adds separately rather than fusing them, so that it rounds the same as
+    /// the other backends.
+    fn combine(columns: &[float32x4_t; 4], v: Vector4) -> float32x4_t {
+        unsafe {
+            let result = vmulq_n_f32(columns[0], v.x);
+            let result = vaddq_f32(result, vmulq_n_f32(columns[1], v.y));
+            let result = vaddq_f32(result, vmulq_n_f32(columns[2], v.z));
+            vaddq_f32(result, vmulq_n_f32(columns[3], v.w))
+        }
+    }
+
+    pub fn mul_matrix4(a: &Matrix4, b: &Matrix4) -> Matrix4 {
+        let columns = [load(&a.0[0]), load(&a.0[1]), load(&a.0[2]), load(&a.0[3])];
+        Matrix4(b.0.map(|column| store(combine(&columns, column))))
+    }
+
+    pub fn transform_vector4(m: &Matrix4, v: Vector4) -> Vector4 {
+        let columns = [load(&m.0[0]), load(&m.0[1]), load(&m.0[2]), load(&m.0[3])];
+        store(combine(&columns, v))
+    }
+
+    pub fn normalize4(x: [f32; 4], y: [f32; 4], z: [f32; 4]) -> [[f32; 4]; 3] {
+        let mut result = [[0.0; 4]; 3];
+        // Safety: each array holds four contiguous floats
+        unsafe {
+            let (x, y, z) = (
+                vld1q_f32(x.as_ptr()),
+                vld1q_f32(y.as_ptr()),
+                vld1q_f32(z.as_ptr()),
+            );
+
+            let length_squared =
+                vaddq_f32(vaddq_f32(vmulq_f32(x, x), vmulq_f32(y, y)), vmulq_f32(z, z));
+            let one = vdupq_n_f32(1.0);
+            let scale = vbslq_f32(
+                vcgtq_f32(length_squared, vdupq_n_f32(0.0)),
+                vdivq_f32(one, vsqrtq_f32(length_squared)),
+                one,
+            );
+
+            vst1q_f32(result[0].as_mut_ptr(), vmulq_f32(x, scale));
+            vst1q_f32(result[1].as_mut_ptr(), vmulq_f32(y, scale));
+            vst1q_f32(result[2].as_mut_ptr(), vmulq_f32(z, scale));
+        }
+        result
+    }
+}
+
+#[cfg(all(
+    not(feature = "portable-simd"),
+    target_arch = "wasm32",
+    target_feature = "simd128"
+))]
+mod wasm {
+    use crate::{Matrix4, Vector4};
+    use core::arch::wasm32::*;
+
+    fn load(v: Vector4) -> v128 {
+        f32x4(v.x, v.y, v.z, v.w)
+    }
+
+    fn store(v: v128) -> Vector4 {
+        Vector4::new(
+            f32x4_extract_lane::<0>(v),
+            f32x4_extract_lane::<1>(v),
+            f32x4_extract_lane::<2>(v),
+            f32x4_extract_lane::<3>(v),
+        )
+    }
+
+    /// A linear combination of the columns of a matrix, weighted by the components of a vector
+    fn combine(columns: &[v128; 4], v: Vector4) -> v128 {
+        let result = f32x4_mul(columns[0], f32x4_splat(v.x));
+        let result = f32x4_add(result, f32x4_mul(columns[1], f32x4_splat(v.y)));
+        let result = f32x4_add(result, f32x4_mul(columns[2], f32x4_splat(v.z)));
+        f32x4_add(result, f32x4_mul(columns[3], f32x4_splat(v.w)))
+    }
+
+    pub fn mul_matrix4(a: &Matrix4, b: &Matrix4) -> Matrix4 {
+        let columns = a.0.map(load);
+        Matrix4(b.0.map(|column| store(combine(&columns, column))))
+    }
+
+    pub fn transform_vector4(m: &Matrix4, v: Vector4) -> Vector4 {
+        store(combine(&m.0.map(load), v))
+    }
+
+    pub fn normalize4(x: [f32; 4], y: [f32; 4], z: [f32; 4]) -> [[f32; 4]; 3] {
+        let (x, y, z) = (
+            f32x4(x[0], x[1], x[2], x[3]),
+            f32x4(y[0], y[1], y[2], y[3]),
+            f32x4(z[0], z[1], z[2], z[3]),
+        );
+
+        let length_squared =
+            f32x4_add(f32x4_add(f32x4_mul(x, x), f32x4_mul(y, y)), f32x4_mul(z, z));
+        let one = f32x4_splat(1.0);
+        let scale = v128_bitselect(
+            f32x4_div(one, f32x4_sqrt(length_squared)),
+            one,
+            f32x4_gt(length_squared, f32x4_splat(0.0)),
+        );
+
+        [
+            *store(f32x4_mul(x, scale)).as_array(),
+            *store(f32x4_mul(y, scale)).as_array(),
+            *store(f32x4_mul(z, scale)).as_array(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn kernels() {
+        let a = Matrix4::perspective(1.5, 1.0, 0.1, 100.0)
+            * Matrix4::translation(Vector3::new(1.0, -2.0, 3.0));
+        let b = Matrix4::rotation_axis_angle(Vector3::new(0.0, 0.6, 0.8), 0.7);
+        let v = Vector4::new(1.0, 2.0, -3.0, 1.0);
+
+        assert_nearly_eq!(
+            &super::mul_matrix4(&a, &b),
+            &super::scalar::mul_matrix4(&a, &b),
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            &super::transform_vector4(&a, v),
+            &super::scalar::transform_vector4(&a, v),
+            epsilon = 1e-5
+        );
+
+        let mut vectors: Vec<_> = (0..11)
+            .map(|i| Vector3::new(i as f32 - 5.0, 2.0 * i as f32, 1.0 - i as f32))
+            .collect();
+        vectors[6] = Vector3::zero();
+        let expected: Vec<_> = vectors.iter().map(Vector3::normalized).collect();
+        super::normalize_vector3s(&mut vectors);
+        assert_nearly_eq!(vectors.as_slice(), expected.as_slice(), epsilon = 1e-6);
+        assert_eq!(vectors[6], Vector3::zero());
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "nan-panic", feature = "debug-validate")))]
+    fn non_finite_lanes() {
+        let mut vectors = vec![Vector3::from_scalar(f32::INFINITY); 5];
+        super::normalize_vector3s(&mut vectors);
+
+        // The first four are normalized in lanes, and the last in the scalar remainder
+        for v in &vectors[..4] {
+            assert_eq!(format!("{v:?}"), format!("{:?}", vectors[4]));
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{Matrix4, Point, Quaternion, Vector3, Vector4};
+
+/// The weighted sum of bone matrices for one vertex (linear blend skinning).
+///
+/// Each influence is the index of a bone in `bones`, and its weight. The weights should sum
+/// to one.
+pub fn blend_matrices(bones: &[Matrix4], influences: &[(u16, f32)]) -> Matrix4 {
+    Matrix4(std::array::from_fn(|col| {
+        influences
+            .iter()
+            .map(|&(bone, weight)| bones[bone as usize].0[col] * weight)
+            .sum::<Vector4>()
+    }))
+}
+
+/// A rigid transform represented as a dual quaternion, which blends without the loss of volume
+/// that linear blend skinning suffers around twisting joints.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DualQuaternion {
+    /// The rotation
+    pub real: Quaternion,
+    /// Half the translation, multiplied by the rotation
+    pub dual: Quaternion,
+}
+
+impl DualQuaternion {
+    /// The dual quaternion representing no transformation
+    pub const fn identity() -> Self {
+        Self {
+            real: Quaternion::identity(),
+            dual: Quaternion::new(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// The transform that applies a unit-length rotation, then a translation
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector3) -> Self {
+        let t = Quaternion::new(translation.x, translation.y, translation.z, 0.0);
+        Self {
+            real: rotation,
+            dual: t * rotation * 0.5,
+        }
+    }
+
+    /// The transform described by a matrix, which must contain only rotation and translation
+    pub fn from_matrix(m: &Matrix4) -> Self {
+        Self::from_rotation_translation(Quaternion::from_matrix(m), Vector3::from(m.0[3]))
+    }
+
+    /// The translation part of this transform
+    pub fn translation(&self) -> Vector3 {
+        (self.dual * self.real.conjugate()).vector() * 2.0
+    }
+
+    /// The matrix equivalent to this transform
+    pub fn to_matrix(&self) -> Matrix4 {
+        Matrix4::translation(self.translation()) * self.real.to_matrix()
+    }
+
+    /// This dual quaternion, scaled so that it represents a rigid transform
+    pub fn normalized(&self) -> Self {
+        let scale = 1.0 / self.real.magnitude();
+        Self {
+            real: self.real * scale,
+            dual: self.dual * scale,
+        }
+    }
+
+    /// Transform a point by this dual quaternion, which must be normalized
+    pub fn transform_point(&self, p: Point) -> Point {
+        Point::from(self.real.rotate(Vector3::from(p)) + self.translation())
+    }
+
+    /// Transform a vector by this dual quaternion, which must be normalized
+    pub fn transform_vector(&self, v: Vector3) -> Vector3 {
+        self.real.rotate(v)
+    }
+}
+
+impl Default for DualQuaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// The weighted blend of bone transforms for one vertex (dual quaternion skinning).
+///
+/// Each influence is the index of a bone in `bones`, and its weight. Since `q` and `-q`
+/// represent the same rotation, each bone is first flipped into the same hemisphere as the
+/// first, so that the blend takes the shortest path. The result is normalized.
+pub fn blend_dual_quaternions(
+    bones: &[DualQuaternion],
+    influences: &[(u16, f32)],
+) -> DualQuaternion {
+    let Some(&(first, _)) = influences.first() else {
+        return DualQuaternion::identity();
+    };
+    let pivot = bones[first as usize].real;
+
+    let mut blend = DualQuaternion {
+        real: Quaternion::new(0.0, 0.0, 0.0, 0.0),
+        dual: Quaternion::new(0.0, 0.0, 0.0, 0.0),
+    };
+    for &(bone, weight) in influences {
+        let bone = &bones[bone as usize];
+        let weight = if bone.real.dot(pivot) < 0.0 {
+            -weight
+        } else {
+            weight
+        };
+        blend.real = blend.real + bone.real * weight;
+        blend.dual = blend.dual + bone.dual * weight;
+    }
+
+    blend.normalized()
+}
+
+/// Skin a batch of vertices with linear blend skinning, writing the results to the output
+/// slices.
+///
+/// Each vertex is influenced by up to four bones from `palette`; unused influences should have
+/// a weight of zero. Normals are transformed without translation, and renormalized. Pass empty
+/// normal slices to skin only positions.
+///
+/// # Panics
+///
+/// Panics if the slices of positions, influences and output positions differ in length, or
+/// the normal slices are not all either empty or the same length.
+pub fn skin_vertices(
+    palette: &[Matrix4],
+    influences: &[[(u16, f32); 4]],
+    positions: &[Point],
+    normals: &[Vector3],
+    out_positions: &mut [Point],
+    out_normals: &mut [Vector3],
+) {
+    assert_eq!(positions.len(), influences.len());
+    assert_eq!(positions.len(), out_positions.len());
+    assert_eq!(normals.len(), out_normals.len());
+    assert!(normals.is_empty() || normals.len() == positions.len());
+
+    for (i, influences) in influences.iter().enumerate() {
+        let m = blend_matrices(palette, influences);
+        out_positions[i] = m * positions[i];
+        if let Some(normal) = normals.get(i) {
+            out_normals[i] = (m * *normal).normalized();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn blend() {
+        let bones = [
+            Matrix4::identity(),
+            Matrix4::translation(Vector3::new(0.0, 2.0, 0.0)) * Matrix4::rotation_z(1.0),
+        ];
+
+        assert_eq!(blend_matrices(&bones, &[(1, 1.0)]), bones[1]);
+        let half = blend_matrices(&bones, &[(0, 0.5), (1, 0.5)]);
+        assert_nearly_eq!(
+            &(half * Point::ORIGIN),
+            &Point::new(0.0, 1.0, 0.0),
+            epsilon = 1e-6
+        );
+
+        let dual: Vec<_> = bones.iter().map(DualQuaternion::from_matrix).collect();
+        assert_nearly_eq!(&dual[1].to_matrix(), &bones[1], epsilon = 1e-6);
+        assert_nearly_eq!(
+            &blend_dual_quaternions(&dual, &[(1, 1.0)]).to_matrix(),
+            &bones[1],
+            epsilon = 1e-6
+        );
+
+        // Blending halfway keeps the point at unit distance from the axis, unlike linear
+        // blending which shrinks it towards the axis
+        let p = Point::new(1.0, 0.0, 0.0);
+        let rotations = [
+            DualQuaternion::identity(),
+            DualQuaternion::from_matrix(&Matrix4::rotation_z(std::f32::consts::FRAC_PI_2)),
+        ];
+        let blended = blend_dual_quaternions(&rotations, &[(0, 0.5), (1, 0.5)]);
+        assert_nearly_eq!(
+            Vector3::from(blended.transform_point(p)).magnitude(),
+            1.0,
+            epsilon = 1e-6
+        );
+        let linear = blend_matrices(
+            &[Matrix4::identity(), rotations[1].to_matrix()],
+            &[(0, 0.5), (1, 0.5)],
+        );
+        assert!(Vector3::from(linear * p).magnitude() < 0.75);
+
+        // Flipping the sign of a bone does not change the blend
+        let flipped = [
+            rotations[0],
+            DualQuaternion {
+                real: -rotations[1].real,
+                dual: -rotations[1].dual,
+            },
+        ];
+        assert_nearly_eq!(
+            &blend_dual_quaternions(&flipped, &[(0, 0.5), (1, 0.5)]).transform_point(p),
+            &blended.transform_point(p),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn skin() {
+        let palette = [
+            Matrix4::identity(),
+            Matrix4::translation(Vector3::new(0.0, 0.0, 4.0)),
+        ];
+        let influences = [
+            [(0, 1.0), (0, 0.0), (0, 0.0), (0, 0.0)],
+            [(0, 0.25), (1, 0.75), (0, 0.0), (0, 0.0)],
+        ];
+        let positions = [Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)];
+        let normals = [Vector3::UNIT_X, Vector3::UNIT_Y];
+
+        let mut out_positions = [Point::ORIGIN; 2];
+        let mut out_normals = [Vector3::zero(); 2];
+        skin_vertices(
+            &palette,
+            &influences,
+            &positions,
+            &normals,
+            &mut out_positions,
+            &mut out_normals,
+        );
+
+        assert_eq!(out_positions, [positions[0], Point::new(0.0, 1.0, 3.0)]);
+        assert_eq!(out_normals, normals);
+
+        skin_vertices(
+            &palette,
+            &influences,
+            &positions,
+            &[],
+            &mut out_positions,
+            &mut [],
+        );
+        assert_eq!(out_positions[1], Point::new(0.0, 1.0, 3.0));
+    }
+}
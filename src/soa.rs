@@ -0,0 +1,238 @@
+use crate::Vector3;
+
+/// Many 3D vectors stored as structure-of-arrays, with one array per coordinate.
+///
+/// The bulk operations loop over the arrays independently, which compilers vectorize far
+/// better than the interleaved coordinates of a `[Vector3]`. This suits particle systems and
+/// other code applying the same operation to many vectors.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Vector3xN {
+    x: Vec<f32>,
+    y: Vec<f32>,
+    z: Vec<f32>,
+}
+
+impl Vector3xN {
+    /// Construct an empty collection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct an empty collection, with room for `capacity` vectors without reallocating
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+            z: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Construct a collection of `len` copies of a vector
+    pub fn from_elem(v: Vector3, len: usize) -> Self {
+        Self {
+            x: vec![v.x; len],
+            y: vec![v.y; len],
+            z: vec![v.z; len],
+        }
+    }
+
+    /// The number of vectors
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Whether there are no vectors
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Append a vector
+    pub fn push(&mut self, v: Vector3) {
+        self.x.push(v.x);
+        self.y.push(v.y);
+        self.z.push(v.z);
+    }
+
+    /// Remove every vector, keeping the allocated capacity
+    pub fn clear(&mut self) {
+        self.x.clear();
+        self.y.clear();
+        self.z.clear();
+    }
+
+    /// The vector at an index, or `None` if it is out of bounds
+    pub fn get(&self, i: usize) -> Option<Vector3> {
+        Some(Vector3::new(*self.x.get(i)?, self.y[i], self.z[i]))
+    }
+
+    /// Replace the vector at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn set(&mut self, i: usize, v: Vector3) {
+        self.x[i] = v.x;
+        self.y[i] = v.y;
+        self.z[i] = v.z;
+    }
+
+    /// The vectors, in order
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Vector3> + '_ {
+        (0..self.len()).map(|i| Vector3::new(self.x[i], self.y[i], self.z[i]))
+    }
+
+    /// The arrays of x, y and z coordinates
+    pub fn components(&self) -> [&[f32]; 3] {
+        [&self.x, &self.y, &self.z]
+    }
+
+    /// The arrays of x, y and z coordinates, for modifying in place. Their lengths cannot be
+    /// changed, so they always match.
+    pub fn components_mut(&mut self) -> [&mut [f32]; 3] {
+        [&mut self.x, &mut self.y, &mut self.z]
+    }
+
+    /// Add the corresponding vector in `rhs` to each vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collections differ in length.
+    pub fn add(&mut self, rhs: &Vector3xN) {
+        self.add_scaled(rhs, 1.0);
+    }
+
+    /// Add the corresponding vector in `rhs`, multiplied by `scale`, to each vector, as when
+    /// integrating positions from velocities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collections differ in length.
+    pub fn add_scaled(&mut self, rhs: &Vector3xN, scale: f32) {
+        assert_eq!(self.len(), rhs.len());
+        for (a, b) in self.components_mut().into_iter().zip(rhs.components()) {
+            for (a, b) in a.iter_mut().zip(b) {
+                *a += b * scale;
+            }
+        }
+    }
+
+    /// Add the same vector to every vector
+    pub fn translate(&mut self, offset: Vector3) {
+        for (a, b) in self
+            .components_mut()
+            .into_iter()
+            .zip([offset.x, offset.y, offset.z])
+        {
+            for a in a {
+                *a += b;
+            }
+        }
+    }
+
+    /// Multiply every vector by a scalar
+    pub fn scale(&mut self, s: f32) {
+        for a in self.components_mut() {
+            for a in a {
+                *a *= s;
+            }
+        }
+    }
+
+    /// Write the dot product of each vector and the corresponding vector in `rhs` to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collections and `out` differ in length.
+    pub fn dot(&self, rhs: &Vector3xN, out: &mut [f32]) {
+        assert_eq!(self.len(), rhs.len());
+        assert_eq!(self.len(), out.len());
+        out.fill(0.0);
+        for (a, b) in self.components().into_iter().zip(rhs.components()) {
+            for ((out, a), b) in out.iter_mut().zip(a).zip(b) {
+                *out += a * b;
+            }
+        }
+    }
+
+    /// Normalize every vector to unit length, leaving zero vectors unchanged, as
+    /// [`Vector3::normalized`] does
+    pub fn normalize(&mut self) {
+        crate::simd::normalize_components(&mut self.x, &mut self.y, &mut self.z);
+    }
+}
+
+impl FromIterator<Vector3> for Vector3xN {
+    fn from_iter<I: IntoIterator<Item = Vector3>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut result = Self::with_capacity(iter.size_hint().0);
+        for v in iter {
+            result.push(v);
+        }
+        result
+    }
+}
+
+impl From<&[Vector3]> for Vector3xN {
+    fn from(vectors: &[Vector3]) -> Self {
+        vectors.iter().copied().collect()
+    }
+}
+
+impl Extend<Vector3> for Vector3xN {
+    fn extend<I: IntoIterator<Item = Vector3>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn bulk() {
+        let vectors: Vec<_> = (0..19)
+            .map(|i| Vector3::new(i as f32, 1.0 - i as f32, (i % 3) as f32))
+            .collect();
+        let mut positions = Vector3xN::from(vectors.as_slice());
+        assert_eq!(positions.len(), 19);
+        assert_eq!(positions.get(4), Some(vectors[4]));
+        assert_eq!(positions.get(19), None);
+        assert!(positions.iter().eq(vectors.iter().copied()));
+
+        let velocities = Vector3xN::from_elem(Vector3::new(0.0, 2.0, 0.0), 19);
+        positions.add_scaled(&velocities, 0.5);
+        positions.translate(Vector3::new(1.0, 0.0, 0.0));
+        positions.scale(2.0);
+        for (p, v) in positions.iter().zip(&vectors) {
+            assert_eq!(p, (*v + Vector3::new(1.0, 1.0, 0.0)) * 2.0);
+        }
+
+        let mut dots = vec![0.0; 19];
+        positions.dot(&velocities, &mut dots);
+        assert_eq!(
+            dots[3],
+            positions.get(3).unwrap().dot(velocities.get(3).unwrap())
+        );
+
+        positions.set(0, Vector3::zero());
+        positions.normalize();
+        assert_eq!(positions.get(0), Some(Vector3::zero()));
+        for (p, v) in positions.iter().zip(&vectors).skip(1) {
+            assert_nearly_eq!(
+                &p,
+                &((*v + Vector3::new(1.0, 1.0, 0.0)) * 2.0).normalized(),
+                epsilon = 1e-6
+            );
+        }
+
+        let mut doubled = positions.clone();
+        doubled.add(&positions);
+        doubled.extend([Vector3::UNIT_X]);
+        assert_eq!(doubled.len(), 20);
+        assert_eq!(doubled.components()[0][19], 1.0);
+        doubled.clear();
+        assert!(doubled.is_empty());
+    }
+}
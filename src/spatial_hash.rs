@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use crate::{Aabb, Grid, Point};
+
+/// A uniform grid of cells over 3D space, storing values at points, for finding neighbors
+/// without testing every pair.
+///
+/// Only occupied cells are stored, so the grid is unbounded. Cells should be around the size
+/// of a typical query radius.
+#[derive(Clone, Debug)]
+pub struct SpatialHash<T> {
+    grid: Grid,
+    cells: HashMap<[i32; 3], Vec<(Point, T)>>,
+    len: usize,
+}
+
+impl<T> SpatialHash<T> {
+    /// Construct an empty grid, with cubic cells of the given size
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            grid: Grid::uniform(cell_size),
+            cells: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// The coordinates of the cell containing a point
+    pub fn cell(&self, point: Point) -> [i32; 3] {
+        self.grid.cell(point)
+    }
+
+    /// Insert a value at the given point
+    pub fn insert(&mut self, point: Point, value: T) {
+        self.cells
+            .entry(self.cell(point))
+            .or_default()
+            .push((point, value));
+        self.len += 1;
+    }
+
+    /// Remove every value, keeping the allocated cells for reuse
+    pub fn clear(&mut self) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        self.len = 0;
+    }
+
+    /// The number of values in the grid
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the grid contains no values
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Call `visit` with every value whose point lies within `radius` of `center`
+    pub fn query_radius(&self, center: Point, radius: f32, mut visit: impl FnMut(Point, &T)) {
+        let bounds = Aabb::new(center - radius, center + radius);
+        self.query_aabb(&bounds, |p, value| {
+            if (p - center).magnitude_squared() <= radius * radius {
+                visit(p, value);
+            }
+        });
+    }
+
+    /// Call `visit` with every value whose point lies within the box
+    pub fn query_aabb(&self, aabb: &Aabb, mut visit: impl FnMut(Point, &T)) {
+        let (lo, hi) = (self.cell(aabb.min), self.cell(aabb.max));
+
+        for x in lo[0]..=hi[0] {
+            for y in lo[1]..=hi[1] {
+                for z in lo[2]..=hi[2] {
+                    let Some(cell) = self.cells.get(&[x, y, z]) else {
+                        continue;
+                    };
+                    for (p, value) in cell {
+                        if aabb.contains(*p) {
+                            visit(*p, value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn neighbors() {
+        let mut grid = SpatialHash::new(1.0);
+        for i in 0..10 {
+            grid.insert(Point::new(i as f32 * 0.5 - 2.0, 0.0, 0.0), i);
+        }
+        assert_eq!(grid.len(), 10);
+        assert_eq!(grid.cell(Point::new(-0.5, 1.5, 2.0)), [-1, 1, 2]);
+
+        let mut found = vec![];
+        grid.query_radius(Point::new(0.0, 0.2, 0.0), 0.6, |_, &i| found.push(i));
+        found.sort();
+        assert_eq!(found, vec![3, 4, 5]);
+
+        let mut found = vec![];
+        let aabb = Aabb::new(Point::new(-2.0, -1.0, -1.0), Point::new(-1.0, 1.0, 1.0));
+        grid.query_aabb(&aabb, |_, &i| found.push(i));
+        found.sort();
+        assert_eq!(found, vec![0, 1, 2]);
+
+        grid.clear();
+        assert!(grid.is_empty());
+        grid.query_radius(Point::ORIGIN, 10.0, |_, _| panic!("grid should be empty"));
+    }
+}
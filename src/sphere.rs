@@ -0,0 +1,68 @@
+use crate::{first_root, Point, Ray};
+
+/// A sphere in 3D space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Sphere {
+    pub center: Point,
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// Construct a sphere from its center and radius
+    pub const fn new(center: Point, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Whether a point lies within this sphere, including its surface
+    pub fn contains(&self, point: Point) -> bool {
+        (point - self.center).magnitude_squared() <= self.radius * self.radius
+    }
+
+    /// Whether this sphere overlaps another
+    pub fn intersects(&self, other: &Sphere) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).magnitude_squared() <= r * r
+    }
+
+    /// The distance along a ray at which it first touches this sphere, in multiples of the
+    /// ray's direction. Returns zero if the ray starts inside the sphere, and `None` if it misses.
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        let m = ray.origin - self.center;
+        let c = m.magnitude_squared() - self.radius * self.radius;
+        if c <= 0.0 {
+            return Some(0.0);
+        }
+
+        first_root(ray.direction.magnitude_squared(), m.dot(ray.direction), c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn ray() {
+        let sphere = Sphere::new(Point::new(0.0, 0.0, -5.0), 1.0);
+
+        assert_eq!(
+            sphere.intersect_ray(&Ray::new(Point::ORIGIN, Vector3::FORWARD)),
+            Some(4.0)
+        );
+        assert_eq!(
+            sphere.intersect_ray(&Ray::new(Point::ORIGIN, Vector3::BACK)),
+            None
+        );
+        assert_eq!(
+            sphere.intersect_ray(&Ray::new(Point::new(0.0, 2.0, 0.0), Vector3::FORWARD)),
+            None
+        );
+        assert_eq!(
+            sphere.intersect_ray(&Ray::new(sphere.center, Vector3::UP)),
+            Some(0.0)
+        );
+        assert!(sphere.intersects(&Sphere::new(Point::new(0.0, 1.5, -5.0), 0.5)));
+        assert!(!sphere.contains(Point::ORIGIN));
+    }
+}
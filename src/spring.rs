@@ -0,0 +1,81 @@
+use crate::VectorSpace;
+
+/// A damped spring pulling a value towards a target, for smoothing camera motion, snapping
+/// UI elements into place, and procedural secondary motion.
+///
+/// Works with `f32` and with any of the vector types.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Spring<T> {
+    pub value: T,
+    pub velocity: T,
+    pub target: T,
+    /// The acceleration per unit of distance from the target
+    pub stiffness: f32,
+    /// The deceleration per unit of velocity
+    pub damping: f32,
+}
+
+impl<T: VectorSpace> Spring<T> {
+    /// Construct a spring at rest at the given value
+    pub fn new(value: T, stiffness: f32, damping: f32) -> Self {
+        Self {
+            value,
+            velocity: T::zero(),
+            target: value,
+            stiffness,
+            damping,
+        }
+    }
+
+    /// Construct a spring at rest at the given value, damped just enough to reach its target
+    /// as fast as possible without overshooting
+    pub fn critically_damped(value: T, stiffness: f32) -> Self {
+        Self::new(value, stiffness, 2.0 * stiffness.sqrt())
+    }
+
+    /// Advance the spring by a time step, returning the new value.
+    ///
+    /// This uses implicit Euler integration, which remains stable for any time step, so
+    /// long or uneven frames will not make the spring explode.
+    pub fn step(&mut self, dt: f32) -> T {
+        let pull = (self.target - self.value) * (dt * self.stiffness);
+        self.velocity =
+            (self.velocity + pull) * (1.0 / (1.0 + dt * self.damping + dt * dt * self.stiffness));
+        self.value = self.value + self.velocity * dt;
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn spring() {
+        let mut spring = Spring::critically_damped(0.0, 100.0);
+        spring.target = 1.0;
+
+        let mut previous = 0.0;
+        for _ in 0..120 {
+            let value = spring.step(1.0 / 60.0);
+            assert!(value >= previous && value <= 1.0);
+            previous = value;
+        }
+        assert_nearly_eq!(previous, 1.0, epsilon = 1e-3);
+
+        // An underdamped spring overshoots
+        let mut spring = Spring::new(Vector3::zero(), 100.0, 2.0);
+        spring.target = Vector3::one();
+        let peak = (0..60)
+            .map(|_| spring.step(1.0 / 60.0).x)
+            .fold(0.0, f32::max);
+        assert!(peak > 1.2);
+
+        // A huge time step does not explode
+        let mut spring = Spring::new(Vector3::zero(), 1000.0, 1.0);
+        spring.target = Vector3::one();
+        for _ in 0..10 {
+            assert!(spring.step(10.0).magnitude() < 2.0);
+        }
+    }
+}
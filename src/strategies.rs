@@ -0,0 +1,87 @@
+//! [proptest](https://docs.rs/proptest) strategies for generating vectors and matrices.
+
+use crate::{Matrix4, Point, Vector2, Vector3, Vector4};
+use proptest::prelude::*;
+use std::f32::consts::PI;
+use std::ops::Range;
+
+/// Vectors whose coordinates are each drawn from the given range.
+pub fn vector2(range: Range<f32>) -> impl Strategy<Value = Vector2> {
+    (range.clone(), range).prop_map(|(x, y)| Vector2::new(x, y))
+}
+
+/// Vectors whose coordinates are each drawn from the given range.
+pub fn vector3(range: Range<f32>) -> impl Strategy<Value = Vector3> {
+    (range.clone(), range.clone(), range).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+/// Points whose coordinates are each drawn from the given range.
+pub fn point(range: Range<f32>) -> impl Strategy<Value = Point> {
+    vector3(range).prop_map(Point::from)
+}
+
+/// Vectors whose coordinates are each drawn from the given range.
+pub fn vector4(range: Range<f32>) -> impl Strategy<Value = Vector4> {
+    (range.clone(), range.clone(), range.clone(), range)
+        .prop_map(|(x, y, z, w)| Vector4::new(x, y, z, w))
+}
+
+/// Vectors of unit length, uniformly distributed over the sphere.
+pub fn unit_vector3() -> impl Strategy<Value = Vector3> {
+    (-1.0f32..=1.0, 0.0f32..2.0 * PI).prop_map(|(z, phi)| {
+        let r = (1.0 - z * z).max(0.0).sqrt();
+        Vector3::new(r * phi.cos(), r * phi.sin(), z)
+    })
+}
+
+/// Orthonormal rotation matrices (no translation, scale or reflection).
+pub fn rotation() -> impl Strategy<Value = Matrix4> {
+    (unit_vector3(), -PI..PI).prop_map(|(axis, angle)| Matrix4::rotation_axis_angle(axis, angle))
+}
+
+/// Affine matrices that are guaranteed to be invertible.
+///
+/// These are composed from a translation, a rotation, and a (possibly mirrored)
+/// non-uniform scale whose magnitude is kept away from zero.
+pub fn invertible_matrix() -> impl Strategy<Value = Matrix4> {
+    let scale = (0.1f32..10.0, any::<bool>()).prop_map(|(s, flip)| if flip { -s } else { s });
+
+    (
+        vector3(-100.0..100.0),
+        rotation(),
+        [scale.clone(), scale.clone(), scale],
+    )
+        .prop_map(|(t, r, [sx, sy, sz])| {
+            let s = Matrix4::from_2d_array([
+                [sx, 0.0, 0.0, 0.0],
+                [0.0, sy, 0.0, 0.0],
+                [0.0, 0.0, sz, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ]);
+            Matrix4::translation(t) * r * s
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn unit_vectors_have_unit_length(v in unit_vector3()) {
+            prop_assert!((v.magnitude() - 1.0).abs() < 1e-5);
+        }
+
+        #[test]
+        fn rotations_preserve_length(r in rotation(), v in vector3(-100.0..100.0)) {
+            let rotated = r * v;
+            prop_assert!((rotated.magnitude() - v.magnitude()).abs() < 1e-3);
+        }
+
+        #[test]
+        fn invertible_matrices_round_trip(m in invertible_matrix(), p in point(-10.0..10.0)) {
+            let q = m.invert() * (m * p);
+            prop_assert!((q - p).magnitude() < 1e-2);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+/// A running sum that compensates for floating-point rounding error.
+///
+/// This uses the Kahan-Babuška (Neumaier) algorithm, which keeps the error of summing
+/// `n` values independent of `n`, at the cost of a few extra additions per value.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CompensatedSum {
+    sum: f32,
+    compensation: f32,
+}
+
+impl CompensatedSum {
+    /// An empty sum.
+    pub const fn new() -> Self {
+        Self {
+            sum: 0.0,
+            compensation: 0.0,
+        }
+    }
+
+    /// Accumulate a value into the sum
+    pub fn add(&mut self, value: f32) {
+        let (sum, error) = two_sum(self.sum, value);
+        self.sum = sum;
+        self.compensation += error;
+    }
+
+    /// The compensated total of all values accumulated so far
+    pub fn value(&self) -> f32 {
+        self.sum + self.compensation
+    }
+}
+
+impl Extend<f32> for CompensatedSum {
+    fn extend<I: IntoIterator<Item = f32>>(&mut self, iter: I) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+impl FromIterator<f32> for CompensatedSum {
+    fn from_iter<I: IntoIterator<Item = f32>>(iter: I) -> Self {
+        let mut sum = Self::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+/// Sum a sequence of values using compensated summation. See [`CompensatedSum`].
+pub fn sum_precise<I: IntoIterator<Item = f32>>(values: I) -> f32 {
+    values.into_iter().collect::<CompensatedSum>().value()
+}
+
+/// Compute `a + b`, along with the rounding error of that addition.
+pub(crate) fn two_sum(a: f32, b: f32) -> (f32, f32) {
+    let sum = a + b;
+    let error = if a.abs() >= b.abs() {
+        (a - sum) + b
+    } else {
+        (b - sum) + a
+    };
+    (sum, error)
+}
+
+/// Compute the dot product of two sequences, compensating for rounding error in both the
+/// products and the sum (the "Dot2" algorithm of Ogita, Rump, and Oishi).
+pub(crate) fn dot_precise(products: impl IntoIterator<Item = (f32, f32)>) -> f32 {
+    let mut sum = CompensatedSum::new();
+    for (a, b) in products {
+        let p = a * b;
+        sum.add(p);
+        sum.compensation += a.mul_add(b, -p);
+    }
+    sum.value()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn summation() {
+        let values = std::iter::once(1.0e8).chain(std::iter::repeat_n(1.0, 10_000));
+
+        assert_eq!(values.clone().sum::<f32>(), 1.0e8);
+        assert_eq!(sum_precise(values), 1.0e8 + 10_000.0);
+        assert_eq!(sum_precise([1.0e20, 1.0, -1.0e20]), 1.0);
+    }
+
+    #[test]
+    fn dot() {
+        let a = Vector3::new(1.0e8, 1.0, -1.0e8);
+        let b = Vector3::new(1.0, 1.0, 1.0);
+
+        assert_eq!(a.dot(b), 0.0);
+        assert_eq!(a.dot_precise(b), 1.0);
+    }
+
+    #[test]
+    fn centroid() {
+        let points: Vec<_> = (0..100_000)
+            .map(|i| Point::new(1000.0 + (i % 2) as f32 * 0.1, -1000.0, 0.3))
+            .collect();
+
+        assert_nearly_eq!(
+            &Point::centroid(&points).unwrap(),
+            &Point::new(1000.05, -1000.0, 0.3),
+            epsilon = 1e-4
+        );
+        assert_eq!(Point::centroid(&[]), None);
+    }
+}
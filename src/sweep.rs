@@ -0,0 +1,170 @@
+//! Continuous collision tests for spheres moving in a straight line, which catch fast-moving
+//! objects that would pass through thin geometry between discrete steps.
+
+use crate::{Capsule, Plane, Point, Ray, Sphere, Vector3};
+
+/// The first contact made by a moving object.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Contact {
+    /// The fraction of the motion completed at the moment of contact, in the range `[0, 1]`.
+    pub time: f32,
+    /// The unit-length surface normal at the point of contact, facing the moving object.
+    pub normal: Vector3,
+}
+
+impl Sphere {
+    /// The first contact between this sphere, moving by `motion`, and a plane (approaching from
+    /// either side). The plane's normal must be unit length.
+    ///
+    /// Returns `None` if the sphere doesn't reach the plane, and a time of zero if it already
+    /// touches the plane.
+    pub fn sweep_plane(&self, motion: Vector3, plane: &Plane) -> Option<Contact> {
+        let distance = plane.signed_distance(self.center);
+        let normal = if distance < 0.0 {
+            -plane.normal
+        } else {
+            plane.normal
+        };
+        let distance = distance.abs();
+
+        if distance <= self.radius {
+            return Some(Contact { time: 0.0, normal });
+        }
+
+        let approach = -normal.dot(motion);
+        if approach <= 0.0 {
+            return None;
+        }
+
+        let time = (distance - self.radius) / approach;
+        (time <= 1.0).then_some(Contact { time, normal })
+    }
+
+    /// The first contact between this sphere, moving by `motion`, and another, stationary, sphere.
+    pub fn sweep_sphere(&self, motion: Vector3, other: &Sphere) -> Option<Contact> {
+        let inflated = Sphere::new(other.center, self.radius + other.radius);
+        let time = inflated.intersect_ray(&Ray::new(self.center, motion))?;
+        if time > 1.0 {
+            return None;
+        }
+
+        let normal = (self.center + motion * time - other.center).normalized();
+        Some(Contact { time, normal })
+    }
+
+    /// The first contact between this sphere, moving by `motion`, and the triangle `abc`, which
+    /// may be hit from either side.
+    pub fn sweep_triangle(&self, motion: Vector3, a: Point, b: Point, c: Point) -> Option<Contact> {
+        let plane = Plane::from_points(a, b, c);
+        let ray = Ray::new(self.center, motion);
+
+        // The sphere either first touches the face of the triangle, or one of its edges or corners
+        let face = self.sweep_plane(motion, &plane).filter(|contact| {
+            let center = ray.at(contact.time);
+            let touching = center - contact.normal * plane.signed_distance(center).abs();
+            inside_triangle(touching, a, b, c, plane.normal)
+        });
+
+        let edge = [(a, b), (b, c), (c, a)]
+            .into_iter()
+            .filter_map(|(p, q)| {
+                let capsule = Capsule::new(p, q, self.radius);
+                let time = capsule.intersect_ray(&ray)?;
+                let center = ray.at(time);
+                let normal = (center - capsule.closest_point_on_segment(center)).normalized();
+                Some(Contact { time, normal })
+            })
+            .min_by(|x, y| x.time.total_cmp(&y.time));
+
+        face.into_iter()
+            .chain(edge)
+            .filter(|contact| contact.time <= 1.0)
+            .min_by(|x, y| x.time.total_cmp(&y.time))
+    }
+}
+
+/// Whether a point in the plane of the triangle `abc`, with the given normal, lies inside it
+fn inside_triangle(p: Point, a: Point, b: Point, c: Point, normal: Vector3) -> bool {
+    [(a, b), (b, c), (c, a)]
+        .into_iter()
+        .all(|(start, end)| (end - start).cross(p - start).dot(normal) >= 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn plane() {
+        let ground = Plane::from_point_normal(Point::ORIGIN, Vector3::UP);
+        let sphere = Sphere::new(Point::new(0.0, 5.0, 0.0), 1.0);
+
+        let contact = sphere
+            .sweep_plane(Vector3::new(0.0, -8.0, 0.0), &ground)
+            .unwrap();
+        assert_eq!(contact.time, 0.5);
+        assert_eq!(contact.normal, Vector3::UP);
+
+        assert_eq!(
+            sphere.sweep_plane(Vector3::new(0.0, -2.0, 0.0), &ground),
+            None
+        );
+        assert_eq!(
+            sphere.sweep_plane(Vector3::new(0.0, 8.0, 0.0), &ground),
+            None
+        );
+
+        let below = Sphere::new(Point::new(0.0, -5.0, 0.0), 1.0);
+        let contact = below
+            .sweep_plane(Vector3::new(0.0, 8.0, 0.0), &ground)
+            .unwrap();
+        assert_eq!(contact.normal, Vector3::DOWN);
+    }
+
+    #[test]
+    fn sphere() {
+        let bullet = Sphere::new(Point::ORIGIN, 0.5);
+        let target = Sphere::new(Point::new(10.0, 0.0, 0.0), 1.5);
+
+        let contact = bullet
+            .sweep_sphere(Vector3::new(20.0, 0.0, 0.0), &target)
+            .unwrap();
+        assert_eq!(contact.time, 0.4);
+        assert_nearly_eq!(&contact.normal, &-Vector3::UNIT_X, epsilon = 1e-6);
+        assert_eq!(
+            bullet.sweep_sphere(Vector3::new(5.0, 0.0, 0.0), &target),
+            None
+        );
+    }
+
+    #[test]
+    fn triangle() {
+        // A thin sheet of paper, which a fast bullet would skip over between frames
+        let (a, b, c) = (
+            Point::new(0.0, -1.0, -1.0),
+            Point::new(0.0, 1.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+        let bullet = Sphere::new(Point::new(-10.0, 0.0, 0.0), 0.1);
+
+        let contact = bullet
+            .sweep_triangle(Vector3::new(20.0, 0.0, 0.0), a, b, c)
+            .unwrap();
+        assert_nearly_eq!(contact.time, 0.495, epsilon = 1e-5);
+        assert_nearly_eq!(contact.normal.dot(-Vector3::UNIT_X), 1.0, epsilon = 1e-5);
+
+        // Grazing the edge between a and b
+        let grazing = Sphere::new(Point::new(-10.0, 0.0, -1.05), 0.1);
+        let contact = grazing
+            .sweep_triangle(Vector3::new(20.0, 0.0, 0.0), a, b, c)
+            .unwrap();
+        assert!(contact.time > 0.495 && contact.time < 0.5);
+        assert!(contact.normal.z < 0.0);
+
+        let miss = Sphere::new(Point::new(-10.0, 3.0, 0.0), 0.1);
+        assert_eq!(
+            miss.sweep_triangle(Vector3::new(20.0, 0.0, 0.0), a, b, c),
+            None
+        );
+    }
+}
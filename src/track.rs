@@ -0,0 +1,286 @@
+use crate::{Quaternion, Vector3};
+
+/// Values which can be blended between keyframes
+pub trait Interpolate: Copy {
+    /// The value a fraction `factor` of the way from `self` to `rhs`
+    fn interpolate(&self, rhs: &Self, factor: f32) -> Self;
+
+    /// The value a fraction `factor` of the way along a cubic Hermite spline from `self` to
+    /// `rhs`, leaving `self` with tangent `out_tangent` and arriving at `rhs` with tangent
+    /// `in_tangent`. The tangents are per unit time, and keyframes are `duration` apart.
+    fn cubic_spline(
+        &self,
+        out_tangent: &Self,
+        rhs: &Self,
+        in_tangent: &Self,
+        factor: f32,
+        duration: f32,
+    ) -> Self;
+}
+
+/// The weights of the start value, out-tangent, end value and in-tangent in the cubic Hermite
+/// spline used by glTF
+fn hermite_weights(t: f32, duration: f32) -> [f32; 4] {
+    let (t2, t3) = (t * t, t * t * t);
+    [
+        2.0 * t3 - 3.0 * t2 + 1.0,
+        duration * (t3 - 2.0 * t2 + t),
+        -2.0 * t3 + 3.0 * t2,
+        duration * (t3 - t2),
+    ]
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, rhs: &Self, factor: f32) -> Self {
+        self + (rhs - self) * factor
+    }
+
+    fn cubic_spline(
+        &self,
+        out_tangent: &Self,
+        rhs: &Self,
+        in_tangent: &Self,
+        factor: f32,
+        duration: f32,
+    ) -> Self {
+        let [a, b, c, d] = hermite_weights(factor, duration);
+        self * a + out_tangent * b + rhs * c + in_tangent * d
+    }
+}
+
+impl Interpolate for Vector3 {
+    fn interpolate(&self, rhs: &Self, factor: f32) -> Self {
+        self.lerp(*rhs, factor)
+    }
+
+    fn cubic_spline(
+        &self,
+        out_tangent: &Self,
+        rhs: &Self,
+        in_tangent: &Self,
+        factor: f32,
+        duration: f32,
+    ) -> Self {
+        let [a, b, c, d] = hermite_weights(factor, duration);
+        *self * a + *out_tangent * b + *rhs * c + *in_tangent * d
+    }
+}
+
+impl Interpolate for Quaternion {
+    fn interpolate(&self, rhs: &Self, factor: f32) -> Self {
+        self.slerp(*rhs, factor)
+    }
+
+    /// Interpolates each component of the quaternion, then normalizes the result, as glTF
+    /// specifies
+    fn cubic_spline(
+        &self,
+        out_tangent: &Self,
+        rhs: &Self,
+        in_tangent: &Self,
+        factor: f32,
+        duration: f32,
+    ) -> Self {
+        let [a, b, c, d] = hermite_weights(factor, duration);
+        (*self * a + *out_tangent * b + *rhs * c + *in_tangent * d).normalized()
+    }
+}
+
+/// How values are computed between keyframes
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Hold each value until the next keyframe
+    Step,
+    /// Blend linearly between keyframes, or spherically for rotations
+    #[default]
+    Linear,
+    /// Follow a cubic Hermite spline through the keyframes, as in glTF. Each keyframe has an
+    /// in-tangent, value and out-tangent.
+    CubicSpline,
+}
+
+/// A sequence of keyframes for animating a single value
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track<T> {
+    times: Vec<f32>,
+    values: Vec<T>,
+    interpolation: Interpolation,
+}
+
+impl<T: Interpolate> Track<T> {
+    /// Construct a track from keyframe times, in increasing order, and the value at each.
+    /// For [`Interpolation::CubicSpline`], each keyframe has three values instead: its
+    /// in-tangent, value and out-tangent, in the same layout as glTF.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no keyframes, the number of values does not match the number of
+    /// times, or the times are not increasing.
+    pub fn new(times: Vec<f32>, values: Vec<T>, interpolation: Interpolation) -> Self {
+        assert!(!times.is_empty(), "a track needs at least one keyframe");
+        let per_keyframe = match interpolation {
+            Interpolation::CubicSpline => 3,
+            _ => 1,
+        };
+        assert_eq!(times.len() * per_keyframe, values.len());
+        assert!(
+            times.windows(2).all(|w| w[0] < w[1]),
+            "keyframe times must be increasing"
+        );
+
+        Self {
+            times,
+            values,
+            interpolation,
+        }
+    }
+
+    /// The time of each keyframe
+    pub fn times(&self) -> &[f32] {
+        &self.times
+    }
+
+    /// The value at each keyframe, interleaved with tangents for cubic splines
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// How values are computed between keyframes
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// The time of the first and last keyframes
+    pub fn duration(&self) -> (f32, f32) {
+        (self.times[0], self.times[self.times.len() - 1])
+    }
+
+    /// The value at the given time. Times outside the track hold the first or last value.
+    pub fn sample(&self, time: f32) -> T {
+        // The index of the first keyframe after the given time
+        let next = self.times.partition_point(|&t| t <= time);
+        if next == 0 {
+            return self.value(0);
+        }
+        if next == self.times.len() {
+            return self.value(next - 1);
+        }
+
+        let previous = next - 1;
+        let (t0, t1) = (self.times[previous], self.times[next]);
+        let factor = (time - t0) / (t1 - t0);
+        match self.interpolation {
+            Interpolation::Step => self.value(previous),
+            Interpolation::Linear => self.values[previous].interpolate(&self.values[next], factor),
+            Interpolation::CubicSpline => {
+                let (v0, out0) = (
+                    &self.values[previous * 3 + 1],
+                    &self.values[previous * 3 + 2],
+                );
+                let (in1, v1) = (&self.values[next * 3], &self.values[next * 3 + 1]);
+                v0.cubic_spline(out0, v1, in1, factor, t1 - t0)
+            }
+        }
+    }
+
+    /// The value at the given keyframe
+    fn value(&self, keyframe: usize) -> T {
+        match self.interpolation {
+            Interpolation::CubicSpline => self.values[keyframe * 3 + 1],
+            _ => self.values[keyframe],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn sample() {
+        let track = Track::new(
+            vec![1.0, 2.0, 4.0],
+            vec![0.0, 10.0, 20.0],
+            Interpolation::Linear,
+        );
+        assert_eq!(track.duration(), (1.0, 4.0));
+        assert_eq!(track.sample(0.0), 0.0);
+        assert_eq!(track.sample(1.5), 5.0);
+        assert_eq!(track.sample(2.0), 10.0);
+        assert_eq!(track.sample(3.0), 15.0);
+        assert_eq!(track.sample(9.0), 20.0);
+
+        let step = Track::new(
+            vec![0.0, 1.0],
+            vec![Vector3::zero(), Vector3::one()],
+            Interpolation::Step,
+        );
+        assert_eq!(step.sample(0.99), Vector3::zero());
+        assert_eq!(step.sample(1.0), Vector3::one());
+    }
+
+    #[test]
+    fn rotation() {
+        let a = Quaternion::identity();
+        let b = Quaternion::from_matrix(&Matrix4::rotation_y(2.0));
+        let track = Track::new(vec![0.0, 1.0], vec![a, b], Interpolation::Linear);
+
+        // Spherical interpolation turns at a constant rate
+        assert_nearly_eq!(
+            &track.sample(0.25).to_matrix(),
+            &Matrix4::rotation_y(0.5),
+            epsilon = 1e-6
+        );
+
+        // Either sign of a rotation takes the shortest path
+        let track = Track::new(vec![0.0, 1.0], vec![a, -b], Interpolation::Linear);
+        assert_nearly_eq!(
+            &track.sample(0.5).to_matrix(),
+            &Matrix4::rotation_y(1.0),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn cubic_spline() {
+        // A ball thrown upwards at 4 units per second, under gravity of -8, lands after a second
+        let track = Track::new(
+            vec![0.0, 0.5, 1.0],
+            vec![
+                Vector3::zero(),
+                Vector3::zero(),
+                Vector3::new(1.0, 4.0, 0.0),
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(0.5, 1.0, 0.0),
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(1.0, -4.0, 0.0),
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::zero(),
+            ],
+            Interpolation::CubicSpline,
+        );
+
+        assert_eq!(track.sample(-1.0), Vector3::zero());
+        assert_eq!(track.sample(0.5), Vector3::new(0.5, 1.0, 0.0));
+        assert_eq!(track.sample(2.0), Vector3::new(1.0, 0.0, 0.0));
+        // A quadratic is reproduced exactly by a cubic spline with matching tangents
+        for t in [0.1, 0.25, 0.7, 0.9] {
+            assert_nearly_eq!(
+                &track.sample(t),
+                &Vector3::new(t, 4.0 * t - 4.0 * t * t, 0.0),
+                epsilon = 1e-6
+            );
+        }
+
+        let a = Quaternion::identity();
+        let b = Quaternion::from_matrix(&Matrix4::rotation_x(1.0));
+        let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+        let rotation = Track::new(
+            vec![0.0, 1.0],
+            vec![zero, a, zero, zero, b, zero],
+            Interpolation::CubicSpline,
+        );
+        assert_nearly_eq!(rotation.sample(0.3).magnitude(), 1.0, epsilon = 1e-6);
+        assert_nearly_eq!(&rotation.sample(1.0), &b, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Point, Vector2, Vector3, Vector3A, Vector4};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Types which can be added together and scaled, for writing code generic over `f32` and
+/// every vector type
+pub trait VectorSpace:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self> + Neg<Output = Self>
+{
+    /// The additive identity
+    fn zero() -> Self;
+
+    /// Linear interpolation between this value and another. The factor is clamped to `[0, 1]`,
+    /// as in the vector types' own `lerp`.
+    // Unlike `clamp`, this maps a NaN factor to 0, as the vector types do
+    #[allow(clippy::manual_clamp)]
+    fn lerp(&self, rhs: Self, factor: f32) -> Self {
+        let t = factor.min(1.0).max(0.0);
+        *self * (1.0 - t) + rhs * t
+    }
+}
+
+/// Vector spaces with a dot product, and so a notion of length
+pub trait InnerProduct: VectorSpace {
+    /// The dot product of this vector and another
+    fn dot(&self, rhs: Self) -> f32;
+
+    /// The squared length of this vector
+    fn magnitude_squared(&self) -> f32 {
+        self.dot(*self)
+    }
+
+    /// The length of this vector
+    fn magnitude(&self) -> f32 {
+        self.magnitude_squared().sqrt()
+    }
+}
+
+/// Types with a notion of distance between values, including points
+pub trait MetricSpace {
+    /// The squared distance between this value and another
+    fn distance_squared(&self, rhs: &Self) -> f32;
+
+    /// The distance between this value and another
+    fn distance(&self, rhs: &Self) -> f32 {
+        self.distance_squared(rhs).sqrt()
+    }
+}
+
+impl VectorSpace for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+}
+
+impl InnerProduct for f32 {
+    fn dot(&self, rhs: Self) -> f32 {
+        self * rhs
+    }
+}
+
+impl MetricSpace for f32 {
+    fn distance_squared(&self, rhs: &Self) -> f32 {
+        (self - rhs) * (self - rhs)
+    }
+}
+
+macro_rules! implement_traits {
+    ($($VectorT:ident),+) => {
+        $(
+            impl VectorSpace for $VectorT {
+                fn zero() -> Self {
+                    $VectorT::zero()
+                }
+
+                fn lerp(&self, rhs: Self, factor: f32) -> Self {
+                    $VectorT::lerp(self, rhs, factor)
+                }
+            }
+
+            impl InnerProduct for $VectorT {
+                fn dot(&self, rhs: Self) -> f32 {
+                    $VectorT::dot(self, rhs)
+                }
+            }
+
+            impl MetricSpace for $VectorT {
+                fn distance_squared(&self, rhs: &Self) -> f32 {
+                    (*self - *rhs).magnitude_squared()
+                }
+            }
+        )+
+    };
+}
+
+implement_traits!(Vector2, Vector3, Vector3A, Vector4);
+
+impl MetricSpace for Point {
+    fn distance_squared(&self, rhs: &Self) -> f32 {
+        (*self - *rhs).magnitude_squared()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn centroid<T: VectorSpace>(values: &[T]) -> T {
+        values.iter().fold(T::zero(), |sum, v| sum + *v) * (1.0 / values.len() as f32)
+    }
+
+    fn furthest<T: MetricSpace + Copy>(from: T, values: &[T]) -> T {
+        *values
+            .iter()
+            .max_by(|a, b| {
+                from.distance_squared(a)
+                    .total_cmp(&from.distance_squared(b))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn generic() {
+        assert_eq!(centroid(&[1.0, 2.0, 6.0]), 3.0);
+        assert_eq!(
+            centroid(&[Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)]),
+            Vector2::new(0.5, 0.5)
+        );
+        assert_eq!(
+            InnerProduct::magnitude(&Vector4::new(1.0, 2.0, 2.0, 4.0)),
+            5.0
+        );
+
+        let points = [Point::new(1.0, 0.0, 0.0), Point::new(0.0, 3.0, 0.0)];
+        assert_eq!(furthest(Point::ORIGIN, &points), points[1]);
+        assert_eq!(Point::ORIGIN.distance(&points[1]), 3.0);
+        assert_eq!(furthest(0.0, &[-4.0, 3.0]), -4.0);
+
+        assert_eq!(VectorSpace::lerp(&2.0, 4.0, 0.5), 3.0);
+        assert_eq!(VectorSpace::lerp(&2.0, 4.0, 1.5), 4.0);
+        assert_eq!(
+            VectorSpace::lerp(&Vector2::zero(), Vector2::one(), -1.0),
+            Vector2::zero()
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Matrix4, Point, Quaternion, Vector3};
+
+/// A transform stored as separate translation, rotation and scale, applied in the order scale,
+/// rotation, translation.
+///
+/// Composing transforms in this form avoids the drift that accumulates in repeated 4x4
+/// multiplication, such as rotations gradually picking up shear in a scene graph. Composition
+/// and inversion are exact when the scale is uniform; with non-uniform scale, the shear that a
+/// matrix would pick up cannot be represented, and is dropped.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
+impl Transform {
+    /// Construct a transform from its parts
+    pub const fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// The transform that does nothing
+    pub const fn identity() -> Self {
+        Self::new(Vector3::zero(), Quaternion::identity(), Vector3::one())
+    }
+
+    /// A transform that only translates
+    pub const fn from_translation(translation: Vector3) -> Self {
+        Self::new(translation, Quaternion::identity(), Vector3::one())
+    }
+
+    /// A transform that only rotates
+    pub const fn from_rotation(rotation: Quaternion) -> Self {
+        Self::new(Vector3::zero(), rotation, Vector3::one())
+    }
+
+    /// A transform that only scales
+    pub const fn from_scale(scale: Vector3) -> Self {
+        Self::new(Vector3::zero(), Quaternion::identity(), scale)
+    }
+
+    /// The transform described by a matrix. See [`Matrix4::decompose`].
+    pub fn from_matrix(m: &Matrix4) -> Self {
+        let (translation, rotation, scale) = m.decompose();
+        Self::new(translation, rotation, scale)
+    }
+
+    /// The matrix equivalent to this transform
+    pub fn to_matrix(&self) -> Matrix4 {
+        Matrix4::from_translation_rotation_scale(self.translation, self.rotation, self.scale)
+    }
+
+    /// The transform that applies `other` first, and then this transform, as for a parent
+    /// transform composed with that of its child
+    pub fn compose(&self, other: &Transform) -> Self {
+        Self::new(
+            self.transform_point(Point::from(other.translation)).into(),
+            (self.rotation * other.rotation).normalized(),
+            self.scale * other.scale,
+        )
+    }
+
+    /// The transform that undoes this one
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        let scale = Vector3::one() / self.scale;
+        Self::new(rotation.rotate(-self.translation) * scale, rotation, scale)
+    }
+
+    /// Transform a point by this transform
+    pub fn transform_point(&self, p: Point) -> Point {
+        Point::from(self.rotation.rotate(Vector3::from(p) * self.scale) + self.translation)
+    }
+
+    /// Transform a direction by this transform, ignoring translation
+    pub fn transform_vector(&self, v: Vector3) -> Vector3 {
+        self.rotation.rotate(v * self.scale)
+    }
+
+    /// Interpolation between this transform and another. Translation and scale are
+    /// interpolated linearly, and rotation spherically.
+    pub fn lerp(&self, rhs: &Transform, factor: f32) -> Self {
+        Self::new(
+            self.translation.lerp(rhs.translation, factor),
+            self.rotation.slerp(rhs.rotation, factor.clamp(0.0, 1.0)),
+            self.scale.lerp(rhs.scale, factor),
+        )
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl std::ops::Mul for Transform {
+    type Output = Transform;
+
+    /// The composition of two transforms, applying `rhs` first. See [`Transform::compose`].
+    fn mul(self, rhs: Transform) -> Transform {
+        self.compose(&rhs)
+    }
+}
+
+impl From<Transform> for Matrix4 {
+    fn from(t: Transform) -> Self {
+        t.to_matrix()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn compose() {
+        let parent = Transform::new(
+            Vector3::new(1.0, 2.0, 3.0),
+            Quaternion::from_axis_angle(Vector3::UNIT_Y, 0.5),
+            Vector3::from_scalar(2.0),
+        );
+        let child = Transform::new(
+            Vector3::new(0.0, 1.0, 0.0),
+            Quaternion::from_axis_angle(Vector3::new(0.6, 0.8, 0.0), 1.0),
+            Vector3::new(1.0, 3.0, 0.5),
+        );
+
+        assert_nearly_eq!(
+            &(parent * child).to_matrix(),
+            &(parent.to_matrix() * child.to_matrix()),
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            &parent.inverse().to_matrix(),
+            &parent.to_matrix().invert(),
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            &(parent.inverse() * parent).to_matrix(),
+            &Matrix4::identity(),
+            epsilon = 1e-5
+        );
+
+        let p = Point::new(0.5, -1.0, 2.0);
+        assert_nearly_eq!(
+            &child.transform_point(p),
+            &(child.to_matrix() * p),
+            epsilon = 1e-5
+        );
+        assert_nearly_eq!(
+            &child.transform_vector(Vector3::UNIT_X),
+            &(child.to_matrix() * Vector3::UNIT_X),
+            epsilon = 1e-5
+        );
+
+        let round_trip = Transform::from_matrix(&child.to_matrix());
+        assert_nearly_eq!(&round_trip.to_matrix(), &child.to_matrix(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Transform::identity();
+        let b = Transform::new(
+            Vector3::new(2.0, 0.0, 0.0),
+            Quaternion::from_axis_angle(Vector3::UNIT_Z, std::f32::consts::FRAC_PI_2),
+            Vector3::from_scalar(3.0),
+        );
+
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid.translation, Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(mid.scale, Vector3::from_scalar(2.0));
+        assert_nearly_eq!(
+            &mid.rotation,
+            &Quaternion::from_axis_angle(Vector3::UNIT_Z, std::f32::consts::FRAC_PI_4),
+            epsilon = 1e-6
+        );
+        assert_eq!(a.lerp(&b, 1.0), b);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Matrix4, Vector3};
+
+/// A rigid-body velocity (an element of the Lie algebra se(3)), combining an angular
+/// velocity with a linear velocity.
+///
+/// The angular part follows the same convention as [`Matrix4::rotation_axis_angle`]: its
+/// direction is the axis of rotation, and its magnitude the angle in radians.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Twist {
+    pub angular: Vector3,
+    pub linear: Vector3,
+}
+
+impl Twist {
+    /// Construct a new twist from angular and linear parts
+    pub const fn new(angular: Vector3, linear: Vector3) -> Self {
+        Self { angular, linear }
+    }
+
+    /// The rigid transformation reached by following this twist for unit time.
+    ///
+    /// Scaling the twist by a time step before calling this integrates a constant velocity
+    /// exactly, which is not true of simply adding rotation angles and translations.
+    pub fn exp(&self) -> Matrix4 {
+        let w = -self.angular;
+        let (a, b, _) = coefficients(w.magnitude());
+
+        let wv = w.cross(self.linear);
+        let t = self.linear + wv * a + w.cross(wv) * b;
+
+        Matrix4::translation(t) * Matrix4::exp_rotation(self.angular)
+    }
+}
+
+impl std::ops::Mul<f32> for Twist {
+    type Output = Twist;
+
+    fn mul(self, t: f32) -> Twist {
+        Twist::new(self.angular * t, self.linear * t)
+    }
+}
+
+/// The coefficients of the series in the exponential map of se(3), and its inverse.
+fn coefficients(theta: f32) -> (f32, f32, f32) {
+    if theta < 1e-3 {
+        let theta2 = theta * theta;
+        (
+            0.5 - theta2 / 24.0,
+            1.0 / 6.0 - theta2 / 120.0,
+            1.0 / 12.0 + theta2 / 720.0,
+        )
+    } else {
+        let (sin, cos) = theta.sin_cos();
+        let theta2 = theta * theta;
+        (
+            (1.0 - cos) / theta2,
+            (theta - sin) / (theta2 * theta),
+            (1.0 - theta * sin / (2.0 * (1.0 - cos))) / theta2,
+        )
+    }
+}
+
+impl Matrix4 {
+    /// The rotation described by a rotation vector, whose direction is the axis of rotation and
+    /// whose magnitude is the angle in radians (the exponential map of so(3)).
+    pub fn exp_rotation(rotation: Vector3) -> Self {
+        let angle = rotation.magnitude();
+        if angle > 0.0 {
+            Self::rotation_axis_angle(rotation / angle, angle)
+        } else {
+            Self::identity()
+        }
+    }
+
+    /// The rotation vector of the rotation part of this matrix, which must be orthonormal
+    /// (the logarithm map of SO(3)). The resulting angle is in the range `[0, π]`.
+    pub fn log_rotation(&self) -> Vector3 {
+        let r = |row: usize, col: usize| self.0[col][row];
+
+        // Twice the axis scaled by the sine of the angle, in the conventional (counter-clockwise) sense
+        let skew = Vector3::new(r(2, 1) - r(1, 2), r(0, 2) - r(2, 0), r(1, 0) - r(0, 1));
+
+        let cos = ((r(0, 0) + r(1, 1) + r(2, 2) - 1.0) * 0.5).clamp(-1.0, 1.0);
+        let sin = skew.magnitude() * 0.5;
+        let angle = sin.atan2(cos);
+
+        if angle < 1e-4 {
+            return -skew * 0.5;
+        }
+
+        let axis = if angle > std::f32::consts::PI - 1e-3 {
+            // The skew part vanishes near a half turn, so recover the axis from the
+            // symmetric part, (R + Rᵀ) / 2 - cos(θ)·I = (1 - cos(θ))·axis·axisᵀ
+            let i = (0..3)
+                .max_by(|&a, &b| r(a, a).total_cmp(&r(b, b)))
+                .unwrap_or(0);
+            let mut column = Vector3::new(
+                (r(0, i) + r(i, 0)) * 0.5,
+                (r(1, i) + r(i, 1)) * 0.5,
+                (r(2, i) + r(i, 2)) * 0.5,
+            );
+            column[i] -= cos;
+
+            let axis = column.normalized();
+            if axis.dot(skew) < 0.0 {
+                -axis
+            } else {
+                axis
+            }
+        } else {
+            skew / (2.0 * sin)
+        };
+
+        // This crate's rotations turn clockwise about their axis
+        -axis * angle
+    }
+
+    /// The twist that generates the rigid transform described by this matrix, which must
+    /// contain only rotation and translation (the logarithm map of SE(3)).
+    pub fn log_rigid(&self) -> Twist {
+        let angular = self.log_rotation();
+        let w = -angular;
+        let (_, _, c) = coefficients(w.magnitude());
+
+        let t = Vector3::from(self.0[3]);
+        let wt = w.cross(t);
+        let linear = t - wt * 0.5 + w.cross(wt) * c;
+
+        Twist::new(angular, linear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn rotation() {
+        let axis = Vector3::new(2.0, -1.0, 2.0).normalized();
+        let m = Matrix4::rotation_axis_angle(axis, 1.3);
+
+        assert_nearly_eq!(&Matrix4::exp_rotation(axis * 1.3), &m, epsilon = 1e-6);
+        assert_nearly_eq!(&m.log_rotation(), &(axis * 1.3), epsilon = 1e-5);
+
+        let half_turn = Matrix4::rotation_axis_angle(axis, std::f32::consts::PI);
+        assert_nearly_eq!(
+            &Matrix4::exp_rotation(half_turn.log_rotation()),
+            &half_turn,
+            epsilon = 1e-5
+        );
+        assert_eq!(Matrix4::identity().log_rotation(), Vector3::zero());
+    }
+
+    #[test]
+    fn rigid() {
+        let m = Matrix4::translation(Vector3::new(1.0, -2.0, 3.0))
+            * Matrix4::rotation_axis_angle(Vector3::new(0.0, 0.6, 0.8), 2.0);
+        let twist = m.log_rigid();
+
+        assert_nearly_eq!(&twist.exp(), &m, epsilon = 1e-5);
+
+        // Following half the twist twice reaches the same transform
+        let half = (twist * 0.5).exp();
+        assert_nearly_eq!(&(half * half), &m, epsilon = 1e-5);
+
+        let translation = Twist::new(Vector3::zero(), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            translation.exp(),
+            Matrix4::translation(Vector3::new(1.0, 2.0, 3.0))
+        );
+    }
+}
@@ -0,0 +1,43 @@
+/// Assert that a condition holds, in debug builds with the `debug-validate` feature enabled.
+/// Otherwise the condition is not evaluated at all.
+///
+/// This catches the source of NaNs and other invalid values where they first appear, rather
+/// than wherever they are eventually noticed.
+macro_rules! validate {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "debug-validate")]
+        debug_assert!($($arg)+);
+    };
+}
+
+pub(crate) use validate;
+
+#[cfg(all(test, feature = "debug-validate", debug_assertions))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[should_panic(expected = "Vector3 has a non-finite coordinate")]
+    fn constructor() {
+        Vector3::new(1.0, f32::NAN, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector2 has a non-finite coordinate")]
+    fn operator() {
+        let _ = Vector2::new(1.0, 0.0) / Vector2::new(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix is singular")]
+    fn singular() {
+        Matrix4::uniform_scale(0.0).invert();
+    }
+
+    #[test]
+    #[should_panic(expected = "plane normal must be unit length")]
+    fn plane() {
+        let plane = Plane::new(Vector3::new(0.0, 2.0, 0.0), 0.0);
+        plane.classify_sphere(&Sphere::new(Point::ORIGIN, 1.0));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::NearlyEqual;
+use crate::validate::validate;
+use crate::{CompensatedSum, MathError, NearlyEqual, Tolerance};
 
 /// A vector in 2D space.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -17,6 +18,24 @@ pub struct Vector3 {
     pub z: f32,
 }
 
+/// A vector in 3D space, stored as four floats aligned to 16 bytes.
+///
+/// This matches the layout of a `vec3` in std140 and std430 uniform and storage buffers. The
+/// fourth float is padding that is always zero, so every byte is initialized and the vector can
+/// be copied into a buffer as-is. Note that GLSL may pack a trailing scalar into the padding of a
+/// `vec3`, which this type never does.
+///
+/// The dot and cross products and normalization operate on all four floats at once, which the
+/// compiler can vectorize without masking off the padding.
+#[derive(Copy, Clone)]
+#[repr(C, align(16))]
+pub struct Vector3A {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    padding: f32,
+}
+
 /// A point in 3D space.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[repr(C)]
@@ -27,8 +46,12 @@ pub struct Point {
 }
 
 /// A homogeneous vector in 3D space.
+///
+/// Note that this is aligned to 16 bytes, so that it can be loaded directly into a SIMD
+/// register, and matches the layout of a `vec4` in std140 and std430 buffers.
+#[cfg(not(feature = "portable-simd"))]
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[repr(C)]
+#[repr(C, align(16))]
 pub struct Vector4 {
     pub x: f32,
     pub y: f32,
@@ -36,6 +59,28 @@ pub struct Vector4 {
     pub w: f32,
 }
 
+/// A homogeneous vector in 3D space.
+///
+/// With the `portable-simd` feature, this is stored as a [`f32x4`](std::simd::f32x4), so that
+/// the matrix kernels operate on it without conversion. The coordinates are still accessed as
+/// fields, through [`Deref`](std::ops::Deref) to [`Xyzw`]. The layout is the same as without
+/// the feature.
+#[cfg(feature = "portable-simd")]
+#[derive(Copy, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct Vector4(std::simd::f32x4);
+
+/// The coordinates of a [`Vector4`] stored as SIMD lanes, which it dereferences to.
+#[cfg(feature = "portable-simd")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Xyzw {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
 macro_rules! implement_operator {
     // Binary operator
     (impl $Op:ident<$S:ident> for $T:ident {
@@ -57,32 +102,94 @@ macro_rules! implement_operator {
     };
 }
 
+macro_rules! count_fields {
+    () => { 0 };
+    ($head:ident $($tail:ident)*) => { 1 + count_fields!($($tail)*) };
+}
+
+#[cfg(feature = "num-traits")]
+pub(crate) use count_fields;
+
+/// Wrap a value into `[min, max)`. The Euclidean remainder of a value just below `min` can round
+/// up to the whole range, so a result of `max` is mapped back to `min`.
+fn wrap_scalar(x: f32, min: f32, max: f32) -> f32 {
+    let wrapped = min + (x - min).rem_euclid(max - min);
+    if wrapped >= max {
+        min
+    } else {
+        wrapped
+    }
+}
+
 macro_rules! implement_vector {
     ($VectorT:ident { $($field:ident),+ }) => {
+        impl $VectorT {
+            /// Construct a vector from an array of coordinates, without validating them
+            const fn from_array_unchecked(a: [f32; count_fields!($($field)+)]) -> Self {
+                let [$($field),+] = a;
+                Self { $($field),+ }
+            }
+
+            /// Compute the dot product between this vector and another
+            pub fn dot(&self, rhs: Self) -> f32 {
+                [$(self.$field * rhs.$field),+].iter().sum()
+            }
+
+            /// Every coordinate of this vector multiplied by a scalar
+            fn scaled(&self, s: f32) -> Self {
+                *self * s
+            }
+        }
+
+        implement_vector!($VectorT { $($field),+ } with custom storage);
+    };
+    // For types that store more than their coordinates. These define `from_array_unchecked`,
+    // `dot` and `scaled` themselves, to make use of the extra storage.
+    ($VectorT:ident { $($field:ident),+ } with custom storage) => {
         impl $VectorT {
             /// Construct new a vector from individual coordinates
             pub const fn new($($field: f32),+) -> Self {
-                Self { $($field),+ }
+                validate!(
+                    $($field.is_finite())&&+,
+                    concat!(stringify!($VectorT), " has a non-finite coordinate")
+                );
+                Self::from_array_unchecked([$($field),+])
             }
 
             /// Construct new a vector where each coordinate is the same
             pub const fn from_scalar(s: f32) -> Self {
-                Self { $($field: s),+ }
+                Self::from_array_unchecked([s; count_fields!($($field)+)])
+            }
+
+            /// Construct a new vector from a slice holding exactly one value per coordinate
+            pub fn from_slice(s: &[f32]) -> Result<Self, MathError> {
+                let a: [f32; count_fields!($($field)+)] =
+                    s.try_into().map_err(|_| MathError::InvalidSliceLength {
+                        expected: count_fields!($($field)+),
+                        actual: s.len(),
+                    })?;
+                Ok(Self::from(a))
+            }
+
+            /// Whether every coordinate of this vector is finite (neither infinite nor NaN)
+            pub fn is_finite(&self) -> bool {
+                $(self.$field.is_finite())&&+
             }
 
             /// The additive identity
             pub const fn zero() -> Self {
-                Self { $($field: 0.0),+ }
+                Self::from_scalar(0.0)
             }
 
             /// The multiplicative identity
             pub const fn one() -> Self {
-                Self { $($field: 1.0),+ }
+                Self::from_scalar(1.0)
             }
 
-            /// Compute the dot product between this vector and another
-            pub fn dot(&self, rhs: Self) -> f32 {
-                [$(self.$field * rhs.$field),+].iter().sum()
+            /// Compute the dot product between this vector and another, compensating for rounding error.
+            /// Note that this is several times slower than [`Self::dot`].
+            pub fn dot_precise(&self, rhs: Self) -> f32 {
+                crate::summation::dot_precise([$((self.$field, rhs.$field)),+])
             }
 
             /// Linear interpolation between this vector and another
@@ -101,6 +208,33 @@ macro_rules! implement_vector {
                 Self::new($(self.$field.max(rhs.$field)),+)
             }
 
+            /// Compute the element-wise Euclidean remainder, which (unlike `%`) is never negative
+            pub fn rem_euclid(&self, rhs: Self) -> Self {
+                Self::new($(self.$field.rem_euclid(rhs.$field)),+)
+            }
+
+            /// Wrap each coordinate into the range `[min, max)`, as for tiling or toroidal spaces
+            pub fn wrap(&self, min: Self, max: Self) -> Self {
+                Self::new($(wrap_scalar(self.$field, min.$field, max.$field)),+)
+            }
+
+            /// Apply a function to each coordinate of this vector
+            pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+                Self::new($(f(self.$field)),+)
+            }
+
+            /// Combine the corresponding coordinates of this vector and another with a function
+            pub fn zip_with(&self, rhs: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+                Self::new($(f(self.$field, rhs.$field)),+)
+            }
+
+            /// Reduce the coordinates of this vector to a single value, in order
+            pub fn fold<T>(&self, init: T, mut f: impl FnMut(T, f32) -> T) -> T {
+                let result = init;
+                $(let result = f(result, self.$field);)+
+                result
+            }
+
             /// The length of this vector squared. Note that this avoids an expensive square root.
             pub fn magnitude_squared(&self) -> f32 {
                 self.dot(*self)
@@ -114,16 +248,50 @@ macro_rules! implement_vector {
             /// Normalize this vector to unit length. Note that this involves an expensive square root.
             pub fn normalized(&self) -> Self {
                 let d = self.magnitude();
-                if d > 0.0 {
-                    let d = 1.0 / d;
-                    *self * d
+                let result = if d > 0.0 {
+                    self.scaled(1.0 / d)
                 } else {
                     *self
+                };
+                crate::nan_policy::apply(result, Self::is_finite, Self::zero, "normalize")
+            }
+
+            /// Normalize this vector to unit length, or return [`MathError::ZeroVector`] if it has
+            /// no length (or its length is not finite).
+            pub fn try_normalize(&self) -> Result<Self, MathError> {
+                let d = self.magnitude();
+                if d > 0.0 && d.is_finite() {
+                    Ok(*self * (1.0 / d))
+                } else {
+                    Err(MathError::ZeroVector)
                 }
             }
 
+            /// Normalize this vector to unit length in place. See [`Self::normalized`].
+            pub fn normalize_in_place(&mut self) {
+                *self = self.normalized();
+            }
+
+            /// Write this vector to a formatting sink. See [`FixedBuffer`](crate::FixedBuffer).
+            pub fn write_to(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+                write!(w, "{}", self)
+            }
+
+            /// View the coordinates of this vector as an array
+            pub fn as_array(&self) -> &[f32; count_fields!($($field)+)] {
+                // Safety: the coordinates are laid out contiguously at the start of the vector
+                unsafe { &*(self as *const Self as *const [f32; count_fields!($($field)+)]) }
+            }
+
+            /// View the coordinates of this vector as a mutable array
+            pub fn as_array_mut(&mut self) -> &mut [f32; count_fields!($($field)+)] {
+                // Safety: as for `as_array`
+                unsafe { &mut *(self as *mut Self as *mut [f32; count_fields!($($field)+)]) }
+            }
+
+            /// View the coordinates of this vector as a slice
             pub fn as_slice(&self) -> &[f32] {
-                unsafe { std::slice::from_raw_parts(&self.x, std::mem::size_of::<Self>() / std::mem::size_of::<f32>()) }
+                self.as_array()
             }
         }
 
@@ -144,6 +312,9 @@ macro_rules! implement_vector {
         implement_operator!(impl Div<f32> for $VectorT {
             fn div(self, t) -> $VectorT { $VectorT::new($(self.$field / t),+) }
         });
+        implement_operator!(impl Rem<f32> for $VectorT {
+            fn rem(self, t) -> $VectorT { $VectorT::new($(self.$field % t),+) }
+        });
 
         implement_operator!(impl AddAssign<f32> for $VectorT {
             fn add_assign(&mut self, t) { $(self.$field += t);+ }
@@ -157,7 +328,16 @@ macro_rules! implement_vector {
         implement_operator!(impl DivAssign<f32> for $VectorT {
             fn div_assign(&mut self, t) { $(self.$field /= t);+ }
         });
+        implement_operator!(impl RemAssign<f32> for $VectorT {
+            fn rem_assign(&mut self, t) { $(self.$field %= t);+ }
+        });
 
+        implement_operator!(impl Add<$VectorT> for f32 {
+            fn add(self, t) -> $VectorT { $VectorT::new($(self + t.$field),+) }
+        });
+        implement_operator!(impl Sub<$VectorT> for f32 {
+            fn sub(self, t) -> $VectorT { $VectorT::new($(self - t.$field),+) }
+        });
         implement_operator!(impl Mul<$VectorT> for f32 {
             fn mul(self, t) -> $VectorT { $VectorT::new($(self * t.$field),+) }
         });
@@ -165,16 +345,55 @@ macro_rules! implement_vector {
             fn div(self, t) -> $VectorT { $VectorT::new($(self / t.$field),+) }
         });
 
+        impl From<[f32; count_fields!($($field)+)]> for $VectorT {
+            fn from(a: [f32; count_fields!($($field)+)]) -> Self {
+                let [$($field),+] = a;
+                Self::new($($field),+)
+            }
+        }
+
+        impl From<$VectorT> for [f32; count_fields!($($field)+)] {
+            fn from(v: $VectorT) -> Self {
+                [$(v.$field),+]
+            }
+        }
+
+        impl AsRef<[f32; count_fields!($($field)+)]> for $VectorT {
+            fn as_ref(&self) -> &[f32; count_fields!($($field)+)] {
+                self.as_array()
+            }
+        }
+
+        impl AsMut<[f32; count_fields!($($field)+)]> for $VectorT {
+            fn as_mut(&mut self) -> &mut [f32; count_fields!($($field)+)] {
+                self.as_array_mut()
+            }
+        }
+
         impl std::ops::Index<usize> for $VectorT {
             type Output = f32;
             fn index(&self, i: usize) -> &f32 {
-                [$(&self.$field),+][i]
+                &self.as_array()[i]
             }
         }
 
         impl std::ops::IndexMut<usize> for $VectorT {
             fn index_mut(&mut self, i: usize) -> &mut f32 {
-                [$(&mut self.$field),+][i]
+                &mut self.as_array_mut()[i]
+            }
+        }
+
+        /// Formats as `(x, y, z)`, applying any width or precision to each coordinate
+        impl std::fmt::Display for $VectorT {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("(")?;
+                for (i, c) in self.as_array().iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    std::fmt::Display::fmt(c, f)?;
+                }
+                f.write_str(")")
             }
         }
 
@@ -182,25 +401,154 @@ macro_rules! implement_vector {
             fn nearly_equals(self, rhs: Self) -> bool {
                 $(self.$field.nearly_equals(rhs.$field))&&+
             }
+
+            fn nearly_equals_within(self, rhs: Self, tolerance: Tolerance) -> bool {
+                $(self.$field.nearly_equals_within(rhs.$field, tolerance))&&+
+            }
+
+            fn nearly_equals_ulps(self, rhs: Self, max_ulps: u32) -> bool {
+                $(self.$field.nearly_equals_ulps(rhs.$field, max_ulps))&&+
+            }
+
+            fn describe_mismatch(self, rhs: Self, tolerance: Tolerance) -> Option<String> {
+                $(if !self.$field.nearly_equals_within(rhs.$field, tolerance) {
+                    return Some(format!(
+                        "{}: `{:?}` vs `{:?}`",
+                        stringify!($field),
+                        self.$field,
+                        rhs.$field
+                    ));
+                })+
+                None
+            }
         }
     }
 }
 
 implement_vector!(Vector2 { x, y });
 implement_vector!(Vector3 { x, y, z });
+implement_vector!(Vector3A { x, y, z } with custom storage);
 implement_vector!(Point { x, y, z });
+#[cfg(not(feature = "portable-simd"))]
 implement_vector!(Vector4 { x, y, z, w });
+#[cfg(feature = "portable-simd")]
+implement_vector!(Vector4 { x, y, z, w } with custom storage);
+
+#[cfg(feature = "portable-simd")]
+impl Vector4 {
+    /// Construct a vector from an array of coordinates, without validating them
+    const fn from_array_unchecked(a: [f32; 4]) -> Self {
+        Self(std::simd::f32x4::from_array(a))
+    }
+
+    /// Compute the dot product between this vector and another
+    pub fn dot(&self, rhs: Self) -> f32 {
+        use std::simd::num::SimdFloat;
+        (self.0 * rhs.0).reduce_sum()
+    }
+
+    /// Every coordinate of this vector multiplied by a scalar
+    fn scaled(&self, s: f32) -> Self {
+        Self::from(self.0 * std::simd::f32x4::splat(s))
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl std::ops::Deref for Vector4 {
+    type Target = Xyzw;
+
+    fn deref(&self) -> &Xyzw {
+        // Safety: `Xyzw` is four contiguous floats, and less strictly aligned than `f32x4`
+        unsafe { &*(self as *const Self as *const Xyzw) }
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl std::ops::DerefMut for Vector4 {
+    fn deref_mut(&mut self) -> &mut Xyzw {
+        // Safety: as for `deref`
+        unsafe { &mut *(self as *mut Self as *mut Xyzw) }
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl std::fmt::Debug for Vector4 {
+    /// Formats the coordinates as fields, matching the layout without `portable-simd`
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Vector4")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .field("w", &self.w)
+            .finish()
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl From<Vector4> for std::simd::f32x4 {
+    fn from(v: Vector4) -> Self {
+        v.0
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl From<std::simd::f32x4> for Vector4 {
+    fn from(v: std::simd::f32x4) -> Self {
+        let v = Self(v);
+        validate!(v.is_finite(), "Vector4 has a non-finite coordinate");
+        v
+    }
+}
 
 impl Vector2 {
+    /// The unit vector along the x-axis.
+    pub const UNIT_X: Self = Self::new(1.0, 0.0);
+    /// The unit vector along the y-axis.
+    pub const UNIT_Y: Self = Self::new(0.0, 1.0);
+
     /// Compute a cross product between this vector and another.
     /// This treats both inputs as 3D vectors with a z-component of zero,
     /// performs the normal 3D cross product, and returns only the resulting z-component.
     pub fn cross(&self, rhs: Self) -> f32 {
         self.x * rhs.y - self.y * rhs.x
     }
+
+    /// This vector rotated about the origin by the given angle.
+    /// Note that, as with [`crate::Matrix4::rotation_z`], positive angles rotate clockwise.
+    pub fn rotated(&self, angle_radians: f32) -> Self {
+        let (sin, cos) = angle_radians.sin_cos();
+        Self::new(self.x * cos + self.y * sin, self.y * cos - self.x * sin)
+    }
+
+    /// This point rotated about a pivot point by the given angle, clockwise.
+    pub fn rotated_around(&self, pivot: Self, angle_radians: f32) -> Self {
+        pivot + (*self - pivot).rotated(angle_radians)
+    }
 }
 
+/// Named directions follow the right-handed, y-up convention used by [`crate::Matrix4::look_at`],
+/// where the x-axis points right, and a camera looks along the negative z-axis.
 impl Vector3 {
+    /// The unit vector along the x-axis.
+    pub const UNIT_X: Self = Self::new(1.0, 0.0, 0.0);
+    /// The unit vector along the y-axis.
+    pub const UNIT_Y: Self = Self::new(0.0, 1.0, 0.0);
+    /// The unit vector along the z-axis.
+    pub const UNIT_Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    /// Up, along the positive y-axis.
+    pub const UP: Self = Self::UNIT_Y;
+    /// Down, along the negative y-axis.
+    pub const DOWN: Self = Self::new(0.0, -1.0, 0.0);
+    /// Right, along the positive x-axis.
+    pub const RIGHT: Self = Self::UNIT_X;
+    /// Left, along the negative x-axis.
+    pub const LEFT: Self = Self::new(-1.0, 0.0, 0.0);
+    /// Forward, along the negative z-axis.
+    pub const FORWARD: Self = Self::new(0.0, 0.0, -1.0);
+    /// Backward, along the positive z-axis.
+    pub const BACK: Self = Self::UNIT_Z;
+
     /// Compute the cross product between this vector and another.
     pub fn cross(&self, rhs: Self) -> Self {
         Self {
@@ -209,6 +557,183 @@ impl Vector3 {
             z: self.x * rhs.y - self.y * rhs.x,
         }
     }
+
+    /// An arbitrary unit vector perpendicular to this one, which must be non-zero.
+    pub fn any_perpendicular(&self) -> Self {
+        let other = if self.x.abs() < 0.9 {
+            Vector3::UNIT_X
+        } else {
+            Vector3::UNIT_Y
+        };
+        self.cross(other).normalized()
+    }
+}
+
+/// Convert three basis vectors into an orthonormal basis using the Gram-Schmidt process.
+///
+/// The first vector keeps its direction, and the second stays in the plane of the first two.
+/// The third is perpendicular to both, pointing to the same side as `c`, so that the
+/// handedness of the input basis is preserved.
+pub fn orthonormalize(a: Vector3, b: Vector3, c: Vector3) -> [Vector3; 3] {
+    let x = a.normalized();
+    let y = (b - x * x.dot(b)).normalized();
+    let z = x.cross(y);
+
+    if z.dot(c) < 0.0 {
+        [x, y, -z]
+    } else {
+        [x, y, z]
+    }
+}
+
+/// Normalize every vector in a slice to unit length, as [`Vector3::normalized`] does, using
+/// SIMD instructions where available.
+pub fn normalize_all(vectors: &mut [Vector3]) {
+    crate::simd::normalize_vector3s(vectors);
+}
+
+impl Point {
+    /// The origin.
+    pub const ORIGIN: Self = Self::new(0.0, 0.0, 0.0);
+    /// The point one unit along the x-axis.
+    pub const UNIT_X: Self = Self::new(1.0, 0.0, 0.0);
+    /// The point one unit along the y-axis.
+    pub const UNIT_Y: Self = Self::new(0.0, 1.0, 0.0);
+    /// The point one unit along the z-axis.
+    pub const UNIT_Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    /// The average of a set of points, computed with compensated summation so that
+    /// large point clouds don't drift. Returns `None` if there are no points.
+    pub fn centroid(points: &[Point]) -> Option<Point> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let mut sum = [CompensatedSum::new(); 3];
+        for p in points {
+            sum[0].add(p.x);
+            sum[1].add(p.y);
+            sum[2].add(p.z);
+        }
+
+        let n = points.len() as f32;
+        Some(Point::new(
+            sum[0].value() / n,
+            sum[1].value() / n,
+            sum[2].value() / n,
+        ))
+    }
+}
+
+impl Vector4 {
+    /// The unit vector along the x-axis.
+    pub const UNIT_X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+    /// The unit vector along the y-axis.
+    pub const UNIT_Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+    /// The unit vector along the z-axis.
+    pub const UNIT_Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+    /// The unit vector along the w-axis.
+    pub const UNIT_W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// The coordinates of this vector, readable in constant functions regardless of storage
+    pub(crate) const fn coordinates(&self) -> [f32; 4] {
+        #[cfg(not(feature = "portable-simd"))]
+        return [self.x, self.y, self.z, self.w];
+        #[cfg(feature = "portable-simd")]
+        return self.0.to_array();
+    }
+}
+
+impl Vector3A {
+    /// Construct a vector from an array of coordinates, without validating them
+    const fn from_array_unchecked(a: [f32; 3]) -> Self {
+        let [x, y, z] = a;
+        Self {
+            x,
+            y,
+            z,
+            padding: 0.0,
+        }
+    }
+
+    /// The coordinates of this vector, followed by the zero padding
+    fn lanes(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.padding]
+    }
+
+    /// Construct a vector from the result of a four-wide operation
+    fn from_lanes(lanes: [f32; 4]) -> Self {
+        let [x, y, z, padding] = lanes;
+        validate!(
+            x.is_finite() && y.is_finite() && z.is_finite(),
+            "Vector3A has a non-finite coordinate"
+        );
+        Self { x, y, z, padding }
+    }
+
+    /// Compute the dot product between this vector and another
+    pub fn dot(&self, rhs: Self) -> f32 {
+        let (a, b) = (self.lanes(), rhs.lanes());
+        // The padding contributes zero, and the sum is in the same order as for `Vector3`
+        (a[0] * b[0] + a[1] * b[1]) + (a[2] * b[2] + a[3] * b[3])
+    }
+
+    /// Every coordinate of this vector multiplied by a scalar
+    fn scaled(&self, s: f32) -> Self {
+        Self::from_lanes(self.lanes().map(|x| x * s))
+    }
+
+    /// Compute the cross product between this vector and another.
+    pub fn cross(&self, rhs: Self) -> Self {
+        let yzx = |v: [f32; 4]| [v[1], v[2], v[0], v[3]];
+        let zxy = |v: [f32; 4]| [v[2], v[0], v[1], v[3]];
+        let (a, b) = (self.lanes(), rhs.lanes());
+        let (a_yzx, a_zxy, b_yzx, b_zxy) = (yzx(a), zxy(a), yzx(b), zxy(b));
+        // The padding lane computes `0 * 0 - 0 * 0`, so it stays zero
+        Self::from_lanes(std::array::from_fn(|i| {
+            a_yzx[i] * b_zxy[i] - a_zxy[i] * b_yzx[i]
+        }))
+    }
+}
+
+impl PartialEq for Vector3A {
+    /// Compares the coordinates, ignoring the padding
+    fn eq(&self, rhs: &Self) -> bool {
+        self.x == rhs.x && self.y == rhs.y && self.z == rhs.z
+    }
+}
+
+impl std::fmt::Debug for Vector3A {
+    /// Formats the coordinates, omitting the padding
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Vector3A")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
+}
+
+impl From<Vector3> for Vector3A {
+    /// Convert a vector into its padded form
+    fn from(v: Vector3) -> Self {
+        Vector3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3A> for Vector3 {
+    /// Convert a padded vector into its packed form
+    fn from(v: Vector3A) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3A> for Vector4 {
+    /// Convert a padded vector into a homogeneous direction, with a `w` of zero, filling the
+    /// same sixteen bytes
+    fn from(v: Vector3A) -> Self {
+        Vector4::new(v.x, v.y, v.z, 0.0)
+    }
 }
 
 impl From<Point> for Vector3 {
@@ -258,24 +783,14 @@ impl From<Vector4> for Point {
 impl From<Vector3> for Vector4 {
     /// Convert a point into a vector
     fn from(v: Vector3) -> Self {
-        Vector4 {
-            x: v.x,
-            y: v.y,
-            z: v.z,
-            w: 0.0,
-        }
+        Vector4::from_array_unchecked([v.x, v.y, v.z, 0.0])
     }
 }
 
 impl From<Point> for Vector4 {
     /// Convert a point into a vector
     fn from(p: Point) -> Self {
-        Vector4 {
-            x: p.x,
-            y: p.y,
-            z: p.z,
-            w: 1.0,
-        }
+        Vector4::from_array_unchecked([p.x, p.y, p.z, 1.0])
     }
 }
 
@@ -300,10 +815,200 @@ mod tests {
         assert_eq!(a.lerp(b, 0.75), Vector3::new(0.25, 0.75, 0.0));
     }
 
+    #[test]
+    fn orthonormalize() {
+        let [x, y, z] = crate::orthonormalize(
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(1.0, 3.0, 0.0),
+            Vector3::new(0.1, 0.2, 0.9),
+        );
+        assert_eq!(x, Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(y, Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(z, Vector3::new(0.0, 0.0, 1.0));
+
+        // A left-handed basis stays left-handed
+        let [x, y, z] = crate::orthonormalize(
+            Vector3::new(1.0, 0.1, 0.0),
+            Vector3::new(0.0, 1.0, 0.1),
+            Vector3::new(0.0, 0.0, -1.0),
+        );
+        assert!(x.cross(y).dot(z) < 0.0);
+        assert_nearly_eq!(x.dot(y), 0.0, epsilon = 1e-6);
+        assert_nearly_eq!(z.magnitude(), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Vector3::RIGHT.cross(Vector3::UP), Vector3::BACK);
+        assert_eq!(-Vector3::FORWARD, Vector3::BACK);
+        assert_eq!(Vector3::UNIT_X.cross(Vector3::UNIT_Y), Vector3::UNIT_Z);
+        assert_eq!(Point::ORIGIN + Vector3::UNIT_Y, Point::UNIT_Y);
+        assert_eq!(Vector4::from(Point::ORIGIN), Vector4::UNIT_W);
+
+        let view = crate::Matrix4::look_at(Point::ORIGIN, Point::new(0.0, 0.0, -1.0), Vector3::UP);
+        assert_eq!(view * Vector3::FORWARD, Vector3::FORWARD);
+    }
+
+    #[test]
+    fn rotated() {
+        let v = Vector2::new(1.0, 0.0);
+        let quarter = std::f32::consts::FRAC_PI_2;
+
+        assert_nearly_eq!(
+            &v.rotated(quarter),
+            &Vector2::new(0.0, -1.0),
+            epsilon = 1e-6
+        );
+        assert_nearly_eq!(
+            &v.rotated(-quarter),
+            &Vector2::new(0.0, 1.0),
+            epsilon = 1e-6
+        );
+        assert_nearly_eq!(
+            &Vector2::new(3.0, 2.0).rotated_around(Vector2::new(2.0, 2.0), std::f32::consts::PI),
+            &Vector2::new(1.0, 2.0),
+            epsilon = 1e-6
+        );
+
+        let m = Matrix4::rotation_z(0.3) * Vector3::new(2.0, 1.0, 0.0);
+        assert_nearly_eq!(
+            &Vector2::new(2.0, 1.0).rotated(0.3),
+            &Vector2::new(m.x, m.y),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn scalar_on_left() {
+        let v = Vector3::new(1.0, 2.0, 4.0);
+
+        assert_eq!(1.0 + v, v + 1.0);
+        assert_eq!(1.0 - v, Vector3::new(0.0, -1.0, -3.0));
+        assert_eq!(2.0 * v, v * 2.0);
+        assert_eq!(4.0 / v, Vector3::new(4.0, 2.0, 1.0));
+        assert_eq!(1.0 - Vector2::new(0.25, 0.5), Vector2::new(0.75, 0.5));
+    }
+
+    #[test]
+    fn remainder() {
+        let v = Vector2::new(5.5, -1.5);
+
+        assert_eq!(v % 2.0, Vector2::new(1.5, -1.5));
+        assert_eq!(v % Vector2::new(2.0, 1.0), Vector2::new(1.5, -0.5));
+        assert_eq!(v.rem_euclid(Vector2::new(2.0, 1.0)), Vector2::new(1.5, 0.5));
+        assert_eq!(
+            Point::new(12.0, -3.0, 5.0)
+                .wrap(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 10.0, 10.0)),
+            Point::new(2.0, 7.0, 5.0)
+        );
+        assert_eq!(
+            Vector3::new(-1.0, 1.0, 3.0)
+                .wrap(Vector3::from_scalar(-1.0), Vector3::from_scalar(1.0)),
+            Vector3::new(-1.0, -1.0, -1.0)
+        );
+        assert_eq!(
+            Vector2::new(-1e-10, 0.5).wrap(Vector2::zero(), Vector2::one()),
+            Vector2::new(0.0, 0.5)
+        );
+    }
+
     #[test]
     fn slice() {
         let a = Vector3::new(1.0, 2.0, 3.0);
 
         assert_eq!(a.as_slice(), &[1.0, 2.0, 3.0]);
+        assert_eq!(Vector3A::from(a).as_slice(), &[1.0, 2.0, 3.0]);
+
+        let mut b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        b.as_array_mut()[3] = 0.0;
+        assert_eq!(b.as_array(), &[1.0, 2.0, 3.0, 0.0]);
+        assert_eq!(
+            AsRef::<[f32; 2]>::as_ref(&Vector2::new(5.0, 6.0)),
+            &[5.0, 6.0]
+        );
+        assert_eq!(<[f32; 3]>::from(a), [1.0, 2.0, 3.0]);
+        assert_eq!(Vector3::from([1.0, 2.0, 3.0]), a);
+    }
+
+    #[test]
+    fn padded_layout() {
+        #[repr(C)]
+        struct Uniforms {
+            position: Vector3A,
+            color: Vector3A,
+            transform: Matrix4,
+        }
+
+        assert_eq!(std::mem::size_of::<Vector3A>(), 16);
+        assert_eq!(std::mem::align_of::<Vector3A>(), 16);
+        assert_eq!(std::mem::align_of::<Matrix4>(), 16);
+        assert_eq!(std::mem::size_of::<Vector4>(), 16);
+        assert_eq!(std::mem::align_of::<Vector4>(), 16);
+        assert_eq!(std::mem::offset_of!(Uniforms, color), 16);
+        assert_eq!(std::mem::offset_of!(Uniforms, transform), 32);
+
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(Vector3::from(Vector3A::from(v)), v);
+        assert_eq!(
+            Vector3A::from(v) + Vector3A::one(),
+            Vector3A::new(2.0, 3.0, 4.0)
+        );
+
+        let a = Vector3A::new(1.0, -2.0, 0.5);
+        let b = Vector3A::new(3.0, 1.0, 2.0);
+        assert_eq!(
+            Vector3::from(a.cross(b)),
+            Vector3::from(a).cross(Vector3::from(b))
+        );
+        assert_eq!(a.dot(b), Vector3::from(a).dot(Vector3::from(b)));
+        assert_eq!(Vector4::from(a), Vector4::new(1.0, -2.0, 0.5, 0.0));
+        assert_nearly_eq!(
+            &Vector3::from(a.normalized()),
+            &Vector3::from(a).normalized(),
+            epsilon = 1e-6
+        );
+
+        // Every byte is initialized, with zero padding after any operation
+        let bytes: [f32; 4] = unsafe { std::mem::transmute(a.cross(b).normalized() * 2.0) };
+        assert_eq!(bytes[3], 0.0);
+        assert_eq!(format!("{:?}", a), "Vector3A { x: 1.0, y: -2.0, z: 0.5 }");
+    }
+
+    #[test]
+    #[cfg(feature = "portable-simd")]
+    fn simd_storage() {
+        let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        v.y = 5.0;
+        v[3] = 6.0;
+
+        assert_eq!(std::simd::f32x4::from(v).to_array(), [1.0, 5.0, 3.0, 6.0]);
+        assert_eq!(
+            format!("{:?}", v),
+            "Vector4 { x: 1.0, y: 5.0, z: 3.0, w: 6.0 }"
+        );
+        assert_eq!(
+            Matrix4::from_rows(v, Vector4::UNIT_Y, Vector4::UNIT_Z, Vector4::UNIT_W).row(0),
+            v
+        );
+    }
+
+    #[test]
+    fn map() {
+        let v = Vector3::new(0.25, -0.05, 1.0);
+
+        assert_eq!(v.map(|x| x * x), v * v);
+        assert_eq!(
+            v.map(|x| if x.abs() < 0.1 { 0.0 } else { x }),
+            Vector3::new(0.25, 0.0, 1.0)
+        );
+        assert_eq!(
+            Point::new(1.0, 2.0, 3.0).zip_with(Point::new(3.0, 2.0, 1.0), f32::max),
+            Point::new(3.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            Vector4::new(1.0, 2.0, 3.0, 4.0).fold(0.0, |a, x| a + x),
+            10.0
+        );
+        assert_eq!(Vector2::new(2.0, 1.0).fold(f32::NAN, f32::min), 1.0);
     }
 }